@@ -17,6 +17,7 @@ pub enum Message {
     Style2(Normal),
     Style3(Normal),
     Style4(Normal),
+    Style5(Normal),
 }
 
 pub struct KnobStep {
@@ -33,6 +34,7 @@ pub struct KnobStep {
     knob_style2_state: knob::State,
     knob_style3_state: knob::State,
     knob_style4_state: knob::State,
+    knob_style5_state: knob::State,
 
     float_tick_marks: tick_marks::Group,
     int_tick_marks: tick_marks::Group,
@@ -44,6 +46,8 @@ pub struct KnobStep {
     db_text_marks: text_marks::Group,
     freq_text_marks: text_marks::Group,
 
+    knob_texture: knob::RotatingTexture,
+
     output_text: String,
 }
 
@@ -93,6 +97,10 @@ impl Default for KnobStep {
                 float_range.default_normal_param(),
             ),
 
+            knob_style5_state: knob::State::new(
+                float_range.default_normal_param(),
+            ),
+
             float_tick_marks: tick_marks::Group::subdivided(
                 1,
                 1,
@@ -148,6 +156,9 @@ impl Default for KnobStep {
             ]
             .into(),
 
+            // rotate the frames of the texture once, up front
+            knob_texture: style::knob::CustomTexture::texture(),
+
             output_text: String::from("Move a widget"),
         }
     }
@@ -211,6 +222,12 @@ impl KnobStep {
                     self.float_range.unmap_to_value(normal),
                 );
             }
+            Message::Style5(normal) => {
+                self.output_text = crate::info_text_f32(
+                    "KnobStyle5",
+                    self.float_range.unmap_to_value(normal),
+                );
+            }
         }
     }
 
@@ -243,14 +260,27 @@ impl KnobStep {
             Knob::new(&mut self.knob_style2_state, Message::Style2)
                 .style(style::knob::CustomStyleLine);
 
+        // show the label in the center of the knob, and the value
+        // while it is hovered or dragged
+        let float_range = self.float_range;
         let knob_style3 =
             Knob::new(&mut self.knob_style3_state, Message::Style3)
-                .style(style::knob::CustomArc);
+                .size(Length::Units(48))
+                .style(style::knob::CustomArc)
+                .label("Mix")
+                .value_text(move |normal| {
+                    format!("{:.2}", float_range.unmap_to_value(normal))
+                });
 
         let knob_style4 =
             Knob::new(&mut self.knob_style4_state, Message::Style4)
                 .style(style::knob::CustomArcBipolar);
 
+        let knob_style5 =
+            Knob::new(&mut self.knob_style5_state, Message::Style5)
+                .size(Length::Units(40))
+                .style(style::knob::CustomTexture(self.knob_texture.clone()));
+
         // push the widgets into rows
         let knob_row = Row::new()
             .spacing(20)
@@ -283,7 +313,9 @@ impl KnobStep {
                     .push(Text::new("Custom Style 3"))
                     .push(knob_style3)
                     .push(Text::new("Custom Bipolar Style 4"))
-                    .push(knob_style4),
+                    .push(knob_style4)
+                    .push(Text::new("Custom Texture Style 5"))
+                    .push(knob_style5),
             );
 
         let content = Column::new()
@@ -1,5 +1,5 @@
 use iced::{image, Color, Rectangle};
-use iced_audio::{h_slider, text_marks, tick_marks, Offset};
+use iced_audio::{effects, h_slider, text_marks, tick_marks, Offset};
 
 use super::colors;
 
@@ -13,9 +13,12 @@ impl RectStyle {
         back_border_radius: 2.0,
         back_border_color: colors::BORDER,
         filled_color: colors::FILLED,
+        filled_gradient: None,
         handle_width: 4,
         handle_color: colors::HANDLE,
         handle_filled_gap: 1.0,
+        handle_shadow: None,
+        handle_glow: None,
     };
 }
 impl h_slider::StyleSheet for RectStyle {
@@ -32,7 +35,16 @@ impl h_slider::StyleSheet for RectStyle {
     }
 
     fn dragging(&self) -> h_slider::Style {
-        self.hovered()
+        h_slider::Style::Rect(h_slider::RectStyle {
+            filled_color: colors::FILLED_HOVER,
+            handle_width: 5,
+            handle_glow: Some(effects::Glow {
+                color: colors::HANDLE,
+                width: 3.0,
+                intensity: effects::GlowIntensity::Fixed(0.5),
+            }),
+            ..Self::ACTIVE_RECT_STYLE
+        })
     }
 
     fn mod_range_style(&self) -> Option<h_slider::ModRangeStyle> {
@@ -63,11 +75,14 @@ impl RectBipolarStyle {
             back_border_color: colors::BORDER,
             left_filled_color: colors::FILLED,
             right_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
+            filled_gradient: None,
             handle_width: 4,
             handle_left_color: colors::HANDLE,
             handle_right_color: Color::from_rgb(0.0, 0.9, 0.0),
             handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
             handle_filled_gap: 1.0,
+            handle_shadow: None,
+            handle_glow: None,
         };
 }
 impl h_slider::StyleSheet for RectBipolarStyle {
@@ -103,6 +118,7 @@ impl h_slider::StyleSheet for TextureStyle {
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
             },
+            rail_texture: None,
             handle_width: 38,
             image_handle: self.0.clone(),
             image_bounds: self.1,
@@ -1,5 +1,5 @@
-use iced::Color;
-use iced_audio::{knob, text_marks};
+use iced::{Color, Rectangle};
+use iced_audio::{effects, knob, text_marks, Offset};
 
 use super::colors;
 
@@ -16,9 +16,23 @@ impl CustomStyleCircle {
     };
     const ACTIVE_CIRCLE_STYLE: knob::CircleStyle = knob::CircleStyle {
         color: colors::KNOB,
+        gradient: None,
         border_width: 3.0,
         border_color: colors::KNOB_BORDER,
         notch: knob::NotchShape::Circle(Self::ACTIVE_CIRCLE_NOTCH),
+        shadow: Some(effects::Shadow {
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.35,
+            },
+            offset: Offset { x: 0.0, y: 2.0 },
+            blur: 4.0,
+            spread: 0.0,
+        }),
+        inner_shadow: None,
+        glow: None,
     };
 }
 impl knob::StyleSheet for CustomStyleCircle {
@@ -75,6 +89,12 @@ impl knob::StyleSheet for CustomStyleCircle {
             offset: 15.0,
             h_char_offset: 3.0,
             v_offset: -0.75,
+            layout: text_marks::RadialLayout {
+                orientation: text_marks::Orientation::Tangential,
+                align: text_marks::RadialAlign::Edges,
+                hide_overlapping: true,
+                ..Default::default()
+            },
         })
     }
 }
@@ -92,9 +112,13 @@ impl CustomStyleLine {
     };
     const ACTIVE_CIRCLE_STYLE: knob::CircleStyle = knob::CircleStyle {
         color: colors::KNOB,
+        gradient: None,
         border_width: 0.0,
         border_color: Color::TRANSPARENT,
         notch: knob::NotchShape::Line(Self::ACTIVE_CIRCLE_NOTCH),
+        shadow: None,
+        inner_shadow: None,
+        glow: None,
     };
 }
 impl knob::StyleSheet for CustomStyleLine {
@@ -126,21 +150,27 @@ impl knob::StyleSheet for CustomStyleLine {
 // Custom style for the Knob
 
 pub struct CustomArc;
-impl knob::StyleSheet for CustomArc {
-    fn active(&self) -> knob::Style {
-        knob::Style::Arc(knob::ArcStyle {
+impl CustomArc {
+    const ACTIVE_ARC_STYLE: knob::ArcStyle = knob::ArcStyle {
+        width: knob::StyleLength::Units(3.15),
+        empty_color: colors::KNOB_ARC_EMPTY,
+        filled_color: colors::KNOB_ARC,
+        filled_gradient: None,
+        notch: knob::NotchShape::Line(knob::LineNotch {
+            color: colors::KNOB_ARC,
             width: knob::StyleLength::Units(3.15),
-            empty_color: colors::KNOB_ARC_EMPTY,
-            filled_color: colors::KNOB_ARC,
-            notch: knob::NotchShape::Line(knob::LineNotch {
-                color: colors::KNOB_ARC,
-                width: knob::StyleLength::Units(3.15),
-                length: knob::StyleLength::Scaled(0.25),
-                cap: knob::LineCap::Round,
-                offset: knob::StyleLength::Units(2.5),
-            }),
+            length: knob::StyleLength::Scaled(0.25),
             cap: knob::LineCap::Round,
-        })
+            offset: knob::StyleLength::Units(2.5),
+        }),
+        cap: knob::LineCap::Round,
+        shadow: None,
+        glow: None,
+    };
+}
+impl knob::StyleSheet for CustomArc {
+    fn active(&self) -> knob::Style {
+        knob::Style::Arc(Self::ACTIVE_ARC_STYLE)
     }
 
     fn hovered(&self) -> knob::Style {
@@ -148,13 +178,35 @@ impl knob::StyleSheet for CustomArc {
     }
 
     fn dragging(&self) -> knob::Style {
-        self.active()
+        knob::Style::Arc(knob::ArcStyle {
+            glow: Some(effects::Glow {
+                color: colors::KNOB_ARC,
+                width: 4.0,
+                intensity: effects::GlowIntensity::Value { min: 0.2, max: 0.6 },
+            }),
+            ..Self::ACTIVE_ARC_STYLE
+        })
     }
 
     fn angle_range(&self) -> iced_audio::KnobAngleRange {
         iced_audio::KnobAngleRange::from_deg(40.0, 320.0)
     }
 
+    fn value_text_style(&self) -> Option<knob::ValueTextStyle> {
+        Some(knob::ValueTextStyle {
+            content: knob::ValueTextContent::Label,
+            ..knob::ValueTextStyle::default()
+        })
+    }
+
+    fn value_text_hovered_style(&self) -> Option<knob::ValueTextStyle> {
+        Some(knob::ValueTextStyle {
+            content: knob::ValueTextContent::Value,
+            color: colors::KNOB_ARC,
+            ..knob::ValueTextStyle::default()
+        })
+    }
+
     fn mod_range_arc_style(&self) -> Option<knob::ModRangeArcStyle> {
         Some(knob::ModRangeArcStyle {
             width: 3.0,
@@ -186,6 +238,7 @@ impl knob::StyleSheet for CustomArcBipolar {
             empty_color: colors::KNOB_ARC_EMPTY,
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: colors::KNOB_ARC_RIGHT,
+            filled_gradient: None,
             notch_center: knob::NotchShape::Line(Self::NOTCH_CENTER),
             notch_left_right: Some((
                 knob::NotchShape::Line(knob::LineNotch {
@@ -198,6 +251,8 @@ impl knob::StyleSheet for CustomArcBipolar {
                 }),
             )),
             cap: knob::LineCap::Butt,
+            shadow: None,
+            glow: None,
         })
     }
 
@@ -213,3 +268,79 @@ impl knob::StyleSheet for CustomArcBipolar {
         iced_audio::KnobAngleRange::from_deg(40.0, 320.0)
     }
 }
+
+// Custom style for the Knob drawn from a rotating texture
+
+pub struct CustomTexture(pub knob::RotatingTexture);
+impl CustomTexture {
+    const SIZE: u32 = 40;
+
+    // Draws a knob cap with an indicator pointing straight up, and rotates
+    // it into its frames. This is slow, so it is only done once when the
+    // step is created.
+    pub fn texture() -> knob::RotatingTexture {
+        let size = Self::SIZE as usize;
+        let radius = Self::SIZE as f32 / 2.0;
+        let mut pixels = vec![0; size * size * 4];
+
+        for y in 0..size {
+            for x in 0..size {
+                let dx = x as f32 + 0.5 - radius;
+                let dy = y as f32 + 0.5 - radius;
+
+                // Fade out the last pixel at the edge of the cap.
+                let coverage =
+                    (radius - (dx * dx + dy * dy).sqrt()).clamp(0.0, 1.0);
+                if coverage == 0.0 {
+                    continue;
+                }
+
+                let is_indicator = dx.abs() < 1.5 && dy < -6.0 && dy > -16.0;
+                let color = if is_indicator {
+                    colors::HANDLE
+                } else {
+                    colors::KNOB
+                };
+
+                let i = (y * size + x) * 4;
+                pixels[i] = (color.b * 255.0) as u8;
+                pixels[i + 1] = (color.g * 255.0) as u8;
+                pixels[i + 2] = (color.r * 255.0) as u8;
+                pixels[i + 3] = (coverage * 255.0) as u8;
+            }
+        }
+
+        knob::RotatingTexture::new(
+            Self::SIZE,
+            Self::SIZE,
+            &pixels,
+            Rectangle {
+                x: -radius,
+                y: -radius,
+                width: Self::SIZE as f32,
+                height: Self::SIZE as f32,
+            },
+            128,
+            &Default::default(),
+        )
+    }
+}
+impl knob::StyleSheet for CustomTexture {
+    fn active(&self) -> knob::Style {
+        knob::Style::Texture(knob::TextureStyle {
+            background: None,
+            shadow: None,
+            knob: self.0.clone(),
+            notch: knob::NotchShape::None,
+            highlight: None,
+        })
+    }
+
+    fn hovered(&self) -> knob::Style {
+        self.active()
+    }
+
+    fn dragging(&self) -> knob::Style {
+        self.active()
+    }
+}
@@ -20,6 +20,7 @@ impl CustomStyle {
         v_rail_color: colors::HANDLE,
         handle: xy_pad::HandleShape::Square(Self::ACTIVE_HANDLE),
         back_color: colors::EMPTY,
+        back_gradient: None,
         border_width: 2.0,
         border_color: Color::BLACK,
         center_line_width: 1.0,
@@ -0,0 +1,593 @@
+//! Helpers for turning blocks of audio into magnitude bins that can be
+//! displayed by spectrum widgets.
+//!
+//! A [`SpectrumAnalyzer`] takes in blocks of samples of any size, and runs an
+//! FFT every time enough new samples have been collected (according to the
+//! FFT size and overlap). The resulting magnitudes are averaged over time,
+//! optionally smoothed by a fraction of an octave, tilted by a slope in
+//! dB per octave, and can then be resampled onto a pixel grid spaced by a
+//! [`FreqRange`].
+//!
+//! [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+//! [`FreqRange`]: ../struct.FreqRange.html
+
+use crate::core::{FreqRange, Normal};
+
+/// The lowest value (in decibels) produced by a [`SpectrumAnalyzer`].
+///
+/// [`SpectrumAnalyzer`]: struct.SpectrumAnalyzer.html
+pub static MIN_DB: f32 = -140.0;
+
+/// The frequency (in Hz) around which the slope tilt pivots. Bins at this
+/// frequency are left unchanged by the tilt.
+pub static TILT_PIVOT_HZ: f32 = 1_000.0;
+
+/// The window function applied to each block of samples before it is
+/// transformed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Window {
+    /// A Hann window. A good general purpose window.
+    Hann,
+    /// A 4-term Blackman-Harris window. It has a very low side-lobe level,
+    /// which is useful for displaying a wide dynamic range.
+    BlackmanHarris,
+    /// A flat-top window. It has a very accurate amplitude at the cost of
+    /// frequency resolution.
+    FlatTop,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Window::Hann
+    }
+}
+
+impl Window {
+    /// Returns the coefficients of this window for a block of `size`
+    /// samples.
+    pub fn coefficients(&self, size: usize) -> Vec<f32> {
+        let n = size as f64;
+
+        (0..size)
+            .map(|i| {
+                let x = std::f64::consts::PI * 2.0 * i as f64 / n;
+
+                let value = match self {
+                    Window::Hann => 0.5 - 0.5 * x.cos(),
+                    Window::BlackmanHarris => {
+                        0.35875 - 0.48829 * x.cos() + 0.14128 * (2.0 * x).cos()
+                            - 0.01168 * (3.0 * x).cos()
+                    }
+                    Window::FlatTop => {
+                        0.21557895 - 0.41663158 * x.cos()
+                            + 0.277263158 * (2.0 * x).cos()
+                            - 0.083578947 * (3.0 * x).cos()
+                            + 0.006947368 * (4.0 * x).cos()
+                    }
+                };
+
+                value as f32
+            })
+            .collect()
+    }
+}
+
+/// Smoothing of the magnitude bins across a fraction of an octave.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OctaveSmoothing {
+    /// No smoothing.
+    None,
+    /// Each bin is averaged with the bins within `1 / n` of an octave
+    /// around it. For example, `Fraction(3.0)` gives third-octave smoothing.
+    Fraction(f32),
+}
+
+impl Default for OctaveSmoothing {
+    fn default() -> Self {
+        OctaveSmoothing::None
+    }
+}
+
+/// Turns blocks of audio samples into magnitude bins (in decibels).
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::analysis::{SpectrumAnalyzer, Window};
+///
+/// let sample_rate = 48_000.0;
+/// let mut analyzer = SpectrumAnalyzer::new(1024, sample_rate);
+/// analyzer.set_window(Window::FlatTop);
+///
+/// // A full-scale sine wave that falls exactly on bin 64.
+/// let freq = analyzer.bin_frequency(64);
+/// let block: Vec<f32> = (0..1024)
+///     .map(|i| {
+///         (std::f32::consts::PI * 2.0 * freq * i as f32 / sample_rate).sin()
+///     })
+///     .collect();
+///
+/// assert!(analyzer.process(&block));
+///
+/// // The peak reads as 0 dBFS.
+/// assert!(analyzer.bins_db()[64].abs() < 0.1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpectrumAnalyzer {
+    fft: Fft,
+    sample_rate: f32,
+
+    window: Window,
+    window_table: Vec<f32>,
+    amplitude_scale: f32,
+
+    hop_size: usize,
+    input: Vec<f32>,
+    samples_since_frame: usize,
+
+    averaging_time: f32,
+    averaging_coeff: f32,
+    smoothing: OctaveSmoothing,
+    tilt: f32,
+
+    re: Vec<f32>,
+    im: Vec<f32>,
+    averaged_power: Vec<f32>,
+    smoothing_sums: Vec<f64>,
+    bins_db: Vec<f32>,
+    has_frame: bool,
+}
+
+impl SpectrumAnalyzer {
+    /// Creates a new `SpectrumAnalyzer`.
+    ///
+    /// * `fft_size` - The number of samples in each analysed block.
+    /// * `sample_rate` - The sample rate of the incoming audio in Hz.
+    ///
+    /// By default the analyzer uses a [`Window::Hann`] window with `50%`
+    /// overlap, no time averaging, no smoothing, and no tilt.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `fft_size` is not a power of two or is less than
+    /// `4`, or if `sample_rate` is not positive.
+    ///
+    /// [`Window::Hann`]: enum.Window.html#variant.Hann
+    pub fn new(fft_size: usize, sample_rate: f32) -> Self {
+        assert!(
+            fft_size >= 4 && fft_size.is_power_of_two(),
+            "fft_size must be a power of two"
+        );
+        assert!(sample_rate > 0.0, "sample_rate must be positive");
+
+        let num_bins = fft_size / 2 + 1;
+
+        let mut analyzer = Self {
+            fft: Fft::new(fft_size),
+            sample_rate,
+
+            window: Window::Hann,
+            window_table: Vec::new(),
+            amplitude_scale: 0.0,
+
+            hop_size: fft_size / 2,
+            input: vec![0.0; fft_size],
+            samples_since_frame: 0,
+
+            averaging_time: 0.0,
+            averaging_coeff: 0.0,
+            smoothing: OctaveSmoothing::None,
+            tilt: 0.0,
+
+            re: vec![0.0; fft_size],
+            im: vec![0.0; fft_size],
+            averaged_power: vec![0.0; num_bins],
+            smoothing_sums: vec![0.0; num_bins + 1],
+            bins_db: vec![MIN_DB; num_bins],
+            has_frame: false,
+        };
+
+        analyzer.set_window(Window::Hann);
+
+        analyzer
+    }
+
+    /// Returns the number of samples in each analysed block.
+    pub fn fft_size(&self) -> usize {
+        self.fft.size
+    }
+
+    /// Returns the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Returns the number of magnitude bins (`fft_size / 2 + 1`).
+    pub fn num_bins(&self) -> usize {
+        self.bins_db.len()
+    }
+
+    /// Returns the center frequency of the bin at `index` in Hz.
+    pub fn bin_frequency(&self, index: usize) -> f32 {
+        index as f32 * self.sample_rate / self.fft.size as f32
+    }
+
+    /// Returns the current window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Sets the window function applied to each block.
+    pub fn set_window(&mut self, window: Window) {
+        self.window = window;
+        self.window_table = window.coefficients(self.fft.size);
+
+        // Scale so that a full-scale sine wave reads as 0 dBFS regardless
+        // of the window's coherent gain.
+        let window_sum: f32 = self.window_table.iter().sum();
+        self.amplitude_scale = 2.0 / window_sum;
+    }
+
+    /// Returns the overlap between consecutive blocks (between `0.0` and
+    /// `1.0`).
+    pub fn overlap(&self) -> f32 {
+        1.0 - (self.hop_size as f32 / self.fft.size as f32)
+    }
+
+    /// Sets the overlap between consecutive blocks.
+    ///
+    /// `0.0` means no overlap, `0.5` means each block shares half of its
+    /// samples with the previous one, etc. This is constrained to the range
+    /// `0.0 <= overlap <= 0.95`.
+    ///
+    /// This can be changed while samples are being processed. If more
+    /// samples have been collected since the last frame than the new hop
+    /// size, the next frame is analysed as soon as more samples arrive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::core::analysis::SpectrumAnalyzer;
+    ///
+    /// let mut analyzer = SpectrumAnalyzer::new(1024, 48_000.0);
+    ///
+    /// // Collect 300 samples with a hop size of 512.
+    /// assert!(!analyzer.process(&[0.0; 300]));
+    ///
+    /// // Lower the hop size to 256.
+    /// analyzer.set_overlap(0.75);
+    /// assert!(analyzer.process(&[0.0; 10]));
+    /// ```
+    pub fn set_overlap(&mut self, overlap: f32) {
+        let overlap = overlap.max(0.0).min(0.95);
+
+        self.hop_size =
+            (((1.0 - overlap) * self.fft.size as f32).round() as usize).max(1);
+        self.samples_since_frame = self.samples_since_frame.min(self.hop_size);
+
+        self.update_averaging_coeff();
+    }
+
+    /// Sets the time in seconds it takes for the averaged magnitudes to
+    /// reach about `63%` of a new value.
+    ///
+    /// Set this to `0.0` (the default) for no time averaging.
+    pub fn set_averaging_time(&mut self, seconds: f32) {
+        self.averaging_time = seconds.max(0.0);
+        self.update_averaging_coeff();
+    }
+
+    /// Sets the fractional-octave smoothing.
+    pub fn set_smoothing(&mut self, smoothing: OctaveSmoothing) {
+        self.smoothing = smoothing;
+        self.update_output();
+    }
+
+    /// Sets the slope tilt in dB per octave, pivoting around
+    /// [`TILT_PIVOT_HZ`].
+    ///
+    /// For example, a tilt of `4.5` makes pink noise appear flat.
+    ///
+    /// [`TILT_PIVOT_HZ`]: static.TILT_PIVOT_HZ.html
+    pub fn set_tilt(&mut self, db_per_octave: f32) {
+        self.tilt = db_per_octave;
+        self.update_output();
+    }
+
+    /// Clears all collected samples and averaged magnitudes.
+    pub fn reset(&mut self) {
+        for s in self.input.iter_mut() {
+            *s = 0.0;
+        }
+        for p in self.averaged_power.iter_mut() {
+            *p = 0.0;
+        }
+        for b in self.bins_db.iter_mut() {
+            *b = MIN_DB;
+        }
+        self.samples_since_frame = 0;
+        self.has_frame = false;
+    }
+
+    /// Feeds a block of samples (of any size) into the analyzer.
+    ///
+    /// Returns `true` if at least one new frame was analysed, meaning the
+    /// values returned by [`bins_db`] have changed.
+    ///
+    /// [`bins_db`]: #method.bins_db
+    pub fn process(&mut self, samples: &[f32]) -> bool {
+        let size = self.fft.size;
+        let mut new_frame = false;
+
+        let mut remaining = samples;
+        while !remaining.is_empty() {
+            let needed = self.hop_size.saturating_sub(self.samples_since_frame);
+            let take = needed.min(remaining.len());
+
+            // Shift the analysis buffer left and append the new samples.
+            let _ = self.input.drain(0..take);
+            self.input.extend_from_slice(&remaining[0..take]);
+            debug_assert_eq!(self.input.len(), size);
+
+            self.samples_since_frame += take;
+            remaining = &remaining[take..];
+
+            if self.samples_since_frame >= self.hop_size {
+                self.samples_since_frame = 0;
+                self.analyse_frame();
+                new_frame = true;
+            }
+        }
+
+        if new_frame {
+            self.update_output();
+        }
+
+        new_frame
+    }
+
+    /// Returns the processed magnitude of each bin in decibels.
+    pub fn bins_db(&self) -> &[f32] {
+        &self.bins_db
+    }
+
+    /// Resamples the magnitude bins onto a grid of `width` pixels that is
+    /// spaced according to `freq_range`.
+    ///
+    /// Each value in `out` is the magnitude in decibels at the center of
+    /// that pixel. Where a pixel covers several bins, the loudest bin is
+    /// used so that narrow peaks stay visible. Where a pixel falls between
+    /// two bins, the value is interpolated.
+    pub fn resample_to_pixels(
+        &self,
+        width: usize,
+        freq_range: &FreqRange,
+        out: &mut Vec<f32>,
+    ) {
+        out.clear();
+        if width == 0 {
+            return;
+        }
+
+        let bin_width = self.sample_rate / self.fft.size as f32;
+        let last_bin = self.bins_db.len() - 1;
+        let width_recip = 1.0 / width as f32;
+
+        let bin_at = |x: f32| -> f32 {
+            let freq = freq_range.unmap_to_value(Normal::new(x * width_recip));
+            (freq / bin_width).max(0.0).min(last_bin as f32)
+        };
+
+        for x in 0..width {
+            let start = bin_at(x as f32);
+            let center = bin_at(x as f32 + 0.5);
+            let end = bin_at(x as f32 + 1.0);
+
+            let first = start.ceil() as usize;
+            let last = end.floor() as usize;
+
+            let value = if last > first {
+                // This pixel spans multiple bins.
+                self.bins_db[first..=last]
+                    .iter()
+                    .fold(MIN_DB, |acc, &db| acc.max(db))
+            } else {
+                let lower = center.floor() as usize;
+                let upper = (lower + 1).min(last_bin);
+                let t = center - lower as f32;
+
+                self.bins_db[lower]
+                    + (self.bins_db[upper] - self.bins_db[lower]) * t
+            };
+
+            out.push(value);
+        }
+    }
+
+    fn update_averaging_coeff(&mut self) {
+        self.averaging_coeff = if self.averaging_time > 0.0 {
+            let frames_per_second = self.sample_rate / self.hop_size as f32;
+            (-1.0 / (self.averaging_time * frames_per_second)).exp()
+        } else {
+            0.0
+        };
+    }
+
+    fn analyse_frame(&mut self) {
+        for ((re, im), (s, w)) in self
+            .re
+            .iter_mut()
+            .zip(self.im.iter_mut())
+            .zip(self.input.iter().zip(self.window_table.iter()))
+        {
+            *re = s * w;
+            *im = 0.0;
+        }
+
+        self.fft.process(&mut self.re, &mut self.im);
+
+        let coeff = if self.has_frame {
+            self.averaging_coeff
+        } else {
+            0.0
+        };
+
+        for (k, avg) in self.averaged_power.iter_mut().enumerate() {
+            let mut amp = (self.re[k] * self.re[k] + self.im[k] * self.im[k])
+                .sqrt()
+                * self.amplitude_scale;
+
+            // The DC and Nyquist bins are not mirrored.
+            if k == 0 || k == self.fft.size / 2 {
+                amp *= 0.5;
+            }
+
+            let power = amp * amp;
+
+            *avg = (*avg * coeff) + (power * (1.0 - coeff));
+        }
+
+        self.has_frame = true;
+    }
+
+    fn update_output(&mut self) {
+        if !self.has_frame {
+            return;
+        }
+
+        let num_bins = self.bins_db.len();
+        let bin_width = self.sample_rate / self.fft.size as f32;
+
+        match self.smoothing {
+            OctaveSmoothing::Fraction(fraction) if fraction > 0.0 => {
+                // Prefix sums let each bin average any span in constant time.
+                self.smoothing_sums[0] = 0.0;
+                for k in 0..num_bins {
+                    self.smoothing_sums[k + 1] = self.smoothing_sums[k]
+                        + f64::from(self.averaged_power[k]);
+                }
+
+                let half_span = 2.0f32.powf(0.5 / fraction);
+
+                for k in 0..num_bins {
+                    let lo = ((k as f32 / half_span).floor() as usize).min(k);
+                    let hi = ((k as f32 * half_span).ceil() as usize)
+                        .max(k)
+                        .min(num_bins - 1);
+
+                    let sum =
+                        self.smoothing_sums[hi + 1] - self.smoothing_sums[lo];
+                    let power = (sum / (hi + 1 - lo) as f64) as f32;
+
+                    self.bins_db[k] = power_to_db(power);
+                }
+            }
+            _ => {
+                for (db, power) in
+                    self.bins_db.iter_mut().zip(self.averaged_power.iter())
+                {
+                    *db = power_to_db(*power);
+                }
+            }
+        }
+
+        if self.tilt != 0.0 {
+            // Leave the DC bin alone since it has no defined octave.
+            for (k, db) in self.bins_db.iter_mut().enumerate().skip(1) {
+                let freq = k as f32 * bin_width;
+                *db += self.tilt * (freq / TILT_PIVOT_HZ).log2();
+            }
+        }
+    }
+}
+
+/// Maps a value in decibels to a [`Normal`], where `floor_db` maps to `0.0`
+/// and `ceiling_db` maps to `1.0`.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[inline]
+pub fn db_to_normal(db: f32, floor_db: f32, ceiling_db: f32) -> Normal {
+    ((db - floor_db) / (ceiling_db - floor_db)).into()
+}
+
+#[inline]
+fn power_to_db(power: f32) -> f32 {
+    if power <= 0.0 {
+        MIN_DB
+    } else {
+        (10.0 * power.log10()).max(MIN_DB)
+    }
+}
+
+/// An iterative radix-2 FFT with precomputed twiddle factors.
+#[derive(Debug, Clone)]
+struct Fft {
+    size: usize,
+    bit_reverse: Vec<usize>,
+    cos_table: Vec<f32>,
+    sin_table: Vec<f32>,
+}
+
+impl Fft {
+    fn new(size: usize) -> Self {
+        let bits = size.trailing_zeros();
+
+        let bit_reverse = (0..size)
+            .map(|i| i.reverse_bits() >> (usize::BITS - bits))
+            .collect();
+
+        let half = size / 2;
+        let mut cos_table = Vec::with_capacity(half);
+        let mut sin_table = Vec::with_capacity(half);
+        for i in 0..half {
+            let angle = -std::f64::consts::PI * 2.0 * i as f64 / size as f64;
+            cos_table.push(angle.cos() as f32);
+            sin_table.push(angle.sin() as f32);
+        }
+
+        Self {
+            size,
+            bit_reverse,
+            cos_table,
+            sin_table,
+        }
+    }
+
+    fn process(&self, re: &mut [f32], im: &mut [f32]) {
+        let n = self.size;
+
+        for i in 0..n {
+            let j = self.bit_reverse[i];
+            if j > i {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let half = len / 2;
+            let stride = n / len;
+
+            for start in (0..n).step_by(len) {
+                for k in 0..half {
+                    let w_re = self.cos_table[k * stride];
+                    let w_im = self.sin_table[k * stride];
+
+                    let a = start + k;
+                    let b = a + half;
+
+                    let t_re = re[b] * w_re - im[b] * w_im;
+                    let t_im = re[b] * w_im + im[b] * w_re;
+
+                    re[b] = re[a] - t_re;
+                    im[b] = im[a] - t_im;
+                    re[a] += t_re;
+                    im[a] += t_im;
+                }
+            }
+
+            len *= 2;
+        }
+    }
+}
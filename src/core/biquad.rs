@@ -0,0 +1,416 @@
+//! Helpers for computing the frequency response of biquad filters, so that
+//! EQ and filter curves can be drawn accurately.
+//!
+//! The response of a [`FilterType`] can be computed two ways:
+//!
+//! * [`Coefficients::rbj`] designs a digital filter with the formulas from
+//! Robert Bristow-Johnson's "Audio EQ Cookbook". Its response matches a
+//! filter running at the given sample rate, including the cramping of the
+//! curve near the Nyquist frequency.
+//! * [`analog_response`] evaluates the analog prototype of the filter. This
+//! matches "analog-matched" filters that have no cramping, and does not
+//! need a sample rate.
+//!
+//! Use [`frequency_grid`] to get the frequencies at each pixel of a curve
+//! spaced by a [`FreqRange`].
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::core::biquad::{Coefficients, FilterType};
+//!
+//! let peak = Coefficients::rbj(
+//!     FilterType::Peak { gain_db: 6.0 },
+//!     1_000.0,
+//!     1.0,
+//!     48_000.0,
+//! );
+//!
+//! let response = peak.response(1_000.0, 48_000.0);
+//! assert!((response.magnitude_db() - 6.0).abs() < 0.001);
+//! ```
+//!
+//! [`FilterType`]: enum.FilterType.html
+//! [`Coefficients::rbj`]: struct.Coefficients.html#method.rbj
+//! [`analog_response`]: fn.analog_response.html
+//! [`frequency_grid`]: fn.frequency_grid.html
+//! [`FreqRange`]: ../struct.FreqRange.html
+
+use crate::core::{FreqRange, Normal};
+
+/// The lowest magnitude (in decibels) returned by
+/// [`Response::magnitude_db`].
+///
+/// [`Response::magnitude_db`]: struct.Response.html#method.magnitude_db
+pub static MIN_DB: f32 = -200.0;
+
+/// The type of a biquad filter.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FilterType {
+    /// A second order low-pass filter
+    LowPass,
+    /// A second order high-pass filter
+    HighPass,
+    /// A first order (6 dB per octave) low-pass filter. The Q is ignored.
+    FirstOrderLowPass,
+    /// A first order (6 dB per octave) high-pass filter. The Q is ignored.
+    FirstOrderHighPass,
+    /// A band-pass filter with a peak gain of `0 dB`
+    BandPass,
+    /// A notch (band-stop) filter
+    Notch,
+    /// An all-pass filter. Only the phase is changed.
+    AllPass,
+    /// A peaking (bell) filter
+    Peak {
+        /// The gain at the center frequency in decibels
+        gain_db: f32,
+    },
+    /// A low shelf filter
+    LowShelf {
+        /// The gain below the corner frequency in decibels
+        gain_db: f32,
+    },
+    /// A high shelf filter
+    HighShelf {
+        /// The gain above the corner frequency in decibels
+        gain_db: f32,
+    },
+}
+
+/// The response of a filter at a single frequency.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Response {
+    /// The linear magnitude, where `1.0` is unity gain
+    pub magnitude: f32,
+    /// The phase shift in radians
+    pub phase: f32,
+}
+
+impl Response {
+    /// A response with unity gain and no phase shift.
+    pub const UNITY: Response = Response {
+        magnitude: 1.0,
+        phase: 0.0,
+    };
+
+    /// Returns the magnitude in decibels. This is never lower than
+    /// [`MIN_DB`].
+    ///
+    /// [`MIN_DB`]: static.MIN_DB.html
+    pub fn magnitude_db(&self) -> f32 {
+        if self.magnitude > 0.0 {
+            (20.0 * self.magnitude.log10()).max(MIN_DB)
+        } else {
+            MIN_DB
+        }
+    }
+}
+
+impl Default for Response {
+    fn default() -> Self {
+        Response::UNITY
+    }
+}
+
+/// Cascades two responses, as if the filters were run one after the other.
+impl std::ops::Mul for Response {
+    type Output = Response;
+
+    fn mul(self, other: Response) -> Response {
+        Response {
+            magnitude: self.magnitude * other.magnitude,
+            phase: self.phase + other.phase,
+        }
+    }
+}
+
+/// The coefficients of a digital biquad filter, normalized so that `a0` is
+/// `1.0`.
+///
+/// The coefficients are stored in `f64`, since low frequencies at high
+/// sample rates lose too much precision in `f32`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Coefficients {
+    /// The feed-forward coefficient of the current sample
+    pub b0: f64,
+    /// The feed-forward coefficient of the previous sample
+    pub b1: f64,
+    /// The feed-forward coefficient of the sample before the previous one
+    pub b2: f64,
+    /// The feedback coefficient of the previous output
+    pub a1: f64,
+    /// The feedback coefficient of the output before the previous one
+    pub a2: f64,
+}
+
+impl Coefficients {
+    /// Designs a filter with the formulas from Robert Bristow-Johnson's
+    /// "Audio EQ Cookbook".
+    ///
+    /// # Arguments
+    ///
+    /// * `filter_type` - the type of the filter
+    /// * `frequency` - the center, corner, or cutoff frequency in Hz
+    /// * `q` - the Q of the filter. For shelves, a Q of `0.707` gives the
+    /// steepest slope without an overshoot.
+    /// * `sample_rate` - the sample rate in Hz
+    ///
+    /// The frequency is constrained to just under the Nyquist frequency.
+    pub fn rbj(
+        filter_type: FilterType,
+        frequency: f32,
+        q: f32,
+        sample_rate: f32,
+    ) -> Self {
+        let sample_rate = f64::from(sample_rate);
+        let frequency = f64::from(frequency).max(0.0).min(sample_rate * 0.4999);
+        let q = f64::from(q).max(std::f64::EPSILON);
+
+        let w0 = std::f64::consts::PI * 2.0 * frequency / sample_rate;
+        let cos = w0.cos();
+        let alpha = w0.sin() / (2.0 * q);
+
+        let (b0, b1, b2, a0, a1, a2) = match filter_type {
+            FilterType::LowPass => (
+                (1.0 - cos) / 2.0,
+                1.0 - cos,
+                (1.0 - cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            FilterType::HighPass => (
+                (1.0 + cos) / 2.0,
+                -(1.0 + cos),
+                (1.0 + cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            FilterType::FirstOrderLowPass => {
+                let k = (w0 / 2.0).tan();
+                (k, k, 0.0, k + 1.0, k - 1.0, 0.0)
+            }
+            FilterType::FirstOrderHighPass => {
+                let k = (w0 / 2.0).tan();
+                (1.0, -1.0, 0.0, k + 1.0, k - 1.0, 0.0)
+            }
+            FilterType::BandPass => {
+                (alpha, 0.0, -alpha, 1.0 + alpha, -2.0 * cos, 1.0 - alpha)
+            }
+            FilterType::Notch => {
+                (1.0, -2.0 * cos, 1.0, 1.0 + alpha, -2.0 * cos, 1.0 - alpha)
+            }
+            FilterType::AllPass => (
+                1.0 - alpha,
+                -2.0 * cos,
+                1.0 + alpha,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            FilterType::Peak { gain_db } => {
+                let a = gain_to_a(gain_db);
+                (
+                    1.0 + (alpha * a),
+                    -2.0 * cos,
+                    1.0 - (alpha * a),
+                    1.0 + (alpha / a),
+                    -2.0 * cos,
+                    1.0 - (alpha / a),
+                )
+            }
+            FilterType::LowShelf { gain_db } => {
+                let a = gain_to_a(gain_db);
+                let two_sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) - ((a - 1.0) * cos) + two_sqrt_a_alpha),
+                    2.0 * a * ((a - 1.0) - ((a + 1.0) * cos)),
+                    a * ((a + 1.0) - ((a - 1.0) * cos) - two_sqrt_a_alpha),
+                    (a + 1.0) + ((a - 1.0) * cos) + two_sqrt_a_alpha,
+                    -2.0 * ((a - 1.0) + ((a + 1.0) * cos)),
+                    (a + 1.0) + ((a - 1.0) * cos) - two_sqrt_a_alpha,
+                )
+            }
+            FilterType::HighShelf { gain_db } => {
+                let a = gain_to_a(gain_db);
+                let two_sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) + ((a - 1.0) * cos) + two_sqrt_a_alpha),
+                    -2.0 * a * ((a - 1.0) + ((a + 1.0) * cos)),
+                    a * ((a + 1.0) + ((a - 1.0) * cos) - two_sqrt_a_alpha),
+                    (a + 1.0) - ((a - 1.0) * cos) + two_sqrt_a_alpha,
+                    2.0 * ((a - 1.0) - ((a + 1.0) * cos)),
+                    (a + 1.0) - ((a - 1.0) * cos) - two_sqrt_a_alpha,
+                )
+            }
+        };
+
+        let a0_recip = 1.0 / a0;
+
+        Self {
+            b0: b0 * a0_recip,
+            b1: b1 * a0_recip,
+            b2: b2 * a0_recip,
+            a1: a1 * a0_recip,
+            a2: a2 * a0_recip,
+        }
+    }
+
+    /// Returns the response of the filter at `frequency` (in Hz) when it
+    /// runs at `sample_rate` (in Hz).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::core::biquad::{Coefficients, FilterType};
+    ///
+    /// // A Butterworth low-pass filter is 3 dB down at its cutoff.
+    /// let low_pass = Coefficients::rbj(
+    ///     FilterType::LowPass,
+    ///     1_000.0,
+    ///     std::f32::consts::FRAC_1_SQRT_2,
+    ///     48_000.0,
+    /// );
+    ///
+    /// let response = low_pass.response(1_000.0, 48_000.0);
+    /// assert!((response.magnitude_db() + 3.0103).abs() < 0.001);
+    ///
+    /// let dc = low_pass.response(0.0, 48_000.0);
+    /// assert!(dc.magnitude_db().abs() < 0.001);
+    /// ```
+    pub fn response(&self, frequency: f32, sample_rate: f32) -> Response {
+        let w = std::f64::consts::PI * 2.0 * f64::from(frequency)
+            / f64::from(sample_rate);
+
+        // Evaluate `b0 + b1 z^-1 + b2 z^-2` at `z = e^jw`.
+        let (cos_1, sin_1) = (w.cos(), w.sin());
+        let (cos_2, sin_2) = ((2.0 * w).cos(), (2.0 * w).sin());
+
+        let num_re = self.b0 + (self.b1 * cos_1) + (self.b2 * cos_2);
+        let num_im = -(self.b1 * sin_1) - (self.b2 * sin_2);
+        let den_re = 1.0 + (self.a1 * cos_1) + (self.a2 * cos_2);
+        let den_im = -(self.a1 * sin_1) - (self.a2 * sin_2);
+
+        polynomial_ratio(num_re, num_im, den_re, den_im)
+    }
+}
+
+/// Returns the response at `frequency` (in Hz) of the analog prototype of a
+/// filter with the given center, corner, or cutoff frequency (in Hz) and Q.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::biquad::{analog_response, FilterType};
+///
+/// let peak = analog_response(
+///     FilterType::Peak { gain_db: -9.0 },
+///     250.0,
+///     2.0,
+///     250.0,
+/// );
+/// assert!((peak.magnitude_db() + 9.0).abs() < 0.001);
+///
+/// // A low shelf reaches its full gain far below its corner frequency.
+/// let shelf = analog_response(
+///     FilterType::LowShelf { gain_db: 4.0 },
+///     1_000.0,
+///     0.707,
+///     10.0,
+/// );
+/// assert!((shelf.magnitude_db() - 4.0).abs() < 0.01);
+/// ```
+pub fn analog_response(
+    filter_type: FilterType,
+    frequency: f32,
+    q: f32,
+    at_frequency: f32,
+) -> Response {
+    // The frequency relative to the center, so that `s = jw`.
+    let w =
+        f64::from(at_frequency) / f64::from(frequency).max(std::f64::EPSILON);
+    let q = f64::from(q).max(std::f64::EPSILON);
+
+    // Each polynomial is `c0 + c1 s + c2 s^2`.
+    let (num, den) = match filter_type {
+        FilterType::LowPass => ([1.0, 0.0, 0.0], [1.0, 1.0 / q, 1.0]),
+        FilterType::HighPass => ([0.0, 0.0, 1.0], [1.0, 1.0 / q, 1.0]),
+        FilterType::FirstOrderLowPass => ([1.0, 0.0, 0.0], [1.0, 1.0, 0.0]),
+        FilterType::FirstOrderHighPass => ([0.0, 1.0, 0.0], [1.0, 1.0, 0.0]),
+        FilterType::BandPass => ([0.0, 1.0 / q, 0.0], [1.0, 1.0 / q, 1.0]),
+        FilterType::Notch => ([1.0, 0.0, 1.0], [1.0, 1.0 / q, 1.0]),
+        FilterType::AllPass => ([1.0, -1.0 / q, 1.0], [1.0, 1.0 / q, 1.0]),
+        FilterType::Peak { gain_db } => {
+            let a = gain_to_a(gain_db);
+            ([1.0, a / q, 1.0], [1.0, 1.0 / (a * q), 1.0])
+        }
+        FilterType::LowShelf { gain_db } => {
+            let a = gain_to_a(gain_db);
+            let sqrt_a = a.sqrt();
+            ([a * a, a * sqrt_a / q, a], [1.0, sqrt_a / q, a])
+        }
+        FilterType::HighShelf { gain_db } => {
+            let a = gain_to_a(gain_db);
+            let sqrt_a = a.sqrt();
+            ([a, a * sqrt_a / q, a * a], [a, sqrt_a / q, 1.0])
+        }
+    };
+
+    polynomial_ratio(
+        num[0] - (num[2] * w * w),
+        num[1] * w,
+        den[0] - (den[2] * w * w),
+        den[1] * w,
+    )
+}
+
+/// Returns the frequency (in Hz) at the center of each of `width` pixels
+/// that are spaced according to `freq_range`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::biquad::frequency_grid;
+/// use iced_audio::FreqRange;
+///
+/// let freq_range = FreqRange::new(20.0, 20_480.0);
+/// let frequencies = frequency_grid(10, &freq_range);
+///
+/// // Each pixel covers one octave.
+/// assert_eq!(frequencies.len(), 10);
+/// assert!((frequencies[1] / frequencies[0] - 2.0).abs() < 0.001);
+/// ```
+pub fn frequency_grid(width: usize, freq_range: &FreqRange) -> Vec<f32> {
+    let width_recip = 1.0 / width.max(1) as f32;
+
+    (0..width)
+        .map(|x| {
+            freq_range
+                .unmap_to_value(Normal::new((x as f32 + 0.5) * width_recip))
+        })
+        .collect()
+}
+
+#[inline]
+fn gain_to_a(gain_db: f32) -> f64 {
+    10.0_f64.powf(f64::from(gain_db) / 40.0)
+}
+
+/// Returns the response of `num / den`, where both are complex numbers.
+#[inline]
+fn polynomial_ratio(
+    num_re: f64,
+    num_im: f64,
+    den_re: f64,
+    den_im: f64,
+) -> Response {
+    let num_magnitude = num_re.hypot(num_im);
+    let den_magnitude = den_re.hypot(den_im).max(std::f64::MIN_POSITIVE);
+
+    Response {
+        magnitude: (num_magnitude / den_magnitude) as f32,
+        phase: (num_im.atan2(num_re) - den_im.atan2(den_re)) as f32,
+    }
+}
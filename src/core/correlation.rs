@@ -0,0 +1,214 @@
+//! Helpers for measuring the phase correlation and balance of a stereo
+//! signal, to be displayed by a phase meter.
+//!
+//! The phase correlation of a stereo signal ranges from `-1.0` to `1.0`:
+//! * `1.0` - both channels are identical (mono)
+//! * `0.0` - the channels are unrelated (wide stereo), or only one channel
+//!   has signal
+//! * `-1.0` - the channels are identical but with inverted phase, which
+//!   cancels out when summed to mono
+//!
+//! A [`CorrelationMeter`] takes in blocks of stereo samples of any size,
+//! and averages the correlation and balance over a configurable integration
+//! time.
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::core::correlation::CorrelationMeter;
+//!
+//! let mut meter = CorrelationMeter::new(44_100.0);
+//!
+//! let left: Vec<f32> = (0..44_100)
+//!     .map(|i| (i as f32 * 0.05).sin())
+//!     .collect();
+//! let right: Vec<f32> = left.iter().map(|s| -s).collect();
+//!
+//! meter.process(&left, &right);
+//!
+//! // The channels cancel each other out.
+//! assert!((meter.correlation() + 1.0).abs() < 0.001);
+//! // Both channels are equally loud.
+//! assert!(meter.balance().abs() < 0.001);
+//! ```
+//!
+//! [`CorrelationMeter`]: struct.CorrelationMeter.html
+
+/// The default integration time of a [`CorrelationMeter`] in seconds.
+///
+/// [`CorrelationMeter`]: struct.CorrelationMeter.html
+pub static DEFAULT_INTEGRATION_TIME: f32 = 0.3;
+
+/// The mean power below which a signal is treated as silence. This is
+/// about `-100` dBFS.
+static SILENCE_POWER: f64 = 1.0e-10;
+
+/// Returns the phase correlation of a whole block of stereo samples, from
+/// `-1.0` to `1.0`. Silence has a correlation of `0.0`.
+///
+/// If the channels differ in length, the extra samples of the longer one
+/// are ignored.
+pub fn correlation_of(left: &[f32], right: &[f32]) -> f32 {
+    let (lr, ll, rr) = left.iter().zip(right.iter()).fold(
+        (0.0, 0.0, 0.0),
+        |(lr, ll, rr): (f64, f64, f64), (l, r)| {
+            let l = f64::from(*l);
+            let r = f64::from(*r);
+            (lr + (l * r), ll + (l * l), rr + (r * r))
+        },
+    );
+
+    let len = left.len().min(right.len()).max(1) as f64;
+
+    correlation_from_powers(lr / len, ll / len, rr / len)
+}
+
+/// Returns the balance of a whole block of stereo samples, from `-1.0`
+/// (only the left channel has signal) to `1.0` (only the right channel has
+/// signal). Silence has a balance of `0.0`.
+///
+/// If the channels differ in length, the extra samples of the longer one
+/// are ignored.
+pub fn balance_of(left: &[f32], right: &[f32]) -> f32 {
+    let (ll, rr) = left.iter().zip(right.iter()).fold(
+        (0.0, 0.0),
+        |(ll, rr): (f64, f64), (l, r)| {
+            let l = f64::from(*l);
+            let r = f64::from(*r);
+            (ll + (l * l), rr + (r * r))
+        },
+    );
+
+    let len = left.len().min(right.len()).max(1) as f64;
+
+    balance_from_powers(ll / len, rr / len)
+}
+
+fn correlation_from_powers(lr: f64, ll: f64, rr: f64) -> f32 {
+    if ll < SILENCE_POWER || rr < SILENCE_POWER {
+        0.0
+    } else {
+        (lr / (ll * rr).sqrt()).max(-1.0).min(1.0) as f32
+    }
+}
+
+fn balance_from_powers(ll: f64, rr: f64) -> f32 {
+    let sum = ll + rr;
+
+    if sum < SILENCE_POWER {
+        0.0
+    } else {
+        ((rr - ll) / sum) as f32
+    }
+}
+
+/// Measures the phase correlation and balance of a stereo signal, averaged
+/// over an integration time.
+///
+/// The products of the channels are averaged with a one-pole lowpass
+/// filter, so older samples fade out of the measurement smoothly instead
+/// of dropping out all at once. A longer integration time gives a steadier
+/// reading that reacts more slowly.
+#[derive(Debug, Clone)]
+pub struct CorrelationMeter {
+    sample_rate: f32,
+    integration_time: f32,
+    coeff: f64,
+
+    lr: f64,
+    ll: f64,
+    rr: f64,
+}
+
+impl CorrelationMeter {
+    /// Creates a new `CorrelationMeter` with an integration time of
+    /// [`DEFAULT_INTEGRATION_TIME`].
+    ///
+    /// * `sample_rate` - The sample rate of the incoming audio in Hz.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `sample_rate` is not positive.
+    ///
+    /// [`DEFAULT_INTEGRATION_TIME`]: static.DEFAULT_INTEGRATION_TIME.html
+    pub fn new(sample_rate: f32) -> Self {
+        assert!(sample_rate > 0.0, "sample_rate must be positive");
+
+        let mut meter = Self {
+            sample_rate,
+            integration_time: DEFAULT_INTEGRATION_TIME,
+            coeff: 0.0,
+
+            lr: 0.0,
+            ll: 0.0,
+            rr: 0.0,
+        };
+
+        meter.set_integration_time(DEFAULT_INTEGRATION_TIME);
+
+        meter
+    }
+
+    /// Returns the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Returns the integration time in seconds.
+    pub fn integration_time(&self) -> f32 {
+        self.integration_time
+    }
+
+    /// Sets the time in seconds over which the measurement is averaged. A
+    /// time of `0.0` disables averaging, so only the latest sample is
+    /// measured.
+    pub fn set_integration_time(&mut self, seconds: f32) {
+        self.integration_time = seconds.max(0.0);
+
+        let samples = f64::from(self.integration_time * self.sample_rate);
+
+        self.coeff = if samples > 0.0 {
+            (-1.0 / samples).exp()
+        } else {
+            0.0
+        };
+    }
+
+    /// Clears the measurement.
+    pub fn reset(&mut self) {
+        self.lr = 0.0;
+        self.ll = 0.0;
+        self.rr = 0.0;
+    }
+
+    /// Processes a block of stereo samples.
+    ///
+    /// If the channels differ in length, the extra samples of the longer one
+    /// are ignored.
+    pub fn process(&mut self, left: &[f32], right: &[f32]) {
+        let a = self.coeff;
+        let b = 1.0 - a;
+
+        for (l, r) in left.iter().zip(right.iter()) {
+            let l = f64::from(*l);
+            let r = f64::from(*r);
+
+            self.lr = (a * self.lr) + (b * l * r);
+            self.ll = (a * self.ll) + (b * l * l);
+            self.rr = (a * self.rr) + (b * r * r);
+        }
+    }
+
+    /// Returns the current phase correlation, from `-1.0` to `1.0`.
+    /// Silence has a correlation of `0.0`.
+    pub fn correlation(&self) -> f32 {
+        correlation_from_powers(self.lr, self.ll, self.rr)
+    }
+
+    /// Returns the current balance, from `-1.0` (only the left channel has
+    /// signal) to `1.0` (only the right channel has signal). Silence has a
+    /// balance of `0.0`.
+    pub fn balance(&self) -> f32 {
+        balance_from_powers(self.ll, self.rr)
+    }
+}
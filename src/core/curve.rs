@@ -0,0 +1,105 @@
+//! Curved segments between two points, where the amount of curvature is
+//! controlled by a [`Normal`].
+//!
+//! A curve [`Normal`] of `0.5` is a straight line. Values above `0.5` bend
+//! the segment upwards (towards the top corner of the box spanned by the two
+//! points), and values below `0.5` bend it downwards. `0.0` and `1.0` bend
+//! the segment all the way into the corners.
+//!
+//! Coordinates are in "value space", meaning `y` increases upwards.
+//!
+//! [`Normal`]: ../struct.Normal.html
+
+use crate::core::Normal;
+
+/// Returns the control point of the quadratic bezier curve from
+/// `(x0, y0)` to `(x1, y1)` with the given `curve`.
+///
+/// `x1` must be greater than or equal to `x0`.
+pub fn control_point(
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+    curve: Normal,
+) -> (f32, f32) {
+    let amount = (curve.as_f32() - 0.5) * 2.0;
+
+    let mid_x = (x0 + x1) / 2.0;
+    let mid_y = (y0 + y1) / 2.0;
+
+    // The corner that the curve bends towards.
+    let (corner_x, corner_y) = if (amount > 0.0) == (y1 >= y0) {
+        (x0, y1)
+    } else {
+        (x1, y0)
+    };
+
+    let amount = amount.abs();
+
+    (
+        mid_x + (corner_x - mid_x) * amount,
+        mid_y + (corner_y - mid_y) * amount,
+    )
+}
+
+/// Returns the `y` value of the curve from `(x0, y0)` to `(x1, y1)` at
+/// position `x`.
+///
+/// `x` is constrained to the range `x0 <= x <= x1`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::curve;
+/// use iced_audio::Normal;
+///
+/// // A straight line.
+/// let y = curve::value_at(0.0, 0.0, 1.0, 1.0, Normal::center(), 0.25);
+/// assert!((y - 0.25).abs() < 0.0001);
+///
+/// // Bent upwards, so it rises faster at the start.
+/// let y = curve::value_at(0.0, 0.0, 1.0, 1.0, Normal::new(0.9), 0.25);
+/// assert!(y > 0.25);
+///
+/// // Bent downwards, so it rises slower at the start.
+/// let y = curve::value_at(0.0, 0.0, 1.0, 1.0, Normal::new(0.1), 0.25);
+/// assert!(y < 0.25);
+/// ```
+pub fn value_at(
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+    curve: Normal,
+    x: f32,
+) -> f32 {
+    if x1 - x0 <= std::f32::EPSILON {
+        return y1;
+    }
+
+    let x = x.max(x0).min(x1);
+    let (cx, cy) = control_point(x0, y0, x1, y1, curve);
+
+    // Solve x(t) = x for the bezier parameter t. Since the control point
+    // always lies between `x0` and `x1`, x(t) is monotonic.
+    let a = x0 - (2.0 * cx) + x1;
+    let b = 2.0 * (cx - x0);
+    let c = x0 - x;
+
+    let t = if a.abs() < 0.000_01 {
+        if b.abs() < 0.000_01 {
+            0.0
+        } else {
+            -c / b
+        }
+    } else {
+        let discriminant = ((b * b) - (4.0 * a * c)).max(0.0);
+        (-b + discriminant.sqrt()) / (2.0 * a)
+    };
+
+    let t = t.max(0.0).min(1.0);
+    let inv_t = 1.0 - t;
+
+    (inv_t * inv_t * y0) + (2.0 * inv_t * t * cy) + (t * t * y1)
+}
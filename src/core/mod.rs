@@ -3,12 +3,17 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod analysis;
+pub mod biquad;
+pub mod correlation;
+pub mod curve;
 pub mod knob_angle_range;
 pub mod math;
 pub mod modulation_range;
 pub mod normal;
 pub mod normal_param;
 pub mod offset;
+pub mod peak_cache;
 pub mod range;
 
 pub use knob_angle_range::*;
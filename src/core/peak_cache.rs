@@ -0,0 +1,387 @@
+//! A multi-resolution summary of the peaks of an audio clip, so that
+//! waveforms can be drawn at any zoom level without scanning every sample.
+//!
+//! A [`PeakCache`] owns the samples of a clip, and keeps levels of
+//! min / max / RMS summaries on top of them. Each level summarizes
+//! [`LEVEL_FACTOR`] times as many samples per entry as the level below it,
+//! like the mipmaps of a texture. Samples can be appended while recording,
+//! and only the summaries at the end of each level are updated.
+//!
+//! A [`PeakCache`] does not depend on any widget, so it can be built on a
+//! worker thread and then sent to the GUI.
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::core::peak_cache::PeakCache;
+//!
+//! let samples: Vec<f32> = (0..100_000)
+//!     .map(|i| if i == 54_321 { 0.9 } else { 0.1 })
+//!     .collect();
+//!
+//! let cache = PeakCache::from_samples(samples, 64);
+//!
+//! // Summarize the whole clip into 100 pixels.
+//! let mut peaks = vec![Default::default(); 100];
+//! cache.peaks(0.0, cache.len() as f64, &mut peaks);
+//!
+//! // The short spike is not lost when zoomed out.
+//! assert_eq!(peaks[54].max, 0.9);
+//! assert_eq!(peaks[10].max, 0.1);
+//! ```
+//!
+//! [`PeakCache`]: struct.PeakCache.html
+//! [`LEVEL_FACTOR`]: static.LEVEL_FACTOR.html
+
+/// The number of entries of a level that are summarized by each entry of the
+/// level above it.
+pub static LEVEL_FACTOR: usize = 4;
+
+/// The default number of samples summarized by each entry of the lowest
+/// level.
+pub static DEFAULT_BLOCK_SIZE: usize = 64;
+
+/// The minimum, maximum, and RMS amplitude of a range of samples.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Peak {
+    /// The lowest sample in the range
+    pub min: f32,
+    /// The highest sample in the range
+    pub max: f32,
+    /// The root mean square of the samples in the range
+    pub rms: f32,
+}
+
+impl Peak {
+    /// Returns the peak of the given samples. The peak of an empty slice
+    /// is silence.
+    pub fn from_samples(samples: &[f32]) -> Self {
+        Summary::from_samples(samples).peak()
+    }
+}
+
+/// Fills `peaks` with the peaks of evenly sized ranges of `samples` from
+/// `start` to `end`. `start` and `end` are positions in samples, and may be
+/// fractional. Each range covers at least one sample.
+///
+/// This scans every sample from `start` to `end`. Use a [`PeakCache`] for
+/// long clips.
+///
+/// [`PeakCache`]: struct.PeakCache.html
+pub fn peaks_of_samples(
+    samples: &[f32],
+    start: f64,
+    end: f64,
+    peaks: &mut [Peak],
+) {
+    if peaks.is_empty() {
+        return;
+    }
+
+    let step = (end - start) / peaks.len() as f64;
+
+    for (i, peak) in peaks.iter_mut().enumerate() {
+        let range_start = start + (step * i as f64);
+        let (first, last) =
+            covered_range(range_start, range_start + step, 1, samples.len());
+
+        *peak = Peak::from_samples(&samples[first..last]);
+    }
+}
+
+/// A summary of a range of samples.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Summary {
+    min: f32,
+    max: f32,
+    sum_squares: f64,
+    len: usize,
+}
+
+impl Summary {
+    const EMPTY: Summary = Summary {
+        min: std::f32::MAX,
+        max: std::f32::MIN,
+        sum_squares: 0.0,
+        len: 0,
+    };
+
+    fn from_samples(samples: &[f32]) -> Self {
+        samples.iter().fold(Summary::EMPTY, |mut summary, sample| {
+            summary.min = summary.min.min(*sample);
+            summary.max = summary.max.max(*sample);
+            summary.sum_squares += f64::from(*sample) * f64::from(*sample);
+            summary.len += 1;
+            summary
+        })
+    }
+
+    fn merge(summaries: &[Summary]) -> Self {
+        summaries.iter().fold(Summary::EMPTY, |mut summary, other| {
+            summary.min = summary.min.min(other.min);
+            summary.max = summary.max.max(other.max);
+            summary.sum_squares += other.sum_squares;
+            summary.len += other.len;
+            summary
+        })
+    }
+
+    fn peak(&self) -> Peak {
+        if self.len == 0 {
+            Peak::default()
+        } else {
+            Peak {
+                min: self.min,
+                max: self.max,
+                rms: (self.sum_squares / self.len as f64).sqrt() as f32,
+            }
+        }
+    }
+}
+
+/// Returns the indexes of the entries of `entry_size` samples that cover
+/// the range from `start` to `end` (in samples). At least one entry is
+/// covered as long as the range overlaps the clip.
+fn covered_range(
+    start: f64,
+    end: f64,
+    entry_size: usize,
+    num_entries: usize,
+) -> (usize, usize) {
+    let entry_size = entry_size as f64;
+
+    let first =
+        ((start.max(0.0) / entry_size).floor() as usize).min(num_entries);
+    let last = ((end.max(0.0) / entry_size).ceil() as usize)
+        .min(num_entries)
+        .max(first);
+
+    if first == last && last < num_entries {
+        (first, last + 1)
+    } else {
+        (first, last)
+    }
+}
+
+/// A multi-resolution summary of the peaks of an audio clip.
+///
+/// The cache owns the samples of the clip, so it can be used as the buffer
+/// of a recording. Queries that are zoomed in further than the block size
+/// read the samples directly, and all other queries read the coarsest level
+/// that still has at least one entry per pixel.
+#[derive(Debug, Clone)]
+pub struct PeakCache {
+    samples: Vec<f32>,
+    block_size: usize,
+    levels: Vec<Vec<Summary>>,
+}
+
+impl PeakCache {
+    /// Creates a new empty [`PeakCache`].
+    ///
+    /// * `block_size` - the number of samples summarized by each entry of
+    /// the lowest level
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `block_size` is `0`.
+    ///
+    /// [`PeakCache`]: struct.PeakCache.html
+    pub fn new(block_size: usize) -> Self {
+        Self::from_samples(Vec::new(), block_size)
+    }
+
+    /// Creates a new [`PeakCache`] that takes ownership of the samples of a
+    /// clip and summarizes them.
+    ///
+    /// * `block_size` - the number of samples summarized by each entry of
+    /// the lowest level
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `block_size` is `0`.
+    ///
+    /// [`PeakCache`]: struct.PeakCache.html
+    pub fn from_samples(samples: Vec<f32>, block_size: usize) -> Self {
+        assert!(block_size > 0, "block_size must be greater than 0");
+
+        let mut cache = Self {
+            samples,
+            block_size,
+            levels: Vec::new(),
+        };
+
+        cache.update_from(0);
+
+        cache
+    }
+
+    /// Returns the number of samples in the clip.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if there are no samples in the clip.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the samples of the clip.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Returns the samples of the clip and drops the summaries.
+    pub fn into_samples(self) -> Vec<f32> {
+        self.samples
+    }
+
+    /// Returns the number of samples summarized by each entry of the lowest
+    /// level.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the number of summary levels.
+    pub fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Appends samples to the end of the clip, such as while recording. Only
+    /// the summaries at the end of each level are updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::core::peak_cache::PeakCache;
+    ///
+    /// let samples: Vec<f32> =
+    ///     (0..10_000).map(|i| ((i % 100) as f32 / 50.0) - 1.0).collect();
+    ///
+    /// let mut recording = PeakCache::new(64);
+    /// for block in samples.chunks(100) {
+    ///     recording.append(block);
+    /// }
+    ///
+    /// let whole = PeakCache::from_samples(samples, 64);
+    ///
+    /// let mut a = vec![Default::default(); 37];
+    /// let mut b = vec![Default::default(); 37];
+    /// recording.peaks(0.0, 10_000.0, &mut a);
+    /// whole.peaks(0.0, 10_000.0, &mut b);
+    ///
+    /// assert_eq!(a, b);
+    /// assert_eq!(recording.num_levels(), whole.num_levels());
+    /// ```
+    pub fn append(&mut self, samples: &[f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let start = self.samples.len();
+        self.samples.extend_from_slice(samples);
+
+        self.update_from(start);
+    }
+
+    /// Removes all samples and summaries.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.levels.clear();
+    }
+
+    /// Fills `peaks` with the peaks of evenly sized ranges from `start` to
+    /// `end`, such as one range per pixel. `start` and `end` are positions
+    /// in samples, and may be fractional.
+    pub fn peaks(&self, start: f64, end: f64, peaks: &mut [Peak]) {
+        if peaks.is_empty() {
+            return;
+        }
+
+        let samples_per_peak = (end - start) / peaks.len() as f64;
+
+        if samples_per_peak < self.block_size as f64 || self.levels.is_empty() {
+            peaks_of_samples(&self.samples, start, end, peaks);
+            return;
+        }
+
+        // The coarsest level whose entries are no larger than each range.
+        let mut level = 0;
+        let mut entry_size = self.block_size;
+        while level + 1 < self.levels.len()
+            && ((entry_size * LEVEL_FACTOR) as f64) <= samples_per_peak
+        {
+            level += 1;
+            entry_size *= LEVEL_FACTOR;
+        }
+
+        let entries = &self.levels[level];
+
+        for (i, peak) in peaks.iter_mut().enumerate() {
+            let range_start = start + (samples_per_peak * i as f64);
+            let (first, last) = covered_range(
+                range_start,
+                range_start + samples_per_peak,
+                entry_size,
+                entries.len(),
+            );
+
+            *peak = Summary::merge(&entries[first..last]).peak();
+        }
+    }
+
+    /// Recomputes the summaries of every level that cover the samples from
+    /// `start` to the end of the clip.
+    fn update_from(&mut self, start: usize) {
+        let block_size = self.block_size;
+        let len = self.samples.len();
+
+        if self.levels.is_empty() {
+            self.levels.push(Vec::new());
+        }
+
+        let mut first = start / block_size;
+
+        let lowest = &mut self.levels[0];
+        lowest.truncate(first);
+        for i in first..((len + block_size - 1) / block_size) {
+            let end = ((i + 1) * block_size).min(len);
+            lowest.push(Summary::from_samples(
+                &self.samples[i * block_size..end],
+            ));
+        }
+
+        let mut level = 1;
+        loop {
+            let below_len = self.levels[level - 1].len();
+            if below_len <= 1 {
+                self.levels.truncate(level);
+                break;
+            }
+
+            first /= LEVEL_FACTOR;
+
+            if self.levels.len() <= level {
+                self.levels.push(Vec::new());
+            }
+
+            let (lower, upper) = self.levels.split_at_mut(level);
+            let below = &lower[level - 1];
+            let current = &mut upper[0];
+
+            current.truncate(first);
+            for i in first..((below_len + LEVEL_FACTOR - 1) / LEVEL_FACTOR) {
+                let end = ((i + 1) * LEVEL_FACTOR).min(below_len);
+                current.push(Summary::merge(&below[i * LEVEL_FACTOR..end]));
+            }
+
+            level += 1;
+        }
+    }
+}
+
+impl Default for PeakCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_BLOCK_SIZE)
+    }
+}
@@ -108,6 +108,156 @@ impl Default for FloatRange {
     }
 }
 
+/// A range that maps a continuous, skewed range of `f32` values to a
+/// [`Normal`]. This is useful for parameters such as envelope times, where
+/// small values need more resolution than large ones.
+///
+/// A `skew` of `1.0` is linear. A `skew` greater than `1.0` gives more
+/// resolution to values near `min`, and a `skew` less than `1.0` gives more
+/// resolution to values near `max`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::SkewedRange;
+///
+/// // An attack time from 0 to 10 seconds, where the center of the
+/// // widget is 500 milliseconds.
+/// let range = SkewedRange::with_center(0.0, 10.0, 0.5);
+///
+/// let value = range.unmap_to_value(0.5.into());
+/// assert!((value - 0.5).abs() < 0.0001);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone)]
+pub struct SkewedRange {
+    min: f32,
+    max: f32,
+    span: f32,
+    span_recip: f32,
+    skew: f32,
+    skew_recip: f32,
+}
+
+impl SkewedRange {
+    /// Creates a new `SkewedRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `skew` - the exponent applied to the [`Normal`] when it is unmapped
+    /// to a value
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `skew` <= `0.0`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn new(min: f32, max: f32, skew: f32) -> Self {
+        assert!(max > min);
+        assert!(skew > 0.0);
+
+        let span = max - min;
+        let span_recip = span.recip();
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip,
+            skew,
+            skew_recip: skew.recip(),
+        }
+    }
+
+    /// Creates a new `SkewedRange` where the center of the [`Normal`] range
+    /// (`0.5`) maps to the given `center` value.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `center` - the value at the center of the range
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `center` <= `min` or `center` >= `max`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn with_center(min: f32, max: f32, center: f32) -> Self {
+        assert!(max > min);
+        assert!(center > min && center < max);
+
+        let skew = ((center - min) / (max - min)).ln() / 0.5_f32.ln();
+
+        Self::new(min, max, skew)
+    }
+
+    fn constrain(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `min`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(self.min),
+            default: self.map_to_normal(self.min),
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.constrain(value);
+        ((value - self.min) * self.span_recip)
+            .powf(self.skew_recip)
+            .into()
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        (normal.as_f32().powf(self.skew) * self.span) + self.min
+    }
+}
+
+impl Default for SkewedRange {
+    fn default() -> Self {
+        SkewedRange::new(0.0, 1.0, 1.0)
+    }
+}
+
 /// A range that defines a discrete linear range of i32 values
 #[derive(Debug, Copy, Clone)]
 pub struct IntRange {
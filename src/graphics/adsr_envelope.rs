@@ -0,0 +1,165 @@
+//! Display an ADSR envelope with optional delay and hold stages.
+
+use crate::core::curve;
+use crate::native::adsr_envelope;
+use iced_graphics::canvas::{Fill, Frame, LineCap, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::adsr_envelope::{Handle, Segment, Stage, State};
+pub use crate::style::adsr_envelope::{HandleStyle, Style, StyleSheet};
+
+/// An ADSR envelope GUI widget with optional delay and hold stages.
+pub type ADSREnvelope<'a, Message, Backend> =
+    adsr_envelope::ADSREnvelope<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> adsr_envelope::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        handles: &[Handle],
+        segments: &[Segment],
+        active_handle: Option<Stage>,
+        active_curve: Option<Stage>,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x.floor(),
+                y: bounds.y.floor(),
+                width: bounds.width.floor(),
+                height: bounds.height.floor(),
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back];
+
+        if !segments.is_empty() && bounds.width > 0.0 && bounds.height > 0.0 {
+            let to_local = |point: Point| {
+                Point::new(point.x - bounds.x, point.y - bounds.y)
+            };
+
+            let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+            if style.fill_color.a > 0.0 {
+                let first = to_local(segments[0].start);
+                let last = to_local(segments[segments.len() - 1].end);
+
+                let path = Path::new(|p| {
+                    p.move_to(Point::new(first.x, bounds.height));
+                    p.line_to(first);
+
+                    for segment in segments.iter() {
+                        let (control, end) = segment_curve(segment, &to_local);
+                        p.quadratic_curve_to(control, end);
+                    }
+
+                    p.line_to(Point::new(last.x, bounds.height));
+                    p.close();
+                });
+
+                frame.fill(
+                    &path,
+                    Fill {
+                        color: style.fill_color,
+                        ..Fill::default()
+                    },
+                );
+            }
+
+            for segment in segments.iter() {
+                let start = to_local(segment.start);
+                let (control, end) = segment_curve(segment, &to_local);
+
+                let path = Path::new(|p| {
+                    p.move_to(start);
+                    p.quadratic_curve_to(control, end);
+                });
+
+                let color = if active_curve == Some(segment.stage) {
+                    style.active_line_color
+                } else {
+                    style.line_color
+                };
+
+                frame.stroke(
+                    &path,
+                    Stroke {
+                        width: style.line_width,
+                        color,
+                        line_cap: LineCap::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for handle in handles.iter() {
+            let handle_style = if active_handle == Some(handle.stage) {
+                &style.active_handle
+            } else {
+                &style.handle
+            };
+
+            let diameter = handle_style.diameter;
+            let radius = diameter / 2.0;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: (handle.position.x - radius).round(),
+                    y: (handle.position.y - radius).round(),
+                    width: diameter,
+                    height: diameter,
+                },
+                background: Background::Color(handle_style.color),
+                border_radius: radius,
+                border_width: handle_style.border_width,
+                border_color: handle_style.border_color,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns the control point and end point of the quadratic curve of a
+/// segment, in local coordinates.
+fn segment_curve(
+    segment: &Segment,
+    to_local: &impl Fn(Point) -> Point,
+) -> (Point, Point) {
+    let start = to_local(segment.start);
+    let end = to_local(segment.end);
+
+    // The curve is defined with `y` increasing upwards.
+    let (control_x, control_y) =
+        curve::control_point(start.x, -start.y, end.x, -end.y, segment.curve);
+
+    (Point::new(control_x, -control_y), end)
+}
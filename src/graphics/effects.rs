@@ -0,0 +1,233 @@
+//! Draw drop shadows, inner shadows, and glows
+//!
+//! The soft edges are drawn from layers of translucent primitives that grow
+//! outward (or inward) from the shape. The alpha of each layer is picked so
+//! that the layers add up to the alpha of the color where they all overlap.
+
+use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
+use iced_graphics::Primitive;
+use iced_native::{Background, Color, Point, Rectangle, Size, Vector};
+
+use crate::core::Normal;
+use crate::graphics::gradient::StrokedArc;
+
+pub use crate::style::effects::*;
+
+/// The number of layers in a soft edge
+const LAYERS: usize = 8;
+
+/// Draws a [`Shadow`] underneath a (rounded) rectangle.
+///
+/// [`Shadow`]: struct.Shadow.html
+pub fn shadow(
+    bounds: Rectangle,
+    border_radius: f32,
+    shadow: &Shadow,
+) -> Primitive {
+    let bounds = Rectangle {
+        x: bounds.x + shadow.offset.x,
+        y: bounds.y + shadow.offset.y,
+        ..bounds
+    };
+
+    Primitive::Group {
+        primitives: layers(shadow.color, shadow.blur)
+            .map(|(color, grow)| {
+                let grow = shadow.spread + grow;
+
+                Primitive::Quad {
+                    bounds: expand(bounds, grow),
+                    background: Background::Color(color),
+                    border_radius: border_radius + grow,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Draws an [`InnerShadow`] on top of a (rounded) rectangle.
+///
+/// [`InnerShadow`]: struct.InnerShadow.html
+pub fn inner_shadow(
+    bounds: Rectangle,
+    border_radius: f32,
+    inner_shadow: &InnerShadow,
+) -> Primitive {
+    if inner_shadow.width <= 0.0 {
+        return Primitive::None;
+    }
+
+    Primitive::Group {
+        primitives: layers(inner_shadow.color, inner_shadow.width)
+            .map(|(color, width)| Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius,
+                border_width: width,
+                border_color: color,
+            })
+            .collect(),
+    }
+}
+
+/// Draws a [`Glow`] around a (rounded) rectangle. The glow is drawn
+/// underneath the rectangle, so it should be drawn before it.
+///
+/// [`Glow`]: struct.Glow.html
+pub fn glow(
+    bounds: Rectangle,
+    border_radius: f32,
+    glow: &Glow,
+    value: Normal,
+) -> Primitive {
+    let color = match glow_color(glow, value) {
+        Some(color) => color,
+        None => return Primitive::None,
+    };
+
+    Primitive::Group {
+        primitives: layers(color, glow.width)
+            .map(|(color, grow)| Primitive::Quad {
+                bounds: expand(bounds, grow),
+                background: Background::Color(color),
+                border_radius: border_radius + grow,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect(),
+    }
+}
+
+/// Draws a [`Shadow`] and a [`Glow`] around a (rounded) rectangle, such as
+/// the handle of a slider. They are drawn underneath the rectangle, so they
+/// should be drawn before it.
+///
+/// [`Shadow`]: struct.Shadow.html
+/// [`Glow`]: struct.Glow.html
+pub fn shadow_and_glow(
+    bounds: Rectangle,
+    border_radius: f32,
+    shadow: Option<&Shadow>,
+    glow: Option<&Glow>,
+    value: Normal,
+) -> Primitive {
+    let shadow = match shadow {
+        Some(style) => self::shadow(bounds, border_radius, style),
+        None => Primitive::None,
+    };
+
+    let glow = match glow {
+        Some(style) => self::glow(bounds, border_radius, style, value),
+        None => Primitive::None,
+    };
+
+    Primitive::Group {
+        primitives: vec![shadow, glow],
+    }
+}
+
+/// Draws a [`Shadow`] underneath a stroked arc.
+///
+/// [`Shadow`]: struct.Shadow.html
+pub fn arc_shadow(arc: &StrokedArc, shadow: &Shadow) -> Primitive {
+    let center = Point::new(
+        arc.center.x + shadow.offset.x,
+        arc.center.y + shadow.offset.y,
+    );
+
+    stroke_layers(
+        &StrokedArc { center, ..*arc },
+        shadow.color,
+        shadow.spread,
+        shadow.blur,
+    )
+}
+
+/// Draws a [`Glow`] around a stroked arc. The glow is drawn underneath the
+/// arc, so it should be drawn before it.
+///
+/// [`Glow`]: struct.Glow.html
+pub fn arc_glow(arc: &StrokedArc, glow: &Glow, value: Normal) -> Primitive {
+    match glow_color(glow, value) {
+        Some(color) => stroke_layers(arc, color, 0.0, glow.width),
+        None => Primitive::None,
+    }
+}
+
+fn glow_color(glow: &Glow, value: Normal) -> Option<Color> {
+    let alpha = glow.color.a * glow.intensity_at(value);
+
+    if alpha <= 0.0 || glow.width <= 0.0 {
+        None
+    } else {
+        Some(Color {
+            a: alpha,
+            ..glow.color
+        })
+    }
+}
+
+/// Returns the color and the width of each layer of a soft edge that is
+/// `width` wide.
+fn layers(color: Color, width: f32) -> impl Iterator<Item = (Color, f32)> {
+    let layers = if width > 0.0 { LAYERS } else { 1 };
+
+    let color = Color {
+        a: 1.0 - (1.0 - color.a.min(1.0)).powf(1.0 / layers as f32),
+        ..color
+    };
+
+    (0..layers).map(move |i| (color, width * (i + 1) as f32 / layers as f32))
+}
+
+fn stroke_layers(
+    arc: &StrokedArc,
+    color: Color,
+    spread: f32,
+    width: f32,
+) -> Primitive {
+    // Meshes are clipped to the size of their frame, so the frame must be
+    // large enough to hold the widest layer.
+    let extent = (arc.radius + (arc.width / 2.0) + spread + width).ceil();
+    let origin = Point::new(arc.center.x - extent, arc.center.y - extent);
+    let center = Point::new(extent, extent);
+
+    let mut frame = Frame::new(Size::new(extent * 2.0, extent * 2.0));
+
+    let path = Path::new(|path| {
+        path.arc(Arc {
+            center,
+            radius: arc.radius,
+            start_angle: arc.start_angle,
+            end_angle: arc.end_angle,
+        })
+    });
+
+    for (color, grow) in layers(color, width) {
+        frame.stroke(
+            &path,
+            Stroke {
+                width: arc.width + (spread + grow) * 2.0,
+                color,
+                line_cap: arc.cap,
+                ..Stroke::default()
+            },
+        );
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(origin.x, origin.y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
+fn expand(bounds: Rectangle, by: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x - by,
+        y: bounds.y - by,
+        width: bounds.width + by * 2.0,
+        height: bounds.height + by * 2.0,
+    }
+}
@@ -0,0 +1,209 @@
+//! Display an editor for an envelope made of points connected by curved
+//! segments.
+
+use crate::core::curve;
+use crate::native::envelope_editor;
+use iced_graphics::canvas::{Frame, LineCap, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::envelope_editor::{value_at, EnvelopePoint, State};
+pub use crate::style::envelope_editor::{PointStyle, Style, StyleSheet};
+
+/// An editor for an envelope made of points connected by curved segments.
+pub type EnvelopeEditor<'a, Message, Backend> =
+    envelope_editor::EnvelopeEditor<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> envelope_editor::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[EnvelopePoint],
+        active_point: Option<usize>,
+        active_segment: Option<usize>,
+        is_dragging: bool,
+        loop_range: Option<(usize, usize)>,
+        sustain: Option<usize>,
+        time_grid: Option<u16>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.floor(),
+            y: bounds.y.floor(),
+            width: bounds.width.floor(),
+            height: bounds.height.floor(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back];
+
+        if let Some(divisions) = time_grid {
+            let line_width = style.grid_line_width;
+            let spacing = bounds.width / f32::from(divisions);
+
+            for i in 1..divisions {
+                let x = (bounds.x + (f32::from(i) * spacing)).floor();
+
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: x - (line_width / 2.0).floor(),
+                        y: bounds.y,
+                        width: line_width,
+                        height: bounds.height,
+                    },
+                    background: Background::Color(style.grid_line_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Default::default(),
+                });
+            }
+        }
+
+        let marker_x = |index: usize| -> f32 {
+            (bounds.x + (points[index].time.as_f32() * bounds.width)).floor()
+        };
+
+        let marker_line = |x: f32, color: Color| Primitive::Quad {
+            bounds: Rectangle {
+                x: x - (style.marker_line_width / 2.0).floor(),
+                y: bounds.y,
+                width: style.marker_line_width,
+                height: bounds.height,
+            },
+            background: Background::Color(color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Default::default(),
+        };
+
+        if let Some((start, end)) = loop_range {
+            let start_x = marker_x(start);
+            let end_x = marker_x(end);
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: start_x,
+                    y: bounds.y,
+                    width: end_x - start_x,
+                    height: bounds.height,
+                },
+                background: Background::Color(style.loop_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Default::default(),
+            });
+
+            primitives.push(marker_line(start_x, style.loop_line_color));
+            primitives.push(marker_line(end_x, style.loop_line_color));
+        }
+
+        if let Some(sustain) = sustain {
+            primitives
+                .push(marker_line(marker_x(sustain), style.sustain_line_color));
+        }
+
+        if points.len() > 1 && bounds.width > 0.0 && bounds.height > 0.0 {
+            let to_local = |x: f32, y: f32| -> Point {
+                Point::new(x * bounds.width, (1.0 - y) * bounds.height)
+            };
+
+            let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+            for (i, pair) in points.windows(2).enumerate() {
+                let start = pair[0];
+                let end = pair[1];
+
+                let (control_x, control_y) = curve::control_point(
+                    start.time.as_f32(),
+                    start.value.as_f32(),
+                    end.time.as_f32(),
+                    end.value.as_f32(),
+                    start.curve,
+                );
+
+                let from = to_local(start.time.as_f32(), start.value.as_f32());
+                let control = to_local(control_x, control_y);
+                let to = to_local(end.time.as_f32(), end.value.as_f32());
+
+                let path = Path::new(|p| {
+                    p.move_to(from);
+                    p.quadratic_curve_to(control, to);
+                });
+
+                let color = if active_segment == Some(i) {
+                    style.active_line_color
+                } else {
+                    style.line_color
+                };
+
+                frame.stroke(
+                    &path,
+                    Stroke {
+                        width: style.line_width,
+                        color,
+                        line_cap: LineCap::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for (i, point) in points.iter().enumerate() {
+            let point_style = if active_point == Some(i) {
+                &style.active_point
+            } else {
+                &style.point
+            };
+
+            let diameter = point_style.diameter;
+            let radius = diameter / 2.0;
+
+            let center_x = bounds.x + (point.time.as_f32() * bounds.width);
+            let center_y =
+                bounds.y + ((1.0 - point.value.as_f32()) * bounds.height);
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: (center_x - radius).round(),
+                    y: (center_y - radius).round(),
+                    width: diameter,
+                    height: diameter,
+                },
+                background: Background::Color(point_style.color),
+                border_radius: radius,
+                border_width: point_style.border_width,
+                border_color: point_style.border_color,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
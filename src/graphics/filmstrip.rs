@@ -0,0 +1,76 @@
+//! Draw frames of a filmstrip (sprite sheet) texture
+
+use iced_graphics::Primitive;
+use iced_native::{Rectangle, Vector};
+
+pub use crate::style::filmstrip::*;
+
+/// Returns the primitive that draws the frame at `frame_index` of the
+/// [`Filmstrip`] scaled to fit inside `bounds`.
+///
+/// The whole texture is drawn and clipped to `bounds`, so that only the
+/// selected frame is visible.
+///
+/// [`Filmstrip`]: struct.Filmstrip.html
+pub fn draw(
+    filmstrip: &Filmstrip,
+    frame_index: u16,
+    bounds: Rectangle,
+) -> Primitive {
+    if filmstrip.num_frames == 0 || bounds.width <= 0.0 || bounds.height <= 0.0
+    {
+        return Primitive::None;
+    }
+
+    let frame_index = f32::from(frame_index.min(filmstrip.num_frames - 1));
+    let num_frames = f32::from(filmstrip.num_frames);
+
+    let image_bounds = match filmstrip.orientation {
+        Orientation::Vertical => Rectangle {
+            x: bounds.x,
+            y: bounds.y - (frame_index * bounds.height),
+            width: bounds.width,
+            height: bounds.height * num_frames,
+        },
+        Orientation::Horizontal => Rectangle {
+            x: bounds.x - (frame_index * bounds.width),
+            y: bounds.y,
+            width: bounds.width * num_frames,
+            height: bounds.height,
+        },
+    };
+
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Image {
+            handle: filmstrip.image_handle.clone(),
+            bounds: image_bounds,
+        }),
+    }
+}
+
+/// Returns the largest bounds with the aspect ratio of a frame of the
+/// [`Filmstrip`] that fit inside `bounds`, centered in `bounds`.
+///
+/// [`Filmstrip`]: struct.Filmstrip.html
+pub fn fit_frame(filmstrip: &Filmstrip, bounds: &Rectangle) -> Rectangle {
+    if filmstrip.frame_width == 0 || filmstrip.frame_height == 0 {
+        return *bounds;
+    }
+
+    let frame_width = f32::from(filmstrip.frame_width);
+    let frame_height = f32::from(filmstrip.frame_height);
+
+    let scale = (bounds.width / frame_width).min(bounds.height / frame_height);
+
+    let width = (frame_width * scale).round();
+    let height = (frame_height * scale).round();
+
+    Rectangle {
+        x: (bounds.center_x() - (width / 2.0)).round(),
+        y: (bounds.center_y() - (height / 2.0)).round(),
+        width,
+        height,
+    }
+}
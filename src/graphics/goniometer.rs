@@ -0,0 +1,229 @@
+//! Display a goniometer (stereo vectorscope) that plots the mid / side
+//! image of a stereo signal.
+
+use crate::native::goniometer;
+use iced_graphics::canvas::{path::Arc, Fill, Frame, LineCap, Path, Stroke};
+use iced_graphics::{alignment, Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::goniometer::{GonioPoint, Mode, State};
+pub use crate::style::goniometer::{Style, StyleSheet};
+
+/// The number of steps that the opacity of fading points is rounded to.
+/// Points with the same opacity are drawn with a single path.
+static OPACITY_STEPS: usize = 8;
+
+/// A goniometer (stereo vectorscope).
+pub type Goniometer<'a, Backend> =
+    goniometer::Goniometer<'a, Renderer<Backend>>;
+
+impl<B: Backend> goniometer::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        points: &[GonioPoint],
+        mode: Mode,
+        polar: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let padding = style.padding;
+
+        // The center and radius of the circle, relative to the bounds.
+        let (center, radius) = if polar {
+            let radius = ((bounds.width / 2.0) - padding)
+                .min(bounds.height - (padding * 2.0));
+            let top = (bounds.height - radius) / 2.0;
+
+            (Point::new(bounds.width / 2.0, top + radius), radius)
+        } else {
+            let radius = (bounds.width.min(bounds.height) / 2.0) - padding;
+
+            (Point::new(bounds.width / 2.0, bounds.height / 2.0), radius)
+        };
+
+        if radius <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let to_point = |x: f32, y: f32| -> Point {
+            Point::new(center.x + (x * radius), center.y - (y * radius))
+        };
+
+        let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+        if style.grid_width > 0.0 && style.grid_color.a > 0.0 {
+            let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+            let bottom = if polar { 0.0 } else { -1.0 };
+
+            let grid = Path::new(|p| {
+                if polar {
+                    p.move_to(to_point(-1.0, 0.0));
+                    p.arc(Arc {
+                        center,
+                        radius,
+                        start_angle: std::f32::consts::PI,
+                        end_angle: std::f32::consts::PI * 2.0,
+                    });
+                } else {
+                    p.circle(center, radius);
+                }
+
+                // S
+                p.move_to(to_point(-1.0, 0.0));
+                p.line_to(to_point(1.0, 0.0));
+                // M
+                p.move_to(to_point(0.0, bottom));
+                p.line_to(to_point(0.0, 1.0));
+                // L
+                p.move_to(to_point(-bottom * diagonal, bottom * diagonal));
+                p.line_to(to_point(-diagonal, diagonal));
+                // R
+                p.move_to(to_point(bottom * diagonal, bottom * diagonal));
+                p.line_to(to_point(diagonal, diagonal));
+            });
+
+            frame.stroke(
+                &grid,
+                Stroke {
+                    width: style.grid_width,
+                    color: style.grid_color,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let step_of = |opacity: f32| -> usize {
+            ((opacity * OPACITY_STEPS as f32).ceil() as usize)
+                .min(OPACITY_STEPS)
+        };
+        let faded = |color: Color, step: usize| -> Color {
+            Color {
+                a: color.a * step as f32 / OPACITY_STEPS as f32,
+                ..color
+            }
+        };
+
+        match mode {
+            Mode::Dots => {
+                let half_size = style.dot_size / 2.0;
+
+                for step in 1..=OPACITY_STEPS {
+                    let dots = Path::new(|p| {
+                        for point in
+                            points.iter().filter(|p| step_of(p.opacity) == step)
+                        {
+                            let position = to_point(point.x, point.y);
+
+                            p.rectangle(
+                                Point::new(
+                                    position.x - half_size,
+                                    position.y - half_size,
+                                ),
+                                Size::new(style.dot_size, style.dot_size),
+                            );
+                        }
+                    });
+
+                    frame.fill(
+                        &dots,
+                        Fill {
+                            color: faded(style.dot_color, step),
+                            ..Fill::default()
+                        },
+                    );
+                }
+            }
+            Mode::Lines => {
+                for step in 1..=OPACITY_STEPS {
+                    let lines = Path::new(|p| {
+                        for pair in points.windows(2) {
+                            if step_of(pair[1].opacity) != step {
+                                continue;
+                            }
+
+                            p.move_to(to_point(pair[0].x, pair[0].y));
+                            p.line_to(to_point(pair[1].x, pair[1].y));
+                        }
+                    });
+
+                    frame.stroke(
+                        &lines,
+                        Stroke {
+                            width: style.line_width,
+                            color: faded(style.line_color, step),
+                            line_cap: LineCap::Round,
+                            ..Stroke::default()
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut primitives = vec![
+            back,
+            Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            },
+        ];
+
+        if let Some(labels) = &style.labels {
+            let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+            // Place the labels just outside of the circle.
+            let distance =
+                1.0 + ((f32::from(labels.bounds_height) / 2.0 + 1.0) / radius);
+
+            let label_positions = [
+                ("M", 0.0, 1.0),
+                ("L", -diagonal, diagonal),
+                ("R", diagonal, diagonal),
+                ("S", -1.0, 0.0),
+                ("S", 1.0, 0.0),
+            ];
+
+            for (text, x, y) in label_positions.iter() {
+                let position = to_point(x * distance, y * distance);
+
+                primitives.push(Primitive::Text {
+                    content: String::from(*text),
+                    size: f32::from(labels.text_size),
+                    bounds: Rectangle {
+                        x: (bounds.x + position.x).round(),
+                        y: (bounds.y + position.y).round(),
+                        width: f32::from(labels.bounds_width),
+                        height: f32::from(labels.bounds_height),
+                    },
+                    color: labels.color,
+                    font: labels.font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,772 @@
+//! Draw gradients and value-dependent color ramps
+//!
+//! Gradients are drawn as meshes of triangles with a color at each vertex.
+//! The shapes are split at the stops of the [`ColorRamp`], so that the
+//! colors between two stops blend exactly, and two stops at the same
+//! position make a hard edge.
+//!
+//! [`ColorRamp`]: struct.ColorRamp.html
+
+use iced_graphics::canvas::LineCap;
+use iced_graphics::triangle::{Mesh2D, Vertex2D};
+use iced_graphics::Primitive;
+use iced_native::{Background, Color, Point, Rectangle, Size, Vector};
+
+use crate::core::Normal;
+
+pub use crate::style::gradient::*;
+
+/// The number of segments of a full circle
+const CIRCLE_SEGMENTS: usize = 64;
+/// The number of segments of each edge of a rectangle in a radial gradient
+const EDGE_SEGMENTS: usize = 16;
+/// The number of segments of a round cap
+const CAP_SEGMENTS: usize = 8;
+/// The largest angle in radians spanned by one segment of an arc
+const MAX_ARC_SEGMENT_ANGLE: f32 = std::f32::consts::PI / 48.0;
+/// How far the color of a vertex is moved into its slice, so that the
+/// vertices on either side of a hard edge take the color of their own side
+const EDGE_NUDGE: f32 = 0.0001;
+
+/// Where the value of a widget goes from `0.0` to `1.0`. This is used to
+/// draw a [`Gradient::Range`].
+///
+/// [`Gradient::Range`]: enum.Gradient.html#variant.Range
+#[derive(Debug, Copy, Clone)]
+pub enum ValueAxis {
+    /// The value goes from `start` to `end` along a straight line.
+    Line {
+        /// The point where the value is `0.0`
+        start: Point,
+        /// The point where the value is `1.0`
+        end: Point,
+    },
+    /// The value goes from `start_angle` to `end_angle` around `center`.
+    Angle {
+        /// The center of the rotation
+        center: Point,
+        /// The angle in radians where the value is `0.0`
+        start_angle: f32,
+        /// The angle in radians where the value is `1.0`
+        end_angle: f32,
+    },
+}
+
+impl ValueAxis {
+    fn position(&self, point: Point) -> f32 {
+        match self {
+            ValueAxis::Line { start, end } => {
+                Projection::new(*start, *end).position(point)
+            }
+            ValueAxis::Angle {
+                center,
+                start_angle,
+                end_angle,
+            } => {
+                let span = end_angle - start_angle;
+                if span == 0.0 {
+                    return 0.0;
+                }
+
+                let angle = (point.y - center.y).atan2(point.x - center.x);
+
+                // Unwrap the angle to the turn that is closest to the middle
+                // of the axis.
+                let middle = start_angle + (span / 2.0);
+                let turn = 2.0 * std::f32::consts::PI;
+                let angle = angle + ((middle - angle) / turn).round() * turn;
+
+                (angle - start_angle) / span
+            }
+        }
+    }
+}
+
+/// An arc stroked with a width, such as the filled arc of a knob
+#[derive(Debug, Copy, Clone)]
+pub struct StrokedArc {
+    /// The center of the arc
+    pub center: Point,
+    /// The radius of the middle of the stroke
+    pub radius: f32,
+    /// The width (thickness) of the stroke
+    pub width: f32,
+    /// The angle in radians where the arc starts
+    pub start_angle: f32,
+    /// The angle in radians where the arc ends
+    pub end_angle: f32,
+    /// The cap at the ends of the arc
+    pub cap: LineCap,
+}
+
+/// Returns the primitive that fills `bounds` with the [`Gradient`].
+///
+/// `value` is the current value of the widget, and `value_axis` is where
+/// the values of the widget lie inside the shape. If `value_axis` is `None`,
+/// then [`Gradient::Range`] is drawn like [`Gradient::Value`].
+///
+/// The corners are only rounded by `border_radius` when the whole rectangle
+/// has a single color.
+///
+/// [`Gradient`]: enum.Gradient.html
+/// [`Gradient::Range`]: enum.Gradient.html#variant.Range
+/// [`Gradient::Value`]: enum.Gradient.html#variant.Value
+pub fn fill_rectangle(
+    bounds: Rectangle,
+    border_radius: f32,
+    gradient: &Gradient,
+    value: Normal,
+    value_axis: Option<ValueAxis>,
+) -> Primitive {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return Primitive::None;
+    }
+
+    let corners = [
+        Point::new(bounds.x, bounds.y),
+        Point::new(bounds.x + bounds.width, bounds.y),
+        Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        Point::new(bounds.x, bounds.y + bounds.height),
+    ];
+
+    let mut mesh = MeshBuilder::default();
+
+    match (gradient, value_axis) {
+        (Gradient::Linear { angle, ramp }, _) => {
+            let projection = Projection::across(&bounds, *angle);
+
+            mesh.sliced_polygon(&corners, |p| projection.position(p), ramp);
+        }
+        (Gradient::Radial(ramp), _) => {
+            let boundary: Vec<Point> = corners
+                .iter()
+                .zip(corners.iter().cycle().skip(1))
+                .flat_map(|(from, to)| {
+                    (0..EDGE_SEGMENTS).map(move |i| {
+                        let t = i as f32 / EDGE_SEGMENTS as f32;
+
+                        Point::new(
+                            from.x + ((to.x - from.x) * t),
+                            from.y + ((to.y - from.y) * t),
+                        )
+                    })
+                })
+                .collect();
+
+            let max_radius = bounds.width.hypot(bounds.height) / 2.0;
+
+            mesh.radial(bounds.center(), &boundary, max_radius, ramp);
+        }
+        (Gradient::Range(ramp), Some(ValueAxis::Line { start, end })) => {
+            let projection = Projection::new(start, end);
+
+            mesh.sliced_polygon(&corners, |p| projection.position(p), ramp);
+        }
+        (Gradient::Range(ramp), Some(axis)) => {
+            mesh.polygon(&corners, |p| ramp.color_at(axis.position(p)));
+        }
+        (Gradient::Range(ramp), None) | (Gradient::Value(ramp), _) => {
+            return Primitive::Quad {
+                bounds,
+                background: Background::Color(ramp.color_at(value.as_f32())),
+                border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            };
+        }
+    }
+
+    mesh.into_primitive()
+}
+
+/// Returns the primitive that fills a circle with the [`Gradient`].
+///
+/// A circle does not represent a range of values, so
+/// [`Gradient::Range`] is drawn like [`Gradient::Value`].
+///
+/// [`Gradient`]: enum.Gradient.html
+/// [`Gradient::Range`]: enum.Gradient.html#variant.Range
+/// [`Gradient::Value`]: enum.Gradient.html#variant.Value
+pub fn fill_circle(
+    center: Point,
+    radius: f32,
+    gradient: &Gradient,
+    value: Normal,
+) -> Primitive {
+    if radius <= 0.0 {
+        return Primitive::None;
+    }
+
+    let bounds = Rectangle {
+        x: center.x - radius,
+        y: center.y - radius,
+        width: radius * 2.0,
+        height: radius * 2.0,
+    };
+
+    let boundary: Vec<Point> = (0..CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = (i as f32 / CIRCLE_SEGMENTS as f32)
+                * 2.0
+                * std::f32::consts::PI;
+
+            Point::new(
+                center.x + (radius * angle.cos()),
+                center.y + (radius * angle.sin()),
+            )
+        })
+        .collect();
+
+    let mut mesh = MeshBuilder::default();
+
+    match gradient {
+        Gradient::Linear { angle, ramp } => {
+            let projection = Projection::across(&bounds, *angle);
+
+            mesh.sliced_polygon(&boundary, |p| projection.position(p), ramp);
+        }
+        Gradient::Radial(ramp) => {
+            mesh.radial(center, &boundary, radius, ramp);
+        }
+        Gradient::Range(ramp) | Gradient::Value(ramp) => {
+            return Primitive::Quad {
+                bounds,
+                background: Background::Color(ramp.color_at(value.as_f32())),
+                border_radius: radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            };
+        }
+    }
+
+    mesh.into_primitive()
+}
+
+/// Returns the primitive that fills a [`StrokedArc`] with the [`Gradient`].
+///
+/// `value` is the current value of the widget, and `value_axis` is where
+/// the values of the widget lie along the arc. If `value_axis` is `None`,
+/// then [`Gradient::Range`] is drawn like [`Gradient::Value`].
+///
+/// [`StrokedArc`]: struct.StrokedArc.html
+/// [`Gradient`]: enum.Gradient.html
+/// [`Gradient::Range`]: enum.Gradient.html#variant.Range
+/// [`Gradient::Value`]: enum.Gradient.html#variant.Value
+pub fn fill_arc(
+    arc: &StrokedArc,
+    gradient: &Gradient,
+    value: Normal,
+    value_axis: Option<ValueAxis>,
+) -> Primitive {
+    if arc.width <= 0.0 || arc.start_angle == arc.end_angle {
+        return Primitive::None;
+    }
+
+    let outer_radius = arc.radius + (arc.width / 2.0);
+    let bounds = Rectangle {
+        x: arc.center.x - outer_radius,
+        y: arc.center.y - outer_radius,
+        width: outer_radius * 2.0,
+        height: outer_radius * 2.0,
+    };
+
+    let mut mesh = MeshBuilder::default();
+
+    match (gradient, value_axis) {
+        (Gradient::Linear { angle, ramp }, _) => {
+            let projection = Projection::across(&bounds, *angle);
+
+            mesh.arc(arc, &[], |p, _| ramp.color_at(projection.position(p)));
+        }
+        (Gradient::Radial(ramp), _) => {
+            mesh.arc(arc, &[], |p, _| {
+                ramp.color_at(p.distance(arc.center) / outer_radius)
+            });
+        }
+        (
+            Gradient::Range(ramp),
+            Some(ValueAxis::Angle {
+                start_angle,
+                end_angle,
+                ..
+            }),
+        ) => {
+            let span = end_angle - start_angle;
+            let cuts: Vec<f32> = ramp
+                .stops()
+                .iter()
+                .map(|(position, _)| start_angle + (position * span))
+                .collect();
+
+            mesh.arc(arc, &cuts, |_, angle| {
+                if span == 0.0 {
+                    ramp.color_at(0.0)
+                } else {
+                    ramp.color_at((angle - start_angle) / span)
+                }
+            });
+        }
+        (Gradient::Range(ramp), Some(axis)) => {
+            mesh.arc(arc, &[], |p, _| ramp.color_at(axis.position(p)));
+        }
+        (Gradient::Range(ramp), None) | (Gradient::Value(ramp), _) => {
+            let color = ramp.color_at(value.as_f32());
+
+            mesh.arc(arc, &[], |_, _| color);
+        }
+    }
+
+    mesh.into_primitive()
+}
+
+/// Projects points onto a line, where `start` is at `0.0` and `end` is at
+/// `1.0`
+#[derive(Debug, Copy, Clone)]
+struct Projection {
+    start: Point,
+    direction: Vector,
+    length_squared: f32,
+}
+
+impl Projection {
+    fn new(start: Point, end: Point) -> Self {
+        let direction = Vector::new(end.x - start.x, end.y - start.y);
+
+        Self {
+            start,
+            direction,
+            length_squared: (direction.x * direction.x)
+                + (direction.y * direction.y),
+        }
+    }
+
+    /// A projection across `bounds` at `angle`, where the first and last
+    /// points of `bounds` in that direction are at `0.0` and `1.0`.
+    fn across(bounds: &Rectangle, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let half_length =
+            ((cos.abs() * bounds.width) + (sin.abs() * bounds.height)) / 2.0;
+        let center = bounds.center();
+
+        Self::new(
+            Point::new(
+                center.x - (cos * half_length),
+                center.y - (sin * half_length),
+            ),
+            Point::new(
+                center.x + (cos * half_length),
+                center.y + (sin * half_length),
+            ),
+        )
+    }
+
+    fn position(&self, point: Point) -> f32 {
+        if self.length_squared <= 0.0 {
+            return 0.0;
+        }
+
+        (((point.x - self.start.x) * self.direction.x)
+            + ((point.y - self.start.y) * self.direction.y))
+            / self.length_squared
+    }
+}
+
+/// Returns the sorted and deduplicated positions of the stops of `ramp`
+/// between `min` and `max`, including `min` and `max` themselves.
+fn slice_positions(ramp: &ColorRamp, min: f32, max: f32) -> Vec<f32> {
+    let mut positions = vec![min];
+
+    for (position, _) in ramp.stops().iter() {
+        if *position > min
+            && *position < max
+            && *position > positions[positions.len() - 1]
+        {
+            positions.push(*position);
+        }
+    }
+
+    positions.push(max);
+    positions
+}
+
+/// Returns the color of `ramp` at `position`, moved into the slice from
+/// `start` to `end`.
+fn slice_color(ramp: &ColorRamp, position: f32, start: f32, end: f32) -> Color {
+    let nudge = EDGE_NUDGE.min((end - start).abs() / 2.0);
+    let (low, high) = if start <= end {
+        (start + nudge, end - nudge)
+    } else {
+        (end + nudge, start - nudge)
+    };
+
+    ramp.color_at(position.max(low).min(high))
+}
+
+/// Clips a convex polygon to the side of a line where `position` is above
+/// `limit` (or below `limit` if `keep_below` is true).
+fn clip_polygon(
+    polygon: &[Point],
+    position: &impl Fn(Point) -> f32,
+    limit: f32,
+    keep_below: bool,
+) -> Vec<Point> {
+    let inside = |t: f32| if keep_below { t <= limit } else { t >= limit };
+
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+
+    for (i, point) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+
+        let t = position(*point);
+        let next_t = position(next);
+
+        if inside(t) {
+            clipped.push(*point);
+        }
+
+        if inside(t) != inside(next_t) {
+            let f = (limit - t) / (next_t - t);
+
+            clipped.push(Point::new(
+                point.x + ((next.x - point.x) * f),
+                point.y + ((next.y - point.y) * f),
+            ));
+        }
+    }
+
+    clipped
+}
+
+#[derive(Debug, Default)]
+struct MeshBuilder {
+    vertices: Vec<(Point, Color)>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuilder {
+    fn vertex(&mut self, point: Point, color: Color) -> u32 {
+        self.vertices.push((point, color));
+        (self.vertices.len() - 1) as u32
+    }
+
+    fn quad(&mut self, corners: [(Point, Color); 4]) {
+        let a = self.vertex(corners[0].0, corners[0].1);
+        let b = self.vertex(corners[1].0, corners[1].1);
+        let c = self.vertex(corners[2].0, corners[2].1);
+        let d = self.vertex(corners[3].0, corners[3].1);
+
+        self.indices.extend_from_slice(&[a, b, c, a, c, d]);
+    }
+
+    /// Adds a convex polygon as a fan of triangles.
+    fn polygon(&mut self, points: &[Point], color: impl Fn(Point) -> Color) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let first = self.vertex(points[0], color(points[0]));
+        let mut previous = self.vertex(points[1], color(points[1]));
+
+        for point in points.iter().skip(2) {
+            let current = self.vertex(*point, color(*point));
+
+            self.indices.extend_from_slice(&[first, previous, current]);
+            previous = current;
+        }
+    }
+
+    /// Adds a convex polygon that is split into slices at the stops of
+    /// `ramp`, where `position` maps a point to its position on the ramp and
+    /// is linear.
+    fn sliced_polygon(
+        &mut self,
+        polygon: &[Point],
+        position: impl Fn(Point) -> f32,
+        ramp: &ColorRamp,
+    ) {
+        let (min, max) = polygon.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), point| {
+                let t = position(*point);
+                (min.min(t), max.max(t))
+            },
+        );
+
+        let positions = slice_positions(ramp, min, max);
+
+        for slice in positions.windows(2) {
+            let (start, end) = (slice[0], slice[1]);
+
+            let clipped = clip_polygon(polygon, &position, start, false);
+            let clipped = clip_polygon(&clipped, &position, end, true);
+
+            self.polygon(&clipped, |p| {
+                slice_color(ramp, position(p), start, end)
+            });
+        }
+    }
+
+    /// Adds a radial gradient from `center` to the convex polygon
+    /// `boundary`, where `max_radius` is at the end of `ramp`.
+    fn radial(
+        &mut self,
+        center: Point,
+        boundary: &[Point],
+        max_radius: f32,
+        ramp: &ColorRamp,
+    ) {
+        if max_radius <= 0.0 {
+            return;
+        }
+
+        let lengths: Vec<f32> =
+            boundary.iter().map(|p| p.distance(center)).collect();
+        let max_length = lengths.iter().cloned().fold(0.0, f32::max);
+
+        let positions =
+            slice_positions(ramp, 0.0, (max_length / max_radius).max(1.0));
+
+        let point_on_ray = |i: usize, position: f32| {
+            let length = lengths[i];
+            let distance = (position * max_radius).min(length);
+
+            if length <= 0.0 {
+                center
+            } else {
+                let f = distance / length;
+
+                Point::new(
+                    center.x + ((boundary[i].x - center.x) * f),
+                    center.y + ((boundary[i].y - center.y) * f),
+                )
+            }
+        };
+
+        for slice in positions.windows(2) {
+            let (start, end) = (slice[0], slice[1]);
+            let color = |p: Point| {
+                slice_color(ramp, p.distance(center) / max_radius, start, end)
+            };
+
+            for i in 0..boundary.len() {
+                let next = (i + 1) % boundary.len();
+
+                let corners = [
+                    point_on_ray(i, start),
+                    point_on_ray(i, end),
+                    point_on_ray(next, end),
+                    point_on_ray(next, start),
+                ];
+
+                self.quad([
+                    (corners[0], color(corners[0])),
+                    (corners[1], color(corners[1])),
+                    (corners[2], color(corners[2])),
+                    (corners[3], color(corners[3])),
+                ]);
+            }
+        }
+    }
+
+    /// Adds a [`StrokedArc`] that is split into slices at the angles in
+    /// `cuts`. `color` maps a point and its angle (moved into its slice) to
+    /// a color.
+    fn arc(
+        &mut self,
+        arc: &StrokedArc,
+        cuts: &[f32],
+        color: impl Fn(Point, f32) -> Color,
+    ) {
+        let half_width = arc.width / 2.0;
+        let (start, end) = (arc.start_angle, arc.end_angle);
+        let direction = if end > start { 1.0 } else { -1.0 };
+
+        let mut angles = vec![start];
+        let mut sorted_cuts: Vec<f32> = cuts
+            .iter()
+            .cloned()
+            .filter(|a| (*a - start) * direction > 0.0)
+            .filter(|a| (end - *a) * direction > 0.0)
+            .collect();
+        sorted_cuts.sort_by(|a, b| {
+            ((*a - *b) * direction)
+                .partial_cmp(&0.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        angles.extend(sorted_cuts);
+        angles.push(end);
+        angles.dedup();
+
+        let point_at = |angle: f32, radius: f32| {
+            Point::new(
+                arc.center.x + (radius * angle.cos()),
+                arc.center.y + (radius * angle.sin()),
+            )
+        };
+
+        for slice in angles.windows(2) {
+            let (slice_start, slice_end) = (slice[0], slice[1]);
+            let nudge = EDGE_NUDGE.min((slice_end - slice_start).abs() / 2.0);
+            let (low, high) = if slice_start <= slice_end {
+                (slice_start + nudge, slice_end - nudge)
+            } else {
+                (slice_end + nudge, slice_start - nudge)
+            };
+
+            let segments = ((slice_end - slice_start).abs()
+                / MAX_ARC_SEGMENT_ANGLE)
+                .ceil()
+                .max(1.0) as usize;
+
+            let edge = |i: usize| {
+                let angle = slice_start
+                    + ((slice_end - slice_start)
+                        * (i as f32 / segments as f32));
+                let color_angle = angle.max(low).min(high);
+
+                let inner = point_at(angle, arc.radius - half_width);
+                let outer = point_at(angle, arc.radius + half_width);
+
+                (
+                    (inner, color(inner, color_angle)),
+                    (outer, color(outer, color_angle)),
+                )
+            };
+
+            for i in 0..segments {
+                let (inner, outer) = edge(i);
+                let (next_inner, next_outer) = edge(i + 1);
+
+                self.quad([inner, outer, next_outer, next_inner]);
+            }
+        }
+
+        let start_color = color(point_at(start, arc.radius), start);
+        let end_color = color(point_at(end, arc.radius), end);
+
+        self.cap(arc, start, -direction, start_color);
+        self.cap(arc, end, direction, end_color);
+    }
+
+    /// Adds the cap of a [`StrokedArc`] at `angle`, facing away from the
+    /// arc when `direction` is positive in the direction of increasing
+    /// angles.
+    fn cap(
+        &mut self,
+        arc: &StrokedArc,
+        angle: f32,
+        direction: f32,
+        color: Color,
+    ) {
+        let half_width = arc.width / 2.0;
+        let (sin, cos) = angle.sin_cos();
+
+        let center = Point::new(
+            arc.center.x + (arc.radius * cos),
+            arc.center.y + (arc.radius * sin),
+        );
+        // The unit vector that points outward from the end of the arc.
+        let tangent = Vector::new(-sin * direction, cos * direction);
+        let normal = Vector::new(cos, sin);
+
+        match arc.cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let offset = |along: f32, across: f32| {
+                    Point::new(
+                        center.x + (tangent.x * along) + (normal.x * across),
+                        center.y + (tangent.y * along) + (normal.y * across),
+                    )
+                };
+
+                self.quad([
+                    (offset(0.0, -half_width), color),
+                    (offset(0.0, half_width), color),
+                    (offset(half_width, half_width), color),
+                    (offset(half_width, -half_width), color),
+                ]);
+            }
+            LineCap::Round => {
+                let base_angle = normal.y.atan2(normal.x);
+                let turn =
+                    if (tangent.x * -normal.y) + (tangent.y * normal.x) > 0.0 {
+                        1.0
+                    } else {
+                        -1.0
+                    };
+
+                let points: Vec<Point> = (0..=CAP_SEGMENTS)
+                    .map(|i| {
+                        let a = base_angle
+                            + (turn
+                                * std::f32::consts::PI
+                                * (i as f32 / CAP_SEGMENTS as f32));
+
+                        Point::new(
+                            center.x + (half_width * a.cos()),
+                            center.y + (half_width * a.sin()),
+                        )
+                    })
+                    .collect();
+
+                let center_index = self.vertex(center, color);
+                let mut previous = self.vertex(points[0], color);
+
+                for point in points.iter().skip(1) {
+                    let current = self.vertex(*point, color);
+
+                    self.indices.extend_from_slice(&[
+                        center_index,
+                        previous,
+                        current,
+                    ]);
+                    previous = current;
+                }
+            }
+        }
+    }
+
+    fn into_primitive(self) -> Primitive {
+        if self.indices.is_empty() {
+            return Primitive::None;
+        }
+
+        let (min, max) = self.vertices.iter().fold(
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+            ),
+            |(min, max), (point, _)| {
+                (
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                )
+            },
+        );
+
+        let origin = Point::new(min.x.floor(), min.y.floor());
+
+        let vertices = self
+            .vertices
+            .into_iter()
+            .map(|(point, color)| Vertex2D {
+                position: [point.x - origin.x, point.y - origin.y],
+                color: color.into_linear(),
+            })
+            .collect();
+
+        Primitive::Translate {
+            translation: Vector::new(origin.x, origin.y),
+            content: Box::new(Primitive::Mesh2D {
+                buffers: Mesh2D {
+                    vertices,
+                    indices: self.indices,
+                },
+                size: Size::new(
+                    (max.x - origin.x).ceil(),
+                    (max.y - origin.y).ceil(),
+                ),
+            }),
+        }
+    }
+}
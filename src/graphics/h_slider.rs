@@ -3,15 +3,19 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::effects;
+use crate::graphics::gradient::{self, Gradient, ValueAxis};
+use crate::graphics::{filmstrip, text_marks, tick_marks};
 use crate::native::h_slider;
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::h_slider::State;
 pub use crate::style::h_slider::{
-    ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
-    RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
+    ClassicHandle, ClassicRail, ClassicStyle, FilmstripStyle,
+    ModRangePlacement, ModRangeStyle, ModulationDot, ModulationNotch,
+    ModulationStyle, RectBipolarStyle, RectStyle, Style, StyleLength,
+    StyleSheet, SvgHandle, SvgLayer, SvgStyle, TextMarksStyle, TextureRail,
     TextureStyle, TickMarksStyle,
 };
 
@@ -24,6 +28,8 @@ struct ValueMarkers<'a> {
     text_marks_style: Option<TextMarksStyle>,
     mod_range_style_1: Option<ModRangeStyle>,
     mod_range_style_2: Option<ModRangeStyle>,
+    modulation: Option<Normal>,
+    modulation_style: Option<ModulationStyle>,
 }
 
 /// A horizontal slider GUI widget that controls a [`Param`]
@@ -46,6 +52,7 @@ impl<B: Backend> h_slider::Renderer for Renderer<B> {
         is_dragging: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        modulation: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style_sheet: &Self::Style,
@@ -78,6 +85,8 @@ impl<B: Backend> h_slider::Renderer for Renderer<B> {
             text_marks_style: style_sheet.text_marks_style(),
             mod_range_style_1: style_sheet.mod_range_style(),
             mod_range_style_2: style_sheet.mod_range_style_2(),
+            modulation,
+            modulation_style: style_sheet.modulation_style(),
         };
 
         let primitives = match style {
@@ -89,6 +98,22 @@ impl<B: Backend> h_slider::Renderer for Renderer<B> {
                 tick_marks_cache,
                 text_marks_cache,
             ),
+            Style::Filmstrip(style) => draw_filmstrip_style(
+                normal,
+                &bounds,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Svg(style) => draw_svg_style(
+                normal,
+                &bounds,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
             Style::Classic(style) => draw_classic_style(
                 normal,
                 &bounds,
@@ -295,6 +320,51 @@ fn draw_mod_range(
     }
 }
 
+fn draw_modulation<'a>(
+    value_bounds: &Rectangle,
+    bounds: &Rectangle,
+    value_markers: &ValueMarkers<'a>,
+) -> Primitive {
+    let (modulation, style) =
+        match (value_markers.modulation, &value_markers.modulation_style) {
+            (Some(modulation), Some(style)) => (modulation, style),
+            _ => return Primitive::None,
+        };
+
+    let center = value_bounds.x + modulation.scale(value_bounds.width);
+
+    match style {
+        ModulationStyle::Dot(dot) => {
+            let radius = dot.diameter / 2.0;
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: (center - radius).round(),
+                    y: (bounds.center_y() + dot.offset - radius).round(),
+                    width: dot.diameter,
+                    height: dot.diameter,
+                },
+                background: Background::Color(dot.color),
+                border_radius: radius,
+                border_width: dot.border_width,
+                border_color: dot.border_color,
+            }
+        }
+        ModulationStyle::Notch(notch) => Primitive::Quad {
+            bounds: Rectangle {
+                x: (center - (notch.width / 2.0)).round(),
+                y: bounds.y + notch.edge_padding,
+                width: notch.width,
+                height: bounds.height - (notch.edge_padding * 2.0),
+            },
+            background: Background::Color(notch.color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+    }
+}
+
 fn draw_texture_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
@@ -318,7 +388,12 @@ fn draw_texture_style<'a>(
         text_marks_cache,
     );
 
-    let (top_rail, bottom_rail) = draw_classic_rail(&bounds, &style.rail);
+    let (top_rail, bottom_rail) = if let Some(rail_texture) = style.rail_texture
+    {
+        (draw_texture_rail(bounds, rail_texture), Primitive::None)
+    } else {
+        draw_classic_rail(&bounds, &style.rail)
+    };
 
     let handle = Primitive::Image {
         handle: style.image_handle,
@@ -333,6 +408,8 @@ fn draw_texture_style<'a>(
         },
     };
 
+    let modulation = draw_modulation(&value_bounds, bounds, value_markers);
+
     Primitive::Group {
         primitives: vec![
             tick_marks,
@@ -342,10 +419,140 @@ fn draw_texture_style<'a>(
             handle,
             mod_range_1,
             mod_range_2,
+            modulation,
+        ],
+    }
+}
+
+fn draw_filmstrip_style<'a>(
+    normal: Normal,
+    bounds: &Rectangle,
+    style: FilmstripStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let frame_bounds = filmstrip::fit_frame(&style.filmstrip, bounds);
+
+    let handle_width = if style.filmstrip.frame_width > 0 {
+        f32::from(style.handle_width) * frame_bounds.width
+            / f32::from(style.filmstrip.frame_width)
+    } else {
+        0.0
+    };
+
+    let value_bounds = Rectangle {
+        x: (frame_bounds.x + (handle_width / 2.0)).round(),
+        y: frame_bounds.y,
+        width: frame_bounds.width - handle_width,
+        height: frame_bounds.height,
+    };
+
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+        &value_bounds,
+        &value_bounds,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
+
+    let frame = filmstrip::draw(
+        &style.filmstrip,
+        style.filmstrip.frame_index(normal),
+        frame_bounds,
+    );
+
+    let modulation =
+        draw_modulation(&value_bounds, &frame_bounds, value_markers);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            frame,
+            mod_range_1,
+            mod_range_2,
+            modulation,
+        ],
+    }
+}
+
+fn draw_svg_style<'a>(
+    normal: Normal,
+    bounds: &Rectangle,
+    style: SvgStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let handle_width = style.handle.width.from_slider_height(bounds.height);
+    let handle_height = style.handle.height.from_slider_height(bounds.height);
+
+    let value_bounds = Rectangle {
+        x: (bounds.x + (handle_width / 2.0)).round(),
+        y: bounds.y,
+        width: bounds.width - handle_width,
+        height: bounds.height,
+    };
+
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+        &value_bounds,
+        &value_bounds,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
+
+    let background = draw_svg_layer(bounds, style.background);
+
+    let handle = Primitive::Svg {
+        handle: style.handle.handle,
+        bounds: Rectangle {
+            x: (value_bounds.x + normal.scale(value_bounds.width)
+                - (handle_width / 2.0))
+                .round(),
+            y: (bounds.center_y() - (handle_height / 2.0)).round(),
+            width: handle_width,
+            height: handle_height,
+        },
+    };
+
+    let foreground = draw_svg_layer(bounds, style.foreground);
+
+    let modulation = draw_modulation(&value_bounds, bounds, value_markers);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            background,
+            handle,
+            foreground,
+            mod_range_1,
+            mod_range_2,
+            modulation,
         ],
     }
 }
 
+fn draw_svg_layer(bounds: &Rectangle, layer: Option<SvgLayer>) -> Primitive {
+    if let Some(layer) = layer {
+        let height = layer.height.from_slider_height(bounds.height);
+
+        Primitive::Svg {
+            handle: layer.handle,
+            bounds: Rectangle {
+                x: bounds.x,
+                y: (bounds.center_y() - (height / 2.0)).round(),
+                width: bounds.width,
+                height,
+            },
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_classic_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
@@ -377,19 +584,38 @@ fn draw_classic_style<'a>(
     let handle_offset = normal.scale(value_bounds.width).round();
     let notch_width = f32::from(style.handle.notch_width);
 
+    let handle_bounds = Rectangle {
+        x: bounds.x + handle_offset,
+        y: bounds.y,
+        width: handle_width,
+        height: bounds.height,
+    };
+
+    let handle_effects = effects::shadow_and_glow(
+        handle_bounds,
+        handle_border_radius,
+        style.handle.shadow.as_ref(),
+        style.handle.glow.as_ref(),
+        normal,
+    );
+
     let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x + handle_offset,
-            y: bounds.y,
-            width: handle_width,
-            height: bounds.height,
-        },
+        bounds: handle_bounds,
         background: Background::Color(style.handle.color),
         border_radius: handle_border_radius,
         border_width: style.handle.border_width,
         border_color: style.handle.border_color,
     };
 
+    let handle_inner_shadow = match &style.handle.inner_shadow {
+        Some(inner_shadow) => effects::inner_shadow(
+            handle_bounds,
+            handle_border_radius,
+            inner_shadow,
+        ),
+        None => Primitive::None,
+    };
+
     let handle_notch: Primitive = if style.handle.notch_width != 0.0 {
         Primitive::Quad {
             bounds: Rectangle {
@@ -409,20 +635,55 @@ fn draw_classic_style<'a>(
         Primitive::None
     };
 
+    let modulation = draw_modulation(&value_bounds, bounds, value_markers);
+
     Primitive::Group {
         primitives: vec![
             tick_marks,
             text_marks,
             top_rail,
             bottom_rail,
+            handle_effects,
             handle,
+            handle_inner_shadow,
             handle_notch,
             mod_range_1,
             mod_range_2,
+            modulation,
         ],
     }
 }
 
+fn draw_filled_rect(
+    filled_bounds: Rectangle,
+    color: Color,
+    gradient: Option<&Gradient>,
+    border_radius: f32,
+    border_width: f32,
+    normal: Normal,
+    bounds: &Rectangle,
+) -> Primitive {
+    match gradient {
+        Some(gradient) => gradient::fill_rectangle(
+            filled_bounds,
+            border_radius,
+            gradient,
+            normal,
+            Some(ValueAxis::Line {
+                start: Point::new(bounds.x, bounds.center_y()),
+                end: Point::new(bounds.x + bounds.width, bounds.center_y()),
+            }),
+        ),
+        None => Primitive::Quad {
+            bounds: filled_bounds,
+            background: Background::Color(color),
+            border_radius,
+            border_width,
+            border_color: Color::TRANSPARENT,
+        },
+    }
+}
+
 fn draw_rect_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
@@ -468,42 +729,58 @@ fn draw_rect_style<'a>(
         .scale(value_bounds.width - twice_border_width)
         .round();
 
-    let filled_rect = Primitive::Quad {
-        bounds: Rectangle {
+    let filled_rect = draw_filled_rect(
+        Rectangle {
             x: bounds.x,
             y: bounds.y,
             width: handle_offset + twice_border_width
                 - f32::from(style.handle_filled_gap),
             height: bounds.height,
         },
-        background: Background::Color(style.filled_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: Color::TRANSPARENT,
+        style.filled_color,
+        style.filled_gradient.as_ref(),
+        style.back_border_radius,
+        style.back_border_width,
+        normal,
+        bounds,
+    );
+
+    let handle_bounds = Rectangle {
+        x: bounds.x + handle_offset,
+        y: bounds.y,
+        width: handle_width + twice_border_width,
+        height: bounds.height,
     };
 
+    let handle_effects = effects::shadow_and_glow(
+        handle_bounds,
+        style.back_border_radius,
+        style.handle_shadow.as_ref(),
+        style.handle_glow.as_ref(),
+        normal,
+    );
+
     let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x + handle_offset,
-            y: bounds.y,
-            width: handle_width + twice_border_width,
-            height: bounds.height,
-        },
+        bounds: handle_bounds,
         background: Background::Color(style.handle_color),
         border_radius: style.back_border_radius,
         border_width: style.back_border_width,
         border_color: Color::TRANSPARENT,
     };
 
+    let modulation = draw_modulation(&value_bounds, bounds, value_markers);
+
     Primitive::Group {
         primitives: vec![
             empty_rect,
             tick_marks,
             text_marks,
             filled_rect,
+            handle_effects,
             handle,
             mod_range_1,
             mod_range_2,
+            modulation,
         ],
     }
 }
@@ -562,8 +839,8 @@ fn draw_rect_bipolar_style<'a>(
             handle_offset + handle_width + f32::from(style.handle_filled_gap);
         (
             style.handle_left_color,
-            Primitive::Quad {
-                bounds: Rectangle {
+            draw_filled_rect(
+                Rectangle {
                     x: bounds.x + filled_rect_offset,
                     y: bounds.y,
                     width: ((bounds.width / 2.0) - filled_rect_offset
@@ -571,18 +848,20 @@ fn draw_rect_bipolar_style<'a>(
                         .round(),
                     height: bounds.height,
                 },
-                background: Background::Color(style.left_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
+                style.left_filled_color,
+                style.filled_gradient.as_ref(),
+                style.back_border_radius,
+                style.back_border_width,
+                normal,
+                bounds,
+            ),
         )
     } else {
         let filled_rect_offset = (bounds.width / 2.0).round() - border_width;
         (
             style.handle_right_color,
-            Primitive::Quad {
-                bounds: Rectangle {
+            draw_filled_rect(
+                Rectangle {
                     x: bounds.x + filled_rect_offset,
                     y: bounds.y,
                     width: handle_offset - filled_rect_offset
@@ -590,40 +869,68 @@ fn draw_rect_bipolar_style<'a>(
                         - f32::from(style.handle_filled_gap),
                     height: bounds.height,
                 },
-                background: Background::Color(style.right_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
+                style.right_filled_color,
+                style.filled_gradient.as_ref(),
+                style.back_border_radius,
+                style.back_border_width,
+                normal,
+                bounds,
+            ),
         )
     };
 
+    let handle_bounds = Rectangle {
+        x: bounds.x + handle_offset,
+        y: bounds.y,
+        width: handle_width + twice_border_width,
+        height: bounds.height,
+    };
+
+    let handle_effects = effects::shadow_and_glow(
+        handle_bounds,
+        style.back_border_radius,
+        style.handle_shadow.as_ref(),
+        style.handle_glow.as_ref(),
+        normal,
+    );
+
     let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x + handle_offset,
-            y: bounds.y,
-            width: handle_width + twice_border_width,
-            height: bounds.height,
-        },
+        bounds: handle_bounds,
         background: Background::Color(handle_color),
         border_radius: style.back_border_radius,
         border_width: style.back_border_width,
         border_color: Color::TRANSPARENT,
     };
 
+    let modulation = draw_modulation(&value_bounds, bounds, value_markers);
+
     Primitive::Group {
         primitives: vec![
             empty_rect,
             tick_marks,
             text_marks,
             filled_rect,
+            handle_effects,
             handle,
             mod_range_1,
             mod_range_2,
+            modulation,
         ],
     }
 }
 
+fn draw_texture_rail(bounds: &Rectangle, rail: TextureRail) -> Primitive {
+    Primitive::Image {
+        handle: rail.image_handle,
+        bounds: Rectangle {
+            x: bounds.x + rail.rail_padding,
+            y: (bounds.center_y() - (rail.width / 2.0)).round(),
+            width: bounds.width - (rail.rail_padding * 2.0),
+            height: rail.width,
+        },
+    }
+}
+
 fn draw_classic_rail(
     bounds: &Rectangle,
     style: &ClassicRail,
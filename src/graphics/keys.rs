@@ -0,0 +1,82 @@
+//! Display an interactive piano keyboard that emits note-on and note-off
+//! messages.
+
+use crate::native::keys;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::keys::{
+    is_black_key, Key, NoteEvent, Orientation, State,
+};
+pub use crate::style::keys::{KeyStyle, Style, StyleSheet};
+
+/// A piano keyboard GUI widget.
+pub type Keys<'a, Message, Backend> =
+    keys::Keys<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> keys::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        keys: &[Key],
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let mut primitives: Vec<Primitive> = Vec::with_capacity(keys.len());
+
+        for key in keys.iter() {
+            let key_style = match (key.is_black, key.is_pressed) {
+                (false, false) => &style.white_key,
+                (true, false) => &style.black_key,
+                (false, true) => &style.pressed_white_key,
+                (true, true) => &style.pressed_black_key,
+            };
+
+            let bounds = Rectangle {
+                x: key.bounds.x.round(),
+                y: key.bounds.y.round(),
+                width: key.bounds.width.round(),
+                height: key.bounds.height.round(),
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(key_style.color),
+                border_radius: key_style.border_radius,
+                border_width: key_style.border_width,
+                border_color: key_style.border_color,
+            });
+
+            if key.is_shaded {
+                primitives.push(overlay(bounds, key_style, style.shade_color));
+            }
+
+            if key.is_highlighted && !key.is_pressed {
+                primitives.push(overlay(
+                    bounds,
+                    key_style,
+                    style.highlight_color,
+                ));
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn overlay(bounds: Rectangle, key_style: &KeyStyle, color: Color) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: key_style.border_radius,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
@@ -3,17 +3,21 @@
 //! [`Param`]: ../core/param/struct.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::effects;
+use crate::graphics::gradient::{self, Gradient, StrokedArc, ValueAxis};
+use crate::graphics::{filmstrip, text_marks, tick_marks};
 use crate::native::knob;
 use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
-use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+use iced_graphics::{alignment, Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
 
 pub use crate::native::knob::State;
 pub use crate::style::knob::{
-    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
-    ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
-    TextMarksStyle, TickMarksStyle, ValueArcStyle,
+    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, FilmstripStyle,
+    LineCap, LineNotch, ModRangeArcStyle, ModulationArcStyle, ModulationStyle,
+    NotchShape, RotatingTexture, Style, StyleLength, StyleSheet, SvgIndicator,
+    SvgLayer, SvgStyle, TextMarksStyle, TextureLayer, TextureStyle,
+    TickMarksStyle, ValueArcStyle, ValueTextContent, ValueTextStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -26,8 +30,11 @@ struct ValueMarkers<'a> {
     value_arc_style: Option<ValueArcStyle>,
     mod_range_style_1: Option<ModRangeArcStyle>,
     mod_range_style_2: Option<ModRangeArcStyle>,
+    modulation: Option<Normal>,
+    modulation_style: Option<ModulationStyle>,
 }
 
+#[derive(Copy, Clone)]
 struct KnobInfo {
     bounds: Rectangle,
     start_angle: f32,
@@ -54,8 +61,11 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
         is_dragging: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        modulation: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        value_text: Option<&dyn Fn(Normal) -> String>,
+        label: Option<&str>,
         style_sheet: &Self::Style,
         tick_marks_cache: &tick_marks::PrimitiveCache,
         text_marks_cache: &text_marks::PrimitiveCache,
@@ -64,12 +74,18 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
 
         let angle_range = style_sheet.angle_range();
 
-        let style = if is_dragging {
-            style_sheet.dragging()
+        let (style, value_text_style) = if is_dragging {
+            (
+                style_sheet.dragging(),
+                style_sheet.value_text_dragging_style(),
+            )
         } else if is_mouse_over {
-            style_sheet.hovered()
+            (
+                style_sheet.hovered(),
+                style_sheet.value_text_hovered_style(),
+            )
         } else {
-            style_sheet.active()
+            (style_sheet.active(), style_sheet.value_text_style())
         };
 
         let value_markers = ValueMarkers {
@@ -82,6 +98,8 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             value_arc_style: style_sheet.value_arc_style(),
             mod_range_style_1: style_sheet.mod_range_arc_style(),
             mod_range_style_2: style_sheet.mod_range_arc_style_2(),
+            modulation,
+            modulation_style: style_sheet.modulation_style(),
         };
 
         let bounds = {
@@ -133,35 +151,100 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             value_angle,
         };
 
+        let value_text = match value_text_style {
+            Some(value_text_style) => draw_value_text(
+                &knob_info,
+                &value_text_style,
+                value_text,
+                label,
+            ),
+            None => Primitive::None,
+        };
+
+        let knob = match style {
+            Style::Texture(style) => draw_texture_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Filmstrip(style) => draw_filmstrip_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Svg(style) => draw_svg_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Circle(style) => draw_circle_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Arc(style) => draw_arc_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::ArcBipolar(style) => draw_arc_bipolar_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+        };
+
         (
-            match style {
-                Style::Circle(style) => draw_circle_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::Arc(style) => draw_arc_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::ArcBipolar(style) => draw_arc_bipolar_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
+            Primitive::Group {
+                primitives: vec![knob, value_text],
             },
             mouse::Interaction::default(),
         )
     }
 }
 
+fn draw_value_text(
+    knob_info: &KnobInfo,
+    style: &ValueTextStyle,
+    value_text: Option<&dyn Fn(Normal) -> String>,
+    label: Option<&str>,
+) -> Primitive {
+    let content = match (style.content, label) {
+        (ValueTextContent::Label, Some(label)) => String::from(label),
+        _ => match value_text {
+            Some(value_text) => value_text(knob_info.value),
+            None => return Primitive::None,
+        },
+    };
+
+    Primitive::Text {
+        content,
+        size: f32::from(style.text_size),
+        bounds: Rectangle {
+            x: knob_info.bounds.center_x() + style.offset.x,
+            y: knob_info.bounds.center_y() + style.offset.y,
+            width: knob_info.bounds.width,
+            height: knob_info.bounds.height,
+        },
+        color: style.color,
+        font: style.font,
+        horizontal_alignment: alignment::Horizontal::Center,
+        vertical_alignment: alignment::Vertical::Center,
+    }
+}
+
 fn draw_value_markers<'a>(
     knob_info: &KnobInfo,
     value_markers: &ValueMarkers<'a>,
@@ -241,6 +324,7 @@ fn draw_text_marks(
                 text_marks,
                 &style.style,
                 style.h_char_offset,
+                &style.layout,
                 false,
                 text_marks_cache,
             )
@@ -455,6 +539,81 @@ fn draw_mod_range_arc(
     }
 }
 
+fn draw_modulation<'a>(
+    knob_info: &KnobInfo,
+    value_markers: &ValueMarkers<'a>,
+) -> Primitive {
+    let (modulation, style) =
+        match (value_markers.modulation, &value_markers.modulation_style) {
+            (Some(modulation), Some(style)) => (modulation, style),
+            _ => return Primitive::None,
+        };
+
+    let modulation_angle =
+        knob_info.start_angle + modulation.scale(knob_info.angle_span);
+
+    match style {
+        ModulationStyle::Notch(notch) => {
+            let modulation_info = KnobInfo {
+                value: modulation,
+                value_angle: modulation_angle,
+                ..*knob_info
+            };
+
+            draw_notch(&modulation_info, notch)
+        }
+        ModulationStyle::Arc(style) => {
+            draw_modulation_arc(knob_info, style, modulation_angle)
+        }
+    }
+}
+
+fn draw_modulation_arc(
+    knob_info: &KnobInfo,
+    style: &ModulationArcStyle,
+    modulation_angle: f32,
+) -> Primitive {
+    if (modulation_angle - knob_info.value_angle).abs() < 0.001 {
+        return Primitive::None;
+    }
+
+    let half_width = style.width / 2.0;
+    let arc_radius = knob_info.radius + style.offset + half_width;
+
+    let half_frame_size = (arc_radius + half_width).ceil();
+    let frame_size = half_frame_size * 2.0;
+    let frame_offset = half_frame_size - knob_info.radius;
+    let center_point = Point::new(half_frame_size, half_frame_size);
+
+    let mut frame = Frame::new(Size::new(frame_size, frame_size));
+
+    let stroke = Stroke {
+        width: style.width,
+        color: style.color,
+        line_cap: style.cap,
+        ..Stroke::default()
+    };
+
+    let arc = Arc {
+        center: center_point,
+        radius: arc_radius,
+        start_angle: knob_info.value_angle.min(modulation_angle),
+        end_angle: knob_info.value_angle.max(modulation_angle),
+    };
+
+    let path = Path::new(|path| path.arc(arc));
+
+    frame.stroke(&path, stroke);
+
+    Primitive::Translate {
+        translation: Vector::new(
+            knob_info.bounds.x - frame_offset,
+            knob_info.bounds.y - frame_offset,
+        ),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
 fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
@@ -531,6 +690,200 @@ fn draw_notch(knob_info: &KnobInfo, notch: &NotchShape) -> Primitive {
     }
 }
 
+fn draw_texture_layer(
+    knob_info: &KnobInfo,
+    layer: Option<TextureLayer>,
+) -> Primitive {
+    if let Some(layer) = layer {
+        Primitive::Image {
+            handle: layer.image_handle,
+            bounds: Rectangle {
+                x: (knob_info.bounds.center_x() + layer.image_bounds.x).round(),
+                y: (knob_info.bounds.center_y() + layer.image_bounds.y).round(),
+                width: layer.image_bounds.width,
+                height: layer.image_bounds.height,
+            },
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_rotating_texture(
+    knob_info: &KnobInfo,
+    texture: &RotatingTexture,
+) -> Primitive {
+    let image_bounds = texture.image_bounds();
+
+    Primitive::Image {
+        handle: texture.frame(texture.frame_index(knob_info.value)),
+        bounds: Rectangle {
+            x: (knob_info.bounds.center_x() + image_bounds.x).round(),
+            y: (knob_info.bounds.center_y() + image_bounds.y).round(),
+            width: image_bounds.width,
+            height: image_bounds.height,
+        },
+    }
+}
+
+fn draw_texture_style<'a>(
+    knob_info: &KnobInfo,
+    style: TextureStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
+        draw_value_markers(
+            knob_info,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    let background = draw_texture_layer(knob_info, style.background);
+    let shadow = draw_texture_layer(knob_info, style.shadow);
+    let knob = draw_rotating_texture(knob_info, &style.knob);
+    let notch = draw_notch(knob_info, &style.notch);
+    let highlight = draw_texture_layer(knob_info, style.highlight);
+
+    let modulation = draw_modulation(knob_info, value_markers);
+
+    Primitive::Group {
+        primitives: vec![
+            background,
+            tick_marks,
+            text_marks,
+            shadow,
+            knob,
+            notch,
+            highlight,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            modulation,
+        ],
+    }
+}
+
+fn draw_filmstrip_style<'a>(
+    knob_info: &KnobInfo,
+    style: FilmstripStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
+        draw_value_markers(
+            knob_info,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    let shadow = draw_texture_layer(knob_info, style.shadow);
+
+    let frame = filmstrip::draw(
+        &style.filmstrip,
+        style.filmstrip.frame_index(knob_info.value),
+        filmstrip::fit_frame(&style.filmstrip, &knob_info.bounds),
+    );
+
+    let highlight = draw_texture_layer(knob_info, style.highlight);
+
+    let modulation = draw_modulation(knob_info, value_markers);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            shadow,
+            frame,
+            highlight,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            modulation,
+        ],
+    }
+}
+
+fn draw_svg_layer(knob_info: &KnobInfo, layer: Option<SvgLayer>) -> Primitive {
+    if let Some(layer) = layer {
+        let size = layer.size.from_knob_diameter(knob_info.bounds.width);
+
+        Primitive::Svg {
+            handle: layer.handle,
+            bounds: Rectangle {
+                x: (knob_info.bounds.center_x() - (size / 2.0)).round(),
+                y: (knob_info.bounds.center_y() - (size / 2.0)).round(),
+                width: size,
+                height: size,
+            },
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_svg_indicator(
+    knob_info: &KnobInfo,
+    indicator: SvgIndicator,
+) -> Primitive {
+    let size = indicator.size.from_knob_diameter(knob_info.bounds.width);
+    let radius = indicator.radius.from_knob_diameter(knob_info.bounds.width);
+
+    let (dy, dx) = knob_info.value_angle.sin_cos();
+
+    Primitive::Svg {
+        handle: indicator.handle,
+        bounds: Rectangle {
+            x: (knob_info.bounds.center_x() + (dx * radius) - (size / 2.0))
+                .round(),
+            y: (knob_info.bounds.center_y() + (dy * radius) - (size / 2.0))
+                .round(),
+            width: size,
+            height: size,
+        },
+    }
+}
+
+fn draw_svg_style<'a>(
+    knob_info: &KnobInfo,
+    style: SvgStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
+        draw_value_markers(
+            knob_info,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    let background = draw_svg_layer(knob_info, style.background);
+    let indicator = draw_svg_indicator(knob_info, style.indicator);
+    let foreground = draw_svg_layer(knob_info, style.foreground);
+
+    let modulation = draw_modulation(knob_info, value_markers);
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            background,
+            indicator,
+            foreground,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            modulation,
+        ],
+    }
+}
+
 fn draw_circle_style<'a>(
     knob_info: &KnobInfo,
     style: CircleStyle,
@@ -546,16 +899,54 @@ fn draw_circle_style<'a>(
             text_marks_cache,
         );
 
-    let knob_back = Primitive::Quad {
-        bounds: knob_info.bounds,
-        background: Background::Color(style.color),
-        border_radius: knob_info.radius,
-        border_width: style.border_width,
-        border_color: style.border_color,
+    let knob_back = match &style.gradient {
+        Some(gradient) => Primitive::Group {
+            primitives: vec![
+                gradient::fill_circle(
+                    knob_info.bounds.center(),
+                    knob_info.radius,
+                    gradient,
+                    knob_info.value,
+                ),
+                Primitive::Quad {
+                    bounds: knob_info.bounds,
+                    background: Background::Color(Color::TRANSPARENT),
+                    border_radius: knob_info.radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+            ],
+        },
+        None => Primitive::Quad {
+            bounds: knob_info.bounds,
+            background: Background::Color(style.color),
+            border_radius: knob_info.radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        },
+    };
+
+    let shadow_and_glow = effects::shadow_and_glow(
+        knob_info.bounds,
+        knob_info.radius,
+        style.shadow.as_ref(),
+        style.glow.as_ref(),
+        knob_info.value,
+    );
+
+    let inner_shadow = match &style.inner_shadow {
+        Some(inner_shadow) => effects::inner_shadow(
+            knob_info.bounds,
+            knob_info.radius,
+            inner_shadow,
+        ),
+        None => Primitive::None,
     };
 
     let notch = draw_notch(knob_info, &style.notch);
 
+    let modulation = draw_modulation(knob_info, value_markers);
+
     Primitive::Group {
         primitives: vec![
             tick_marks,
@@ -563,8 +954,11 @@ fn draw_circle_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            shadow_and_glow,
             knob_back,
+            inner_shadow,
             notch,
+            modulation,
         ],
     }
 }
@@ -613,32 +1007,58 @@ fn draw_arc_style<'a>(
 
         frame.stroke(&empty_path, empty_stroke);
 
-        let filled_stroke = Stroke {
-            width,
-            color: style.filled_color,
-            line_cap: style.cap,
-            ..Stroke::default()
-        };
-
-        let filled_arc = Arc {
+        let filled_arc = StrokedArc {
             center: center_point,
             radius: arc_radius,
+            width,
             start_angle: knob_info.start_angle,
             end_angle: knob_info.value_angle,
+            cap: style.cap,
         };
 
-        let filled_path = Path::new(|path| path.arc(filled_arc));
+        let (shadow, glow) = draw_arc_effects(
+            knob_info,
+            &StrokedArc {
+                center: center_point,
+                radius: arc_radius,
+                width,
+                start_angle: empty_arc.start_angle,
+                end_angle: empty_arc.end_angle,
+                cap: style.cap,
+            },
+            Some(&filled_arc),
+            &style.shadow,
+            &style.glow,
+        );
 
-        frame.stroke(&filled_path, filled_stroke);
+        let filled_gradient = draw_filled_arc(
+            &mut frame,
+            knob_info,
+            filled_arc,
+            style.filled_color,
+            style.filled_gradient.as_ref(),
+        );
 
-        Primitive::Translate {
-            translation: Vector::new(knob_info.bounds.x, knob_info.bounds.y),
-            content: Box::new(frame.into_geometry().into_primitive()),
+        Primitive::Group {
+            primitives: vec![
+                shadow,
+                glow,
+                Primitive::Translate {
+                    translation: Vector::new(
+                        knob_info.bounds.x,
+                        knob_info.bounds.y,
+                    ),
+                    content: Box::new(frame.into_geometry().into_primitive()),
+                },
+                filled_gradient,
+            ],
         }
     };
 
     let notch = draw_notch(knob_info, &style.notch);
 
+    let modulation = draw_modulation(knob_info, value_markers);
+
     Primitive::Group {
         primitives: vec![
             tick_marks,
@@ -648,10 +1068,109 @@ fn draw_arc_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            modulation,
         ],
     }
 }
 
+/// Strokes the filled portion of an arc into `frame`, or returns the
+/// primitive of its [`Gradient`] if it has one. The center of `arc` is
+/// relative to the bounds of the knob.
+///
+/// [`Gradient`]: ../gradient/enum.Gradient.html
+fn draw_filled_arc(
+    frame: &mut Frame,
+    knob_info: &KnobInfo,
+    arc: StrokedArc,
+    color: Color,
+    gradient: Option<&Gradient>,
+) -> Primitive {
+    match gradient {
+        Some(gradient) => {
+            let center = Point::new(
+                knob_info.bounds.x + arc.center.x,
+                knob_info.bounds.y + arc.center.y,
+            );
+
+            gradient::fill_arc(
+                &StrokedArc { center, ..arc },
+                gradient,
+                knob_info.value,
+                Some(ValueAxis::Angle {
+                    center,
+                    start_angle: knob_info.start_angle,
+                    end_angle: knob_info.start_angle + knob_info.angle_span,
+                }),
+            )
+        }
+        None => {
+            let path = Path::new(|path| {
+                path.arc(Arc {
+                    center: arc.center,
+                    radius: arc.radius,
+                    start_angle: arc.start_angle,
+                    end_angle: arc.end_angle,
+                })
+            });
+
+            frame.stroke(
+                &path,
+                Stroke {
+                    width: arc.width,
+                    color,
+                    line_cap: arc.cap,
+                    ..Stroke::default()
+                },
+            );
+
+            Primitive::None
+        }
+    }
+}
+
+/// Returns the primitives of the [`Shadow`] underneath the whole arc and the
+/// [`Glow`] around its filled portion. The centers of the arcs are relative
+/// to the bounds of the knob.
+///
+/// [`Shadow`]: ../effects/struct.Shadow.html
+/// [`Glow`]: ../effects/struct.Glow.html
+fn draw_arc_effects(
+    knob_info: &KnobInfo,
+    empty_arc: &StrokedArc,
+    filled_arc: Option<&StrokedArc>,
+    shadow: &Option<effects::Shadow>,
+    glow: &Option<effects::Glow>,
+) -> (Primitive, Primitive) {
+    let to_bounds = |center: Point| {
+        Point::new(knob_info.bounds.x + center.x, knob_info.bounds.y + center.y)
+    };
+
+    let shadow = match shadow {
+        Some(shadow) => effects::arc_shadow(
+            &StrokedArc {
+                center: to_bounds(empty_arc.center),
+                ..*empty_arc
+            },
+            shadow,
+        ),
+        None => Primitive::None,
+    };
+
+    let glow = match (glow, filled_arc) {
+        (Some(glow), Some(filled_arc)) => effects::arc_glow(
+            &StrokedArc {
+                center: to_bounds(filled_arc.center),
+                ..*filled_arc
+            },
+            glow,
+            knob_info.value,
+        ),
+        _ => Primitive::None,
+    };
+
+    (shadow, glow)
+}
+
 enum BipolarState {
     Left,
     Right,
@@ -718,51 +1237,71 @@ fn draw_arc_bipolar_style<'a>(
 
         frame.stroke(&empty_path, empty_stroke);
 
-        match bipolar_state {
-            BipolarState::Left => {
-                let filled_stroke = Stroke {
-                    width,
-                    color: style.left_filled_color,
-                    line_cap: style.cap,
-                    ..Stroke::default()
-                };
-
-                let filled_arc = Arc {
+        let filled_arc = match bipolar_state {
+            BipolarState::Left => Some((
+                StrokedArc {
                     center: center_point,
                     radius: arc_radius,
+                    width,
                     start_angle: knob_info.value_angle,
                     end_angle: half_angle,
-                };
-
-                let filled_path = Path::new(|path| path.arc(filled_arc));
-
-                frame.stroke(&filled_path, filled_stroke);
-            }
-            BipolarState::Right => {
-                let filled_stroke = Stroke {
-                    width,
-                    color: style.right_filled_color,
-                    line_cap: style.cap,
-                    ..Stroke::default()
-                };
-
-                let filled_arc = Arc {
+                    cap: style.cap,
+                },
+                style.left_filled_color,
+            )),
+            BipolarState::Right => Some((
+                StrokedArc {
                     center: center_point,
                     radius: arc_radius,
+                    width,
                     start_angle: half_angle,
                     end_angle: knob_info.value_angle,
-                };
+                    cap: style.cap,
+                },
+                style.right_filled_color,
+            )),
+            BipolarState::Center => None,
+        };
 
-                let filled_path = Path::new(|path| path.arc(filled_arc));
+        let (shadow, glow) = draw_arc_effects(
+            knob_info,
+            &StrokedArc {
+                center: center_point,
+                radius: arc_radius,
+                width,
+                start_angle: empty_arc.start_angle,
+                end_angle: empty_arc.end_angle,
+                cap: style.cap,
+            },
+            filled_arc.as_ref().map(|(arc, _)| arc),
+            &style.shadow,
+            &style.glow,
+        );
 
-                frame.stroke(&filled_path, filled_stroke);
-            }
-            _ => {}
-        }
+        let filled_gradient = match filled_arc {
+            Some((arc, color)) => draw_filled_arc(
+                &mut frame,
+                knob_info,
+                arc,
+                color,
+                style.filled_gradient.as_ref(),
+            ),
+            None => Primitive::None,
+        };
 
-        Primitive::Translate {
-            translation: Vector::new(knob_info.bounds.x, knob_info.bounds.y),
-            content: Box::new(frame.into_geometry().into_primitive()),
+        Primitive::Group {
+            primitives: vec![
+                shadow,
+                glow,
+                Primitive::Translate {
+                    translation: Vector::new(
+                        knob_info.bounds.x,
+                        knob_info.bounds.y,
+                    ),
+                    content: Box::new(frame.into_geometry().into_primitive()),
+                },
+                filled_gradient,
+            ],
         }
     };
 
@@ -777,6 +1316,8 @@ fn draw_arc_bipolar_style<'a>(
         draw_notch(knob_info, &style.notch_center)
     };
 
+    let modulation = draw_modulation(knob_info, value_markers);
+
     Primitive::Group {
         primitives: vec![
             tick_marks,
@@ -786,6 +1327,7 @@ fn draw_arc_bipolar_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            modulation,
         ],
     }
 }
@@ -1,15 +1,29 @@
 //! A wgpu renderer for Iced Audio widgets
 
+pub mod adsr_envelope;
+pub mod envelope_editor;
+pub mod goniometer;
+pub mod h_range_slider;
 pub mod h_slider;
+pub mod keys;
 pub mod knob;
 pub mod mod_range_input;
+pub mod mod_wheel;
+pub mod par_eq_editor;
+pub mod phase_meter;
 pub mod ramp;
+pub mod spectrogram;
+pub mod v_range_slider;
 pub mod v_slider;
+pub mod waveform_view;
+pub mod wavetable_view;
 pub mod xy_pad;
 
+pub mod effects;
+pub mod filmstrip;
+pub mod gradient;
 pub mod text_marks;
 pub mod tick_marks;
 
 //pub mod db_meter;
-//pub mod phase_meter;
 //pub mod reduction_meter;
@@ -0,0 +1,177 @@
+//! Display an interactive vertical wheel that controls a [`NormalParam`],
+//! like the mod wheel and pitch wheel of a hardware synthesizer
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::Normal;
+use crate::native::mod_wheel;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::mod_wheel::{Mode, State};
+pub use crate::style::mod_wheel::{Style, StyleSheet};
+
+/// The thresholds (as the sine of the angle around the wheel) past which
+/// each layer of shading is drawn. Overlapping layers make the edges of the
+/// wheel darker than its center.
+const SHADE_THRESHOLDS: [f32; 3] = [0.5, 0.8, 0.95];
+
+/// A vertical wheel GUI widget that controls a [`NormalParam`]
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+pub type ModWheel<'a, Message, Backend> =
+    mod_wheel::ModWheel<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> mod_wheel::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normal: Normal,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let wheel_bounds = Rectangle {
+            x: bounds.x + style.wheel_padding,
+            y: bounds.y + style.wheel_padding,
+            width: (bounds.width - (style.wheel_padding * 2.0)).max(0.0),
+            height: (bounds.height - (style.wheel_padding * 2.0)).max(0.0),
+        };
+
+        let wheel = Primitive::Quad {
+            bounds: wheel_bounds,
+            background: Background::Color(style.wheel_color),
+            border_radius: style.wheel_border_radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back, wheel];
+
+        let radius = wheel_bounds.height / 2.0;
+        let center_y = wheel_bounds.y + radius;
+
+        // The angle the wheel is turned by, where up is positive.
+        let angle = (normal.as_f32() - 0.5) * 2.0 * style.max_angle;
+
+        // A line drawn around the wheel at the given angle (in degrees from
+        // the side facing the viewer), which gets thinner as it turns away.
+        let line_at = |degrees: f32, width: f32, color: Color| -> Primitive {
+            let radians = degrees.to_radians();
+            let thickness = width * radians.cos();
+
+            if thickness < 0.5 {
+                return Primitive::None;
+            }
+
+            let y = center_y - (radians.sin() * radius);
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: wheel_bounds.x,
+                    y: y - (thickness / 2.0),
+                    width: wheel_bounds.width,
+                    height: thickness,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            }
+        };
+
+        if style.ridge_spacing > 0.0 {
+            let first = ((-90.0 - angle) / style.ridge_spacing).ceil() as i32;
+            let last = ((90.0 - angle) / style.ridge_spacing).floor() as i32;
+
+            for i in first..=last {
+                // The notch is drawn in place of the ridge at the center.
+                if i == 0 {
+                    continue;
+                }
+
+                primitives.push(line_at(
+                    angle + (i as f32 * style.ridge_spacing),
+                    style.ridge_width,
+                    style.ridge_color,
+                ));
+            }
+        }
+
+        primitives.push(line_at(angle, style.notch_width, style.notch_color));
+
+        if style.shade_color.a > 0.0 {
+            for threshold in SHADE_THRESHOLDS.iter() {
+                let band_height = radius * (1.0 - threshold);
+
+                primitives.push(shade_band(
+                    wheel_bounds.x,
+                    wheel_bounds.y,
+                    wheel_bounds.width,
+                    band_height,
+                    style.shade_color,
+                ));
+                primitives.push(shade_band(
+                    wheel_bounds.x,
+                    wheel_bounds.y + wheel_bounds.height - band_height,
+                    wheel_bounds.width,
+                    band_height,
+                    style.shade_color,
+                ));
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn shade_band(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    color: Color,
+) -> Primitive {
+    Primitive::Quad {
+        bounds: Rectangle {
+            x,
+            y,
+            width,
+            height,
+        },
+        background: Background::Color(color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
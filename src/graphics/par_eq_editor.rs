@@ -0,0 +1,190 @@
+//! Display an editor for a parametric equalizer. Each band is controlled by
+//! a draggable node over a logarithmic frequency axis and a gain axis.
+
+use crate::core::Normal;
+use crate::native::par_eq_editor;
+use iced_graphics::canvas::{Fill, Frame, LineCap, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::par_eq_editor::{
+    Band, BandCurve, BandType, Slope, State,
+};
+pub use crate::style::par_eq_editor::{NodeStyle, Style, StyleSheet};
+
+/// An editor for a parametric equalizer.
+pub type ParEqEditor<'a, Message, Backend> =
+    par_eq_editor::ParEqEditor<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> par_eq_editor::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        bands: &[BandCurve],
+        combined: &[Normal],
+        spectrum: &[Normal],
+        zero_db: Normal,
+        active_band: Option<usize>,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.floor(),
+            y: bounds.y.floor(),
+            width: bounds.width.floor(),
+            height: bounds.height.floor(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let zero_y =
+            (bounds.y + ((1.0 - zero_db.as_f32()) * bounds.height)).floor();
+
+        let zero_line = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: zero_y - (style.zero_line_width / 2.0).floor(),
+                width: bounds.width,
+                height: style.zero_line_width,
+            },
+            background: Background::Color(style.zero_line_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back, zero_line];
+
+        if bounds.width > 0.0 && bounds.height > 0.0 {
+            let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+            let to_y =
+                |normal: &Normal| (1.0 - normal.as_f32()) * bounds.height;
+
+            if spectrum.len() > 1 && style.spectrum_color.a > 0.0 {
+                let x_step = bounds.width / (spectrum.len() - 1) as f32;
+
+                let path = Path::new(|p| {
+                    p.move_to(Point::new(0.0, bounds.height));
+
+                    for (i, normal) in spectrum.iter().enumerate() {
+                        p.line_to(Point::new(i as f32 * x_step, to_y(normal)));
+                    }
+
+                    p.line_to(Point::new(bounds.width, bounds.height));
+                    p.close();
+                });
+
+                frame.fill(
+                    &path,
+                    Fill {
+                        color: style.spectrum_color,
+                        ..Fill::default()
+                    },
+                );
+            }
+
+            let response_path = |response: &[Normal]| -> Path {
+                Path::new(|p| {
+                    for (x, normal) in response.iter().enumerate() {
+                        let point = Point::new(x as f32 + 0.5, to_y(normal));
+
+                        if x == 0 {
+                            p.move_to(point);
+                        } else {
+                            p.line_to(point);
+                        }
+                    }
+                })
+            };
+
+            for (i, band) in bands.iter().enumerate() {
+                if band.response.len() < 2 {
+                    continue;
+                }
+
+                let color = if active_band == Some(i) {
+                    style.active_band_line_color
+                } else {
+                    style.band_line_color
+                };
+
+                frame.stroke(
+                    &response_path(&band.response),
+                    Stroke {
+                        width: style.band_line_width,
+                        color,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            if combined.len() > 1 {
+                frame.stroke(
+                    &response_path(combined),
+                    Stroke {
+                        width: style.curve_line_width,
+                        color: style.curve_line_color,
+                        line_cap: LineCap::Round,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for (i, band) in bands.iter().enumerate() {
+            let node_style = if active_band == Some(i) {
+                &style.active_node
+            } else {
+                &style.node
+            };
+
+            let diameter = node_style.diameter;
+            let radius = diameter / 2.0;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: (band.node.x - radius).round(),
+                    y: (band.node.y - radius).round(),
+                    width: diameter,
+                    height: diameter,
+                },
+                background: Background::Color(node_style.color),
+                border_radius: radius,
+                border_width: node_style.border_width,
+                border_color: node_style.border_color,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,197 @@
+//! Display a horizontal meter of the phase correlation (and optionally the
+//! balance) of a stereo signal.
+
+use crate::core::Normal;
+use crate::graphics::gradient::{self, Gradient, ValueAxis};
+use crate::native::phase_meter;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::phase_meter::{Mode, State};
+pub use crate::style::phase_meter::{Style, StyleSheet};
+
+/// A horizontal phase correlation meter.
+pub type PhaseMeter<'a, Backend> =
+    phase_meter::PhaseMeter<'a, Renderer<Backend>>;
+
+impl<B: Backend> phase_meter::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        correlation: f32,
+        balance: Option<f32>,
+        mode: Mode,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let correlation_color = if correlation < 0.0 {
+            style.negative_color
+        } else {
+            style.positive_color
+        };
+        let correlation_fill = Fill {
+            color: correlation_color,
+            gradient: style.correlation_gradient.as_ref(),
+        };
+        let balance_fill = Fill {
+            color: style.balance_color,
+            gradient: style.balance_gradient.as_ref(),
+        };
+
+        let mut primitives = Vec::with_capacity(6);
+
+        if let Some(balance) = balance {
+            let height = ((bounds.height - style.spacing) / 2.0).floor();
+
+            let correlation_bounds = Rectangle { height, ..bounds };
+            let balance_bounds = Rectangle {
+                y: bounds.y + bounds.height - height,
+                height,
+                ..bounds
+            };
+
+            draw_meter(
+                &mut primitives,
+                correlation_bounds,
+                correlation,
+                correlation_fill,
+                mode,
+                &style,
+            );
+            draw_meter(
+                &mut primitives,
+                balance_bounds,
+                balance,
+                balance_fill,
+                mode,
+                &style,
+            );
+        } else {
+            draw_meter(
+                &mut primitives,
+                bounds,
+                correlation,
+                correlation_fill,
+                mode,
+                &style,
+            );
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// The color of the bar or needle of a meter, and its optional gradient
+struct Fill<'a> {
+    color: Color,
+    gradient: Option<&'a Gradient>,
+}
+
+fn draw_meter(
+    primitives: &mut Vec<Primitive>,
+    bounds: Rectangle,
+    value: f32,
+    fill: Fill<'_>,
+    mode: Mode,
+    style: &Style,
+) {
+    primitives.push(Primitive::Quad {
+        bounds,
+        background: Background::Color(style.back_color),
+        border_radius: 0.0,
+        border_width: style.back_border_width,
+        border_color: style.back_border_color,
+    });
+
+    let border_width = style.back_border_width;
+    let inner = Rectangle {
+        x: bounds.x + border_width,
+        y: bounds.y + border_width,
+        width: (bounds.width - (border_width * 2.0)).max(0.0),
+        height: (bounds.height - (border_width * 2.0)).max(0.0),
+    };
+
+    if inner.width <= 0.0 || inner.height <= 0.0 {
+        return;
+    }
+
+    let half_width = inner.width / 2.0;
+    let center_x = inner.x + half_width;
+    let value = value.max(-1.0).min(1.0);
+    let value_x = center_x + (value * half_width);
+
+    let value_bounds = match mode {
+        Mode::Bar => {
+            let start_x = center_x.min(value_x).round();
+            let end_x = center_x.max(value_x).round();
+
+            Rectangle {
+                x: start_x,
+                width: end_x - start_x,
+                ..inner
+            }
+        }
+        Mode::Needle => {
+            let x = (value_x - (style.needle_width / 2.0))
+                .max(inner.x)
+                .min(inner.x + inner.width - style.needle_width)
+                .round();
+
+            Rectangle {
+                x,
+                width: style.needle_width,
+                ..inner
+            }
+        }
+    };
+
+    if value_bounds.width > 0.0 {
+        primitives.push(match fill.gradient {
+            Some(gradient) => gradient::fill_rectangle(
+                value_bounds,
+                0.0,
+                gradient,
+                Normal::from((value + 1.0) / 2.0),
+                Some(ValueAxis::Line {
+                    start: Point::new(inner.x, inner.center_y()),
+                    end: Point::new(inner.x + inner.width, inner.center_y()),
+                }),
+            ),
+            None => Primitive::Quad {
+                bounds: value_bounds,
+                background: Background::Color(fill.color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+        });
+    }
+
+    if style.center_line_width > 0.0 {
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: (center_x - (style.center_line_width / 2.0)).round(),
+                width: style.center_line_width,
+                ..inner
+            },
+            background: Background::Color(style.center_line_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+    }
+}
@@ -4,13 +4,14 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::Normal;
+use crate::graphics::filmstrip;
 use crate::native::ramp;
 use iced_graphics::canvas::{Frame, LineCap, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
 
 pub use crate::native::ramp::{RampDirection, State};
-pub use crate::style::ramp::{Style, StyleSheet};
+pub use crate::style::ramp::{FilmstripStyle, Style, StyleSheet};
 
 /// A ramp GUI widget that controls a [`Param`]. It is usually used to
 /// represent the easing of a parameter between two points in time.
@@ -34,6 +35,26 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
+        let filmstrip_style = if is_dragging {
+            style_sheet.dragging_filmstrip()
+        } else if is_mouse_over {
+            style_sheet.hovered_filmstrip()
+        } else {
+            style_sheet.active_filmstrip()
+        };
+
+        if let Some(filmstrip_style) = filmstrip_style {
+            return (
+                draw_filmstrip_style(
+                    &bounds,
+                    normal,
+                    &filmstrip_style,
+                    direction,
+                ),
+                mouse::Interaction::default(),
+            );
+        }
+
         let style = if is_dragging {
             style_sheet.dragging()
         } else if is_mouse_over {
@@ -291,3 +312,21 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
         )
     }
 }
+
+fn draw_filmstrip_style(
+    bounds: &Rectangle,
+    normal: Normal,
+    style: &FilmstripStyle,
+    direction: RampDirection,
+) -> Primitive {
+    let filmstrip = match direction {
+        RampDirection::Up => &style.up,
+        RampDirection::Down => &style.down,
+    };
+
+    filmstrip::draw(
+        filmstrip,
+        filmstrip.frame_index(normal),
+        filmstrip::fit_frame(filmstrip, bounds),
+    )
+}
@@ -0,0 +1,293 @@
+//! Display a scrolling spectrogram (waterfall) of magnitude bins over time.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::native::spectrogram;
+use iced_graphics::triangle::{Mesh2D, Vertex2D};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::spectrogram::{Column, ScrollDirection, State};
+pub use crate::style::spectrogram::{ColorMap, Style, StyleSheet};
+
+/// A scrolling spectrogram widget.
+pub type Spectrogram<'a, Backend> =
+    spectrogram::Spectrogram<'a, Renderer<Backend>>;
+
+impl<B: Backend> spectrogram::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        columns: &VecDeque<Column>,
+        capacity: usize,
+        direction: ScrollDirection,
+        style_sheet: &Self::Style,
+        cache: &PrimitiveCache,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let border_width = style.back_border_width;
+        let inner = Rectangle {
+            x: bounds.x + border_width,
+            y: bounds.y + border_width,
+            width: (bounds.width - (border_width * 2.0)).max(0.0),
+            height: (bounds.height - (border_width * 2.0)).max(0.0),
+        };
+
+        let columns_primitive = if inner.width > 0.0 && inner.height > 0.0 {
+            let cached = cache.cached_columns(
+                Size::new(inner.width, inner.height),
+                columns,
+                capacity,
+                direction,
+                &style.color_map,
+            );
+
+            let len = cached.len() as f32;
+
+            let primitives = cached
+                .into_iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    // How many columns away from the newest edge this
+                    // column is.
+                    let age = len - i as f32;
+
+                    let translation = match direction {
+                        ScrollDirection::Horizontal => {
+                            let column_width = inner.width / capacity as f32;
+                            Vector::new(
+                                inner.x + inner.width - (age * column_width),
+                                inner.y,
+                            )
+                        }
+                        ScrollDirection::Vertical => {
+                            let column_height = inner.height / capacity as f32;
+                            Vector::new(
+                                inner.x,
+                                inner.y + ((age - 1.0) * column_height),
+                            )
+                        }
+                    };
+
+                    Primitive::Translate {
+                        translation,
+                        content: Box::new(Primitive::Cached { cache: column }),
+                    }
+                })
+                .collect();
+
+            Primitive::Clip {
+                bounds: inner,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group { primitives }),
+            }
+        } else {
+            Primitive::None
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![back, columns_primitive],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn build_column_mesh(
+    column: &Column,
+    size: Size,
+    capacity: usize,
+    direction: ScrollDirection,
+    color_map: &ColorMap,
+) -> Primitive {
+    let resolution = column.magnitudes.len();
+    if resolution == 0 {
+        return Primitive::None;
+    }
+
+    let mut vertices: Vec<Vertex2D> = Vec::with_capacity(resolution * 4);
+    let mut indices: Vec<u32> = Vec::with_capacity(resolution * 6);
+
+    let (mesh_size, cell_size) = match direction {
+        ScrollDirection::Horizontal => {
+            let width = size.width / capacity as f32;
+            (
+                Size::new(width, size.height),
+                size.height / resolution as f32,
+            )
+        }
+        ScrollDirection::Vertical => {
+            let height = size.height / capacity as f32;
+            (
+                Size::new(size.width, height),
+                size.width / resolution as f32,
+            )
+        }
+    };
+
+    for (i, magnitude) in column.magnitudes.iter().enumerate() {
+        let color: Color = color_map.color_at(*magnitude);
+        let color = color.into_linear();
+
+        let (x0, y0, x1, y1) = match direction {
+            ScrollDirection::Horizontal => {
+                // Low frequencies at the bottom.
+                let y1 = mesh_size.height - (i as f32 * cell_size);
+                (0.0, y1 - cell_size, mesh_size.width, y1)
+            }
+            ScrollDirection::Vertical => {
+                let x0 = i as f32 * cell_size;
+                (x0, 0.0, x0 + cell_size, mesh_size.height)
+            }
+        };
+
+        let base = vertices.len() as u32;
+
+        vertices.push(Vertex2D {
+            position: [x0, y0],
+            color,
+        });
+        vertices.push(Vertex2D {
+            position: [x1, y0],
+            color,
+        });
+        vertices.push(Vertex2D {
+            position: [x1, y1],
+            color,
+        });
+        vertices.push(Vertex2D {
+            position: [x0, y1],
+            color,
+        });
+
+        indices.extend_from_slice(&[
+            base,
+            base + 1,
+            base + 2,
+            base,
+            base + 2,
+            base + 3,
+        ]);
+    }
+
+    Primitive::Mesh2D {
+        buffers: Mesh2D { vertices, indices },
+        size: mesh_size,
+    }
+}
+
+#[derive(Debug)]
+struct PrimitiveCacheData {
+    size: Size,
+    capacity: usize,
+    direction: Option<ScrollDirection>,
+    color_map: Option<ColorMap>,
+    columns: VecDeque<(u64, Arc<Primitive>)>,
+}
+
+impl Default for PrimitiveCacheData {
+    fn default() -> Self {
+        Self {
+            size: Size::ZERO,
+            capacity: 0,
+            direction: None,
+            color_map: None,
+            columns: VecDeque::new(),
+        }
+    }
+}
+
+/// A cache for the rendered columns of a [`Spectrogram`]. Each column is
+/// only built once, and is then reused while it scrolls across the widget.
+///
+/// [`Spectrogram`]: type.Spectrogram.html
+#[derive(Debug, Default)]
+pub struct PrimitiveCache {
+    data: RefCell<PrimitiveCacheData>,
+}
+
+impl Clone for PrimitiveCache {
+    fn clone(&self) -> Self {
+        // The cache is rebuilt on the next draw.
+        Self::default()
+    }
+}
+
+impl PrimitiveCache {
+    /// Cache and retrieve the rendered columns, from oldest to newest.
+    pub fn cached_columns(
+        &self,
+        size: Size,
+        columns: &VecDeque<Column>,
+        capacity: usize,
+        direction: ScrollDirection,
+        color_map: &ColorMap,
+    ) -> Vec<Arc<Primitive>> {
+        let mut data = self.data.borrow_mut();
+
+        if !(data.size == size
+            && data.capacity == capacity
+            && data.direction == Some(direction)
+            && data.color_map.as_ref() == Some(color_map))
+        {
+            data.size = size;
+            data.capacity = capacity;
+            data.direction = Some(direction);
+            data.color_map = Some(color_map.clone());
+            data.columns.clear();
+        }
+
+        // Drop columns that have scrolled out of the history.
+        let oldest_id = columns.front().map(|c| c.id).unwrap_or(u64::MAX);
+        while let Some((id, _)) = data.columns.front() {
+            if *id < oldest_id {
+                let _ = data.columns.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        // Build any new columns.
+        let newest_cached_id = data.columns.back().map(|(id, _)| *id);
+        for column in columns.iter() {
+            let is_new = match newest_cached_id {
+                Some(newest) => column.id > newest,
+                None => true,
+            };
+
+            if is_new {
+                let primitive = build_column_mesh(
+                    column, size, capacity, direction, color_map,
+                );
+                data.columns.push_back((column.id, Arc::new(primitive)));
+            }
+        }
+
+        data.columns
+            .iter()
+            .map(|(_, primitive)| Arc::clone(primitive))
+            .collect()
+    }
+}
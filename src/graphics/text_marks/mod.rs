@@ -29,6 +29,7 @@ struct PrimitiveCacheData {
     pub radius: f32,
     pub start_angle: f32,
     pub angle_span: f32,
+    pub layout: RadialLayout,
 }
 
 impl Default for PrimitiveCacheData {
@@ -46,6 +47,7 @@ impl Default for PrimitiveCacheData {
             radius: 0.0,
             start_angle: 0.0,
             angle_span: 0.0,
+            layout: RadialLayout::default(),
         }
     }
 }
@@ -104,6 +106,7 @@ impl PrimitiveCache {
         angle_span: f32,
         text_marks: &Group,
         style: Style,
+        layout: RadialLayout,
         inverse: bool,
         builder: F,
     ) -> iced_graphics::Primitive {
@@ -115,6 +118,7 @@ impl PrimitiveCache {
             && data.angle_span == angle_span
             && data.text_marks_hash == text_marks.hashed()
             && data.style == style
+            && data.layout == layout
             && data.inverse == inverse)
         {
             data.center = center;
@@ -123,6 +127,7 @@ impl PrimitiveCache {
             data.angle_span = angle_span;
             data.text_marks_hash = text_marks.hashed();
             data.style = style;
+            data.layout = layout;
             data.inverse = inverse;
 
             data.cache = Arc::new(builder());
@@ -1,6 +1,7 @@
 use super::PrimitiveCache;
+use crate::core::Normal;
 use crate::native::text_marks;
-use crate::style::text_marks::Style;
+use crate::style::text_marks::{Orientation, RadialAlign, RadialLayout, Style};
 
 use iced_graphics::{alignment, Point, Primitive, Rectangle};
 
@@ -14,7 +15,10 @@ use iced_graphics::{alignment, Point, Primitive, Rectangle};
 /// * `style` - The text marks style.
 /// * `h_char_offset` - Extra horizontal offset in pixels for each additional
 /// character in the text label. This is used to keep longer labels on the sides
-/// from being too close to the arc.
+/// from being too close to the arc. This is only used by
+/// `Orientation::Upright`.
+/// * `layout` - The orientation, alignment, and overlap handling of the
+/// labels.
 /// * `inverse` - Whether to inverse the positions of the text marks (true) or
 /// not (false).
 pub fn draw_radial_text_marks(
@@ -25,6 +29,7 @@ pub fn draw_radial_text_marks(
     text_marks: &text_marks::Group,
     style: &Style,
     h_char_offset: f32,
+    layout: &RadialLayout,
     inverse: bool,
     cache: &PrimitiveCache,
 ) -> Primitive {
@@ -35,9 +40,11 @@ pub fn draw_radial_text_marks(
         angle_span,
         text_marks,
         *style,
+        *layout,
         inverse,
         || {
             let mut primitives: Vec<Primitive> = Vec::new();
+            let mut drawn: Vec<Rectangle> = Vec::new();
 
             let color = style.color;
             let font = style.font;
@@ -45,47 +52,234 @@ pub fn draw_radial_text_marks(
             let text_bounds_width = f32::from(style.bounds_width);
             let text_bounds_height = f32::from(style.bounds_height);
 
-            let start_angle = start_angle + std::f32::consts::FRAC_PI_2;
-
-            for (position, text) in text_marks.group.iter() {
+            for (index, (position, text)) in text_marks.group.iter().enumerate()
+            {
                 let angle = if inverse {
                     start_angle + position.scale_inv(angle_span)
                 } else {
                     start_angle + position.scale(angle_span)
                 };
 
-                let (dx, dy) = {
-                    if angle < -0.001 || angle > 0.001 {
-                        angle.sin_cos()
-                    } else {
-                        (0.0, -1.0)
+                let label = Label {
+                    center,
+                    radius,
+                    angle,
+                    position: *position,
+                    text,
+                    char_width: layout.char_width,
+                    inverse,
+                };
+
+                let align =
+                    text_marks.radial_align(index).unwrap_or(layout.align);
+
+                let glyphs = match layout.orientation {
+                    Orientation::Upright => {
+                        vec![label.upright(h_char_offset)]
                     }
+                    Orientation::Tangential => label.tangential(align),
+                    Orientation::Radial => label.radial(align),
                 };
 
-                let mut offset_x = dx * radius;
-                if offset_x < -0.001 {
-                    offset_x -= (text.len() as f32 - 1.0) * h_char_offset;
-                } else if offset_x > 0.001 {
-                    offset_x += (text.len() as f32 - 1.0) * h_char_offset;
+                if layout.hide_overlapping {
+                    let label_bounds: Vec<Rectangle> = glyphs
+                        .iter()
+                        .map(|glyph| glyph_bounds(glyph, layout, text_size))
+                        .collect();
+
+                    if label_bounds.iter().any(|glyph| {
+                        drawn
+                            .iter()
+                            .any(|bounds| bounds.intersection(glyph).is_some())
+                    }) {
+                        continue;
+                    }
+
+                    drawn.extend(label_bounds);
                 }
 
-                primitives.push(Primitive::Text {
-                    content: text.clone(),
-                    size: text_size,
-                    bounds: Rectangle {
-                        x: (center.x + offset_x).round(),
-                        y: (center.y - (dy * radius)).round(),
-                        width: text_bounds_width,
-                        height: text_bounds_height,
-                    },
-                    color,
-                    font,
-                    horizontal_alignment: alignment::Horizontal::Center,
-                    vertical_alignment: alignment::Vertical::Center,
-                });
+                for (point, content) in glyphs {
+                    primitives.push(Primitive::Text {
+                        content,
+                        size: text_size,
+                        bounds: Rectangle {
+                            x: point.x.round(),
+                            y: point.y.round(),
+                            width: text_bounds_width,
+                            height: text_bounds_height,
+                        },
+                        color,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
             }
 
             Primitive::Group { primitives }
         },
     )
 }
+
+/// A label that is being laid out around an arc
+struct Label<'a> {
+    center: Point,
+    radius: f32,
+    /// The angle of the position of the label
+    angle: f32,
+    position: Normal,
+    text: &'a str,
+    char_width: f32,
+    inverse: bool,
+}
+
+impl<'a> Label<'a> {
+    /// The whole label, centered on its position, and pushed away from the
+    /// arc by `h_char_offset` for each additional character.
+    fn upright(&self, h_char_offset: f32) -> (Point, String) {
+        let angle = self.angle + std::f32::consts::FRAC_PI_2;
+
+        let (dx, dy) = {
+            if angle < -0.001 || angle > 0.001 {
+                angle.sin_cos()
+            } else {
+                (0.0, -1.0)
+            }
+        };
+
+        let mut offset_x = dx * self.radius;
+        if offset_x < -0.001 {
+            offset_x -= (self.text.len() as f32 - 1.0) * h_char_offset;
+        } else if offset_x > 0.001 {
+            offset_x += (self.text.len() as f32 - 1.0) * h_char_offset;
+        }
+
+        (
+            Point::new(
+                self.center.x + offset_x,
+                self.center.y - (dy * self.radius),
+            ),
+            String::from(self.text),
+        )
+    }
+
+    /// Each character of the label along the arc.
+    fn tangential(&self, align: RadialAlign) -> Vec<(Point, String)> {
+        let radius = self.radius.max(1.0);
+
+        // The label spans `start..end` pixels along the arc from its
+        // position, where positive values go towards higher values.
+        let (start, end) = self.extent(align, true);
+        let (start, end) = if self.inverse {
+            (-end, -start)
+        } else {
+            (start, end)
+        };
+
+        // Read towards higher angles on the top half of the arc, and towards
+        // lower angles on the bottom half.
+        let middle_angle = self.angle + ((start + end) / 2.0) / radius;
+        let forward = middle_angle.sin() <= 0.0;
+
+        self.glyphs(start, end, forward, |offset| {
+            let angle = self.angle + offset / radius;
+
+            Point::new(
+                self.center.x + radius * angle.cos(),
+                self.center.y + radius * angle.sin(),
+            )
+        })
+    }
+
+    /// Each character of the label along a line that points away from the
+    /// center of the arc.
+    fn radial(&self, align: RadialAlign) -> Vec<(Point, String)> {
+        let (start, end) = self.extent(align, false);
+
+        let (sin, cos) = self.angle.sin_cos();
+
+        // Read outwards on the right half of the arc, and inwards on the left
+        // half.
+        let forward = cos >= 0.0;
+
+        self.glyphs(start, end, forward, |offset| {
+            Point::new(
+                self.center.x + (self.radius + offset) * cos,
+                self.center.y + (self.radius + offset) * sin,
+            )
+        })
+    }
+
+    /// The offsets in pixels where the label starts and ends, relative to
+    /// its position.
+    fn extent(&self, align: RadialAlign, use_edges: bool) -> (f32, f32) {
+        let length = self.text.chars().count() as f32 * self.char_width;
+
+        let align = match align {
+            RadialAlign::Edges if !use_edges => RadialAlign::Start,
+            RadialAlign::Edges => {
+                if self.position.as_f32() < 0.001 {
+                    RadialAlign::Start
+                } else if self.position.as_f32() > 0.999 {
+                    RadialAlign::End
+                } else {
+                    RadialAlign::Center
+                }
+            }
+            align => align,
+        };
+
+        match align {
+            RadialAlign::Start => (0.0, length),
+            RadialAlign::End => (-length, 0.0),
+            _ => (-length / 2.0, length / 2.0),
+        }
+    }
+
+    /// Places each character of the label between the `start` and `end`
+    /// offsets, in reading order.
+    fn glyphs(
+        &self,
+        start: f32,
+        end: f32,
+        forward: bool,
+        point_at: impl Fn(f32) -> Point,
+    ) -> Vec<(Point, String)> {
+        self.text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let advance = (i as f32 + 0.5) * self.char_width;
+                let offset = if forward {
+                    start + advance
+                } else {
+                    end - advance
+                };
+
+                (point_at(offset), c.to_string())
+            })
+            .collect()
+    }
+}
+
+/// The bounds around one character of a label, with half of the spacing
+/// between labels on each side.
+///
+/// Each character is checked on its own, so rotated labels only hide each
+/// other where their characters actually meet.
+fn glyph_bounds(
+    (point, content): &(Point, String),
+    layout: &RadialLayout,
+    text_size: f32,
+) -> Rectangle {
+    let width =
+        content.chars().count() as f32 * layout.char_width + layout.spacing;
+    let height = text_size + layout.spacing;
+
+    Rectangle {
+        x: point.x - (width / 2.0),
+        y: point.y - (height / 2.0),
+        width,
+        height,
+    }
+}
@@ -0,0 +1,265 @@
+//! Display an interactive vertical slider with multiple handles that each
+//! control a [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::Normal;
+use crate::graphics::effects;
+use crate::graphics::gradient::{self, ValueAxis};
+use crate::graphics::{text_marks, tick_marks};
+use crate::native::v_range_slider;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::v_range_slider::State;
+pub use crate::style::v_range_slider::{
+    RectStyle, Style, StyleSheet, TextMarksStyle, TickMarksStyle,
+};
+
+/// A vertical slider GUI widget with multiple handles that each control a
+/// [`NormalParam`]
+///
+/// an [`VRangeSlider`] will try to fill the vertical space of its container.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`VRangeSlider`]: struct.VRangeSlider.html
+pub type VRangeSlider<'a, Message, Backend> =
+    v_range_slider::VRangeSlider<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> v_range_slider::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normals: &[Normal],
+        is_dragging: bool,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style_sheet: &Self::Style,
+        tick_marks_cache: &tick_marks::PrimitiveCache,
+        text_marks_cache: &text_marks::PrimitiveCache,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let primitives = match style {
+            Style::Rect(style) => {
+                let handle_height = f32::from(style.handle_height);
+
+                let value_bounds = Rectangle {
+                    x: bounds.x,
+                    y: (bounds.y + (handle_height / 2.0)).round(),
+                    width: bounds.width,
+                    height: bounds.height - handle_height,
+                };
+
+                let tick_marks = draw_tick_marks(
+                    &value_bounds,
+                    tick_marks,
+                    &style_sheet.tick_marks_style(),
+                    tick_marks_cache,
+                );
+
+                let text_marks = draw_text_marks(
+                    &value_bounds,
+                    text_marks,
+                    &style_sheet.text_marks_style(),
+                    text_marks_cache,
+                );
+
+                draw_rect_style(
+                    normals,
+                    &bounds,
+                    &value_bounds,
+                    &style,
+                    tick_marks,
+                    text_marks,
+                )
+            }
+        };
+
+        (primitives, mouse::Interaction::default())
+    }
+}
+
+fn draw_tick_marks(
+    bounds: &Rectangle,
+    tick_marks: Option<&tick_marks::Group>,
+    tick_marks_style: &Option<TickMarksStyle>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(tick_marks) = tick_marks {
+        if let Some(style) = tick_marks_style {
+            tick_marks::draw_vertical_tick_marks(
+                bounds,
+                tick_marks,
+                &style.style,
+                &style.placement,
+                false,
+                tick_marks_cache,
+            )
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_text_marks(
+    value_bounds: &Rectangle,
+    text_marks: Option<&text_marks::Group>,
+    text_marks_style: &Option<TextMarksStyle>,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    if let Some(text_marks) = text_marks {
+        if let Some(style) = text_marks_style {
+            text_marks::draw_vertical_text_marks(
+                &value_bounds,
+                text_marks,
+                &style.style,
+                &style.placement,
+                false,
+                text_marks_cache,
+            )
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_rect_style(
+    normals: &[Normal],
+    bounds: &Rectangle,
+    value_bounds: &Rectangle,
+    style: &RectStyle,
+    tick_marks: Primitive,
+    text_marks: Primitive,
+) -> Primitive {
+    let handle_height = f32::from(style.handle_height);
+
+    let empty_rect = Primitive::Quad {
+        bounds: Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: bounds.width,
+            height: bounds.height,
+        },
+        background: Background::Color(style.back_color),
+        border_radius: style.back_border_radius,
+        border_width: style.back_border_width,
+        border_color: style.back_border_color,
+    };
+
+    let border_width = f32::from(style.back_border_width);
+    let twice_border_width = border_width * 2.0;
+
+    let handle_offsets: Vec<f32> = normals
+        .iter()
+        .map(|normal| {
+            normal
+                .scale_inv(value_bounds.height - twice_border_width)
+                .round()
+        })
+        .collect();
+
+    let min_offset = handle_offsets.iter().cloned().fold(f32::MAX, f32::min);
+    let max_offset = handle_offsets.iter().cloned().fold(f32::MIN, f32::max);
+
+    // The region between the outermost handles is filled.
+    let filled_start = min_offset
+        + handle_height
+        + twice_border_width
+        + f32::from(style.handle_filled_gap);
+    let filled_end = max_offset - f32::from(style.handle_filled_gap);
+
+    let filled_rect = if filled_end > filled_start {
+        let filled_bounds = Rectangle {
+            x: bounds.x,
+            y: bounds.y + filled_start,
+            width: bounds.width,
+            height: filled_end - filled_start,
+        };
+
+        // A `Gradient::Value` follows the highest value.
+        let highest = normals.iter().cloned().fold(Normal::min(), |a, b| {
+            if b.as_f32() > a.as_f32() {
+                b
+            } else {
+                a
+            }
+        });
+
+        match &style.filled_gradient {
+            Some(gradient) => gradient::fill_rectangle(
+                filled_bounds,
+                style.back_border_radius,
+                gradient,
+                highest,
+                Some(ValueAxis::Line {
+                    start: Point::new(
+                        bounds.center_x(),
+                        bounds.y + bounds.height,
+                    ),
+                    end: Point::new(bounds.center_x(), bounds.y),
+                }),
+            ),
+            None => Primitive::Quad {
+                bounds: filled_bounds,
+                background: Background::Color(style.filled_color),
+                border_radius: style.back_border_radius,
+                border_width: style.back_border_width,
+                border_color: Color::TRANSPARENT,
+            },
+        }
+    } else {
+        Primitive::None
+    };
+
+    let mut primitives = vec![empty_rect, tick_marks, text_marks, filled_rect];
+
+    for (normal, handle_offset) in normals.iter().zip(handle_offsets.iter()) {
+        let handle_bounds = Rectangle {
+            x: bounds.x,
+            y: bounds.y + handle_offset,
+            width: bounds.width,
+            height: handle_height + twice_border_width,
+        };
+
+        primitives.push(effects::shadow_and_glow(
+            handle_bounds,
+            style.back_border_radius,
+            style.handle_shadow.as_ref(),
+            style.handle_glow.as_ref(),
+            *normal,
+        ));
+
+        primitives.push(Primitive::Quad {
+            bounds: handle_bounds,
+            background: Background::Color(style.handle_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: Color::TRANSPARENT,
+        });
+    }
+
+    Primitive::Group { primitives }
+}
@@ -0,0 +1,278 @@
+//! Display the peak amplitude of an audio clip, with zooming, scrolling, a
+//! selection region, and draggable markers.
+
+use crate::native::waveform_view;
+use iced_graphics::canvas::{Fill, Frame, Path};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::waveform_view::{
+    Marker, Peak, PeakSource, State, WaveformEvent,
+};
+pub use crate::style::waveform_view::{MarkerStyle, Style, StyleSheet};
+
+/// A view of the peak amplitude of an audio clip.
+pub type WaveformView<'a, Message, Backend> =
+    waveform_view::WaveformView<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> waveform_view::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        peaks: &[Peak],
+        selection: Option<(f32, f32)>,
+        markers: &[(Marker, f32)],
+        active_marker: Option<Marker>,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.floor(),
+            y: bounds.y.floor(),
+            width: bounds.width.floor(),
+            height: bounds.height.floor(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let mut primitives: Vec<Primitive> = vec![back];
+
+        let left = bounds.x;
+        let right = bounds.x + bounds.width;
+        let clamp_x = |x: f32| x.max(left).min(right).round();
+
+        let region =
+            |start: f32, end: f32, color: Color| -> Option<Primitive> {
+                let start = clamp_x(start);
+                let end = clamp_x(end);
+
+                if end > start && color.a > 0.0 {
+                    Some(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: start,
+                            y: bounds.y,
+                            width: end - start,
+                            height: bounds.height,
+                        },
+                        background: Background::Color(color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    })
+                } else {
+                    None
+                }
+            };
+
+        let marker_x = |marker: Marker| -> Option<f32> {
+            markers.iter().find(|(m, _)| *m == marker).map(|(_, x)| *x)
+        };
+
+        if let Some(x) = marker_x(Marker::SampleStart) {
+            primitives.extend(region(left, x, style.outside_color));
+        }
+        if let Some(x) = marker_x(Marker::SampleEnd) {
+            primitives.extend(region(x, right, style.outside_color));
+        }
+
+        if let (Some(start), Some(end)) =
+            (marker_x(Marker::LoopStart), marker_x(Marker::LoopEnd))
+        {
+            primitives.extend(region(start, end, style.loop_color));
+        }
+
+        if let Some((start, end)) = selection {
+            primitives.extend(region(start, end, style.selection_color));
+        }
+
+        let center_y = (bounds.y + (bounds.height / 2.0)).floor();
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: center_y - (style.center_line_width / 2.0).floor(),
+                width: bounds.width,
+                height: style.center_line_width,
+            },
+            background: Background::Color(style.center_line_color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        if !peaks.is_empty() && bounds.width > 0.0 && bounds.height > 0.0 {
+            let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+            let half_height = bounds.height / 2.0;
+
+            let to_y = |amplitude: f32| {
+                half_height - (amplitude.max(-1.0).min(1.0) * half_height)
+            };
+
+            let peak_columns: Vec<(f32, f32)> = peaks
+                .iter()
+                .map(|peak| (to_y(peak.max), to_y(peak.min)))
+                .collect();
+
+            frame.fill(
+                &columns_path(&peak_columns, bounds.width),
+                Fill {
+                    color: style.wave_color,
+                    ..Fill::default()
+                },
+            );
+
+            if style.rms_color.a > 0.0 {
+                // The RMS is drawn symmetrically around the center, but never
+                // outside of the peaks.
+                let rms_columns: Vec<(f32, f32)> = peaks
+                    .iter()
+                    .map(|peak| {
+                        (
+                            to_y(peak.rms.min(peak.max)),
+                            to_y((-peak.rms).max(peak.min)),
+                        )
+                    })
+                    .collect();
+
+                frame.fill(
+                    &columns_path(&rms_columns, bounds.width),
+                    Fill {
+                        color: style.rms_color,
+                        ..Fill::default()
+                    },
+                );
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            });
+        }
+
+        for (marker, x) in markers.iter() {
+            if *x < left || *x > right {
+                continue;
+            }
+
+            let marker_style = match marker {
+                Marker::Playhead => &style.playhead,
+                Marker::LoopStart | Marker::LoopEnd => &style.loop_marker,
+                Marker::SampleStart | Marker::SampleEnd => &style.sample_marker,
+            };
+
+            let color = if active_marker == Some(*marker) {
+                style.active_marker_color
+            } else {
+                marker_style.color
+            };
+
+            let line_x = (x - (marker_style.width / 2.0)).round();
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: line_x,
+                    y: bounds.y,
+                    width: marker_style.width,
+                    height: bounds.height,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            if marker_style.handle_size > 0.0 {
+                let size = marker_style.handle_size;
+
+                // Start markers hang to the right of their line, and end
+                // markers hang to the left.
+                let handle_x = match marker {
+                    Marker::LoopStart | Marker::SampleStart => line_x,
+                    Marker::LoopEnd | Marker::SampleEnd => {
+                        line_x + marker_style.width - size
+                    }
+                    Marker::Playhead => (x - (size / 2.0)).round(),
+                };
+
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: handle_x,
+                        y: bounds.y,
+                        width: size,
+                        height: size,
+                    },
+                    background: Background::Color(color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns a closed path around evenly spaced columns, given as the top and
+/// bottom of each column. Columns are at least one pixel tall so that quiet
+/// parts are still visible.
+fn columns_path(columns: &[(f32, f32)], width: f32) -> Path {
+    let x_step = width / columns.len() as f32;
+
+    let columns: Vec<(f32, f32)> = columns
+        .iter()
+        .map(|(top, bottom)| {
+            if bottom - top < 1.0 {
+                let middle = (top + bottom) / 2.0;
+                (middle - 0.5, middle + 0.5)
+            } else {
+                (*top, *bottom)
+            }
+        })
+        .collect();
+
+    Path::new(|p| {
+        for (i, (top, _)) in columns.iter().enumerate() {
+            let x = i as f32 * x_step;
+
+            if i == 0 {
+                p.move_to(Point::new(x, *top));
+            } else {
+                p.line_to(Point::new(x, *top));
+            }
+            p.line_to(Point::new(x + x_step, *top));
+        }
+
+        for (i, (_, bottom)) in columns.iter().enumerate().rev() {
+            let x = i as f32 * x_step;
+
+            p.line_to(Point::new(x + x_step, *bottom));
+            p.line_to(Point::new(x, *bottom));
+        }
+
+        p.close();
+    })
+}
@@ -0,0 +1,234 @@
+//! Display a single cycle of a waveform, optionally as a stack of the frames
+//! of a wavetable. In draw mode, the current frame can be sketched with the
+//! mouse.
+
+use crate::native::wavetable_view;
+use iced_graphics::canvas::{Fill, Frame, LineCap, LineJoin, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+
+pub use crate::native::wavetable_view::{
+    frame_bounds, frame_depth, normalize, smooth, State,
+};
+pub use crate::style::wavetable_view::{Style, StyleSheet};
+
+/// A view of a single-cycle waveform, or of the frames of a wavetable.
+pub type WavetableView<'a, Message, Backend> =
+    wavetable_view::WavetableView<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> wavetable_view::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        frames: &[Vec<f32>],
+        current_frame: usize,
+        stack_offset: Option<Vector>,
+        max_stack_frames: usize,
+        is_drawing: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_drawing {
+            style_sheet.drawing()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.floor(),
+            y: bounds.y.floor(),
+            width: bounds.width.floor(),
+            height: bounds.height.floor(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if frames.is_empty() || bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        // The frame is drawn relative to the top-left corner of the bounds.
+        let local_bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            ..bounds
+        };
+        let num_frames = frames.len();
+        let current_frame = current_frame.min(num_frames - 1);
+
+        let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+        if stack_offset.is_some() && num_frames > 1 {
+            let max_stack_frames = max_stack_frames.max(2);
+
+            let stack = Path::new(|p| {
+                // From back to front.
+                for k in (0..num_frames.min(max_stack_frames)).rev() {
+                    let index = if num_frames > max_stack_frames {
+                        ((k * (num_frames - 1)) as f32
+                            / (max_stack_frames - 1) as f32)
+                            .round() as usize
+                    } else {
+                        k
+                    };
+
+                    if index == current_frame {
+                        continue;
+                    }
+
+                    let rect = frame_bounds(
+                        &local_bounds,
+                        stack_offset,
+                        frame_depth(index, num_frames),
+                    );
+
+                    let points = wave_points(&frames[index], &rect);
+
+                    if let Some(first) = points.first() {
+                        p.move_to(*first);
+                        for point in points.iter().skip(1) {
+                            p.line_to(*point);
+                        }
+                    }
+                }
+            });
+
+            frame.stroke(
+                &stack,
+                Stroke {
+                    width: style.stack_width,
+                    color: style.stack_color,
+                    line_join: LineJoin::Round,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let rect = frame_bounds(
+            &local_bounds,
+            stack_offset,
+            frame_depth(current_frame, num_frames),
+        );
+        let center_y = rect.y + (rect.height / 2.0);
+
+        let points = wave_points(&frames[current_frame], &rect);
+
+        if let (Some(first), Some(last)) = (points.first(), points.last()) {
+            if style.fill_color.a > 0.0 {
+                let fill = Path::new(|p| {
+                    p.move_to(Point::new(first.x, center_y));
+                    for point in points.iter() {
+                        p.line_to(*point);
+                    }
+                    p.line_to(Point::new(last.x, center_y));
+                    p.close();
+                });
+
+                frame.fill(
+                    &fill,
+                    Fill {
+                        color: style.fill_color,
+                        ..Fill::default()
+                    },
+                );
+            }
+
+            if style.center_line_width > 0.0 {
+                let center_line = Path::line(
+                    Point::new(rect.x, center_y),
+                    Point::new(rect.x + rect.width, center_y),
+                );
+
+                frame.stroke(
+                    &center_line,
+                    Stroke {
+                        width: style.center_line_width,
+                        color: style.center_line_color,
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            let wave = Path::new(|p| {
+                p.move_to(*first);
+                for point in points.iter().skip(1) {
+                    p.line_to(*point);
+                }
+            });
+
+            frame.stroke(
+                &wave,
+                Stroke {
+                    width: style.wave_width,
+                    color: style.wave_color,
+                    line_cap: LineCap::Round,
+                    line_join: LineJoin::Round,
+                },
+            );
+        }
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    back,
+                    Primitive::Translate {
+                        translation: Vector::new(bounds.x, bounds.y),
+                        content: Box::new(
+                            frame.into_geometry().into_primitive(),
+                        ),
+                    },
+                ],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns the points of the line of a frame in `rect`. When the frame has
+/// more samples than there are pixels, one sample is taken per pixel.
+fn wave_points(samples: &[f32], rect: &Rectangle) -> Vec<Point> {
+    let len = samples.len();
+
+    if len == 0 || rect.width <= 0.0 || rect.height <= 0.0 {
+        return Vec::new();
+    }
+
+    let half_height = rect.height / 2.0;
+    let center_y = rect.y + half_height;
+
+    let to_point = |x: f32, sample: f32| -> Point {
+        Point::new(x, center_y - (sample.max(-1.0).min(1.0) * half_height))
+    };
+
+    if len == 1 {
+        return vec![
+            to_point(rect.x, samples[0]),
+            to_point(rect.x + rect.width, samples[0]),
+        ];
+    }
+
+    let last = (len - 1) as f32;
+    let num_points = len.min(rect.width.ceil() as usize + 1).max(2);
+    let last_point = (num_points - 1) as f32;
+
+    (0..num_points)
+        .map(|i| {
+            let normal = i as f32 / last_point;
+            let index = (normal * last).round() as usize;
+
+            to_point(rect.x + (normal * rect.width), samples[index])
+        })
+        .collect()
+}
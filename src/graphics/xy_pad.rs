@@ -4,6 +4,7 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::Normal;
+use crate::graphics::gradient::{self, ValueAxis};
 use crate::native::xy_pad;
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
@@ -57,17 +58,45 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             }
         };
 
-        let back = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds_x,
-                y: bounds_y,
-                width: bounds_size,
-                height: bounds_size,
+        let back_bounds = Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        };
+
+        let back = match &style.back_gradient {
+            Some(gradient) => Primitive::Group {
+                primitives: vec![
+                    gradient::fill_rectangle(
+                        back_bounds,
+                        0.0,
+                        gradient,
+                        normal_x,
+                        Some(ValueAxis::Line {
+                            start: Point::new(bounds_x, back_bounds.center_y()),
+                            end: Point::new(
+                                bounds_x + bounds_size,
+                                back_bounds.center_y(),
+                            ),
+                        }),
+                    ),
+                    Primitive::Quad {
+                        bounds: back_bounds,
+                        background: Background::Color(Color::TRANSPARENT),
+                        border_radius: 0.0,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                ],
+            },
+            None => Primitive::Quad {
+                bounds: back_bounds,
+                background: Background::Color(style.back_color),
+                border_radius: 0.0,
+                border_width: style.border_width,
+                border_color: style.border_color,
             },
-            background: Background::Color(style.back_color),
-            border_radius: 0.0,
-            border_width: style.border_width,
-            border_color: style.border_color,
         };
 
         let handle_x = (bounds_x + (bounds_size * normal_x.as_f32())).floor();
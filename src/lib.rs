@@ -212,14 +212,22 @@ pub use crate::core::*;
 mod platform {
     #[doc(no_inline)]
     pub use crate::graphics::{
-        h_slider, knob, mod_range_input, ramp, text_marks, tick_marks,
-        v_slider, xy_pad,
+        adsr_envelope, effects, envelope_editor, filmstrip, goniometer, gradient,
+        h_range_slider, h_slider, keys, knob, mod_range_input, mod_wheel,
+        par_eq_editor, phase_meter, ramp, spectrogram, text_marks, tick_marks,
+        v_range_slider, v_slider, waveform_view, wavetable_view, xy_pad,
     };
 
     #[doc(no_inline)]
     pub use {
-        h_slider::HSlider, knob::Knob, mod_range_input::ModRangeInput,
-        ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
+        adsr_envelope::ADSREnvelope, envelope_editor::EnvelopeEditor,
+        goniometer::Goniometer, h_range_slider::HRangeSlider,
+        h_slider::HSlider, keys::Keys, knob::Knob,
+        mod_range_input::ModRangeInput, mod_wheel::ModWheel,
+        par_eq_editor::ParEqEditor, phase_meter::PhaseMeter, ramp::Ramp,
+        spectrogram::Spectrogram, v_range_slider::VRangeSlider,
+        v_slider::VSlider, waveform_view::WaveformView,
+        wavetable_view::WavetableView, xy_pad::XYPad,
     };
 }
 
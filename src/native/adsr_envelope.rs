@@ -0,0 +1,838 @@
+//! Display an ADSR envelope with optional delay and hold stages. Each stage
+//! is controlled by a draggable handle, and the curve of the attack, decay,
+//! and release stages can be adjusted.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_HANDLE_RADIUS: f32 = 6.0;
+static DEFAULT_SCALAR: f32 = 0.00385;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// A stage of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// The (optional) time before the attack stage starts
+    Delay,
+    /// The time it takes to rise from zero to the peak
+    Attack,
+    /// The (optional) time the envelope stays at the peak
+    Hold,
+    /// The time it takes to fall from the peak to the sustain level
+    Decay,
+    /// The level held while the note is held down
+    Sustain,
+    /// The time it takes to fall from the sustain level to zero
+    Release,
+}
+
+impl Stage {
+    /// All stages in the order they appear in the envelope.
+    pub const ALL: [Stage; 6] = [
+        Stage::Delay,
+        Stage::Attack,
+        Stage::Hold,
+        Stage::Decay,
+        Stage::Sustain,
+        Stage::Release,
+    ];
+
+    /// Returns `true` if the curve of this stage can be adjusted. This is
+    /// `true` for the attack, decay, and release stages.
+    pub fn has_curve(&self) -> bool {
+        match self {
+            Stage::Attack | Stage::Decay | Stage::Release => true,
+            _ => false,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Stage::Delay => 0,
+            Stage::Attack => 1,
+            Stage::Hold => 2,
+            Stage::Decay => 3,
+            Stage::Sustain => 4,
+            Stage::Release => 5,
+        }
+    }
+}
+
+/// The position of the handle of a [`Stage`] in an [`ADSREnvelope`].
+///
+/// [`Stage`]: enum.Stage.html
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Handle {
+    /// The stage controlled by this handle
+    pub stage: Stage,
+    /// The position of the center of this handle
+    pub position: Point,
+}
+
+/// The line drawn for a [`Stage`] in an [`ADSREnvelope`].
+///
+/// [`Stage`]: enum.Stage.html
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Segment {
+    /// The stage this segment represents
+    pub stage: Stage,
+    /// The position where this segment starts
+    pub start: Point,
+    /// The position where this segment ends
+    pub end: Point,
+    /// The curve of this segment. A value of `0.5` represents a straight
+    /// line, `0.0` is curved downward all the way, and `1.0` is curved upward
+    /// all the way.
+    pub curve: Normal,
+}
+
+/// An ADSR envelope GUI widget with optional delay and hold stages.
+///
+/// Each time stage (delay, attack, hold, decay, and release) takes up an
+/// equal slot along the `x` axis, and its handle moves within that slot. This
+/// means the time of a [`NormalParam`] mapped with a [`SkewedRange`] keeps
+/// short times editable.
+///
+/// * Drag a handle to adjust its stage.
+/// * Drag up and down on the attack, decay, or release line to adjust its
+/// curve.
+/// * Double-click on a handle or line to reset it to its default value.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`SkewedRange`]: ../../core/range/struct.SkewedRange.html
+#[allow(missing_debug_implementations)]
+pub struct ADSREnvelope<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Stage, Normal) -> Message>,
+    on_curve_change: Box<dyn Fn(Stage, Normal) -> Message>,
+    scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    handle_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    ADSREnvelope<'a, Message, Renderer>
+{
+    /// Creates a new [`ADSREnvelope`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ADSREnvelope`]
+    ///   * a function that will be called with the [`Stage`] and its new
+    /// value when a handle is dragged
+    ///   * a function that will be called with the [`Stage`] and its new
+    /// curve when the curve of a stage is dragged
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Stage`]: enum.Stage.html
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn new<F, C>(
+        state: &'a mut State,
+        on_change: F,
+        on_curve_change: C,
+    ) -> Self
+    where
+        F: 'static + Fn(Stage, Normal) -> Message,
+        C: 'static + Fn(Stage, Normal) -> Message,
+    {
+        ADSREnvelope {
+            state,
+            on_change: Box::new(on_change),
+            on_curve_change: Box::new(on_curve_change),
+            scalar: DEFAULT_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            handle_radius: DEFAULT_HANDLE_RADIUS,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ADSREnvelope`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ADSREnvelope`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the radius (in pixels) around the center of a handle in which the
+    /// handle can be grabbed by the mouse.
+    ///
+    /// The default value is `6.0`
+    pub fn handle_radius(mut self, radius: f32) -> Self {
+        self.handle_radius = radius;
+        self
+    }
+
+    /// Sets how much the [`Normal`] curve of a stage will change per `y`
+    /// pixel movement of the mouse.
+    ///
+    /// The default value is `0.00385`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets the modifier keys of the [`ADSREnvelope`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a handle or a curve while
+    /// holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause handles and
+    /// curves to move half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn is_modifier_pressed(&self) -> bool {
+        keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.modifier_keys
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Drag {
+    None,
+    Handle(Stage),
+    Curve(Stage),
+}
+
+/// The local state of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    params: [Option<NormalParam>; 6],
+    curves: [NormalParam; 6],
+    drag: Drag,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_normal: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`ADSREnvelope`] state without delay and hold stages.
+    ///
+    /// It expects:
+    /// * the [`NormalParam`] of the attack time
+    /// * the [`NormalParam`] of the decay time
+    /// * the [`NormalParam`] of the sustain level
+    /// * the [`NormalParam`] of the release time
+    ///
+    /// All curves start as straight lines.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn new(
+        attack: NormalParam,
+        decay: NormalParam,
+        sustain: NormalParam,
+        release: NormalParam,
+    ) -> Self {
+        let straight = NormalParam {
+            value: Normal::center(),
+            default: Normal::center(),
+        };
+
+        Self {
+            params: [
+                None,
+                Some(attack),
+                None,
+                Some(decay),
+                Some(sustain),
+                Some(release),
+            ],
+            curves: [straight; 6],
+            drag: Drag::None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_normal: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Sets the [`NormalParam`] of the delay time. Set this to `None` to
+    /// remove the delay stage.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub fn set_delay(&mut self, delay: Option<NormalParam>) {
+        self.params[Stage::Delay.index()] = delay;
+        self.drag = Drag::None;
+    }
+
+    /// Sets the [`NormalParam`] of the hold time. Set this to `None` to
+    /// remove the hold stage.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub fn set_hold(&mut self, hold: Option<NormalParam>) {
+        self.params[Stage::Hold.index()] = hold;
+        self.drag = Drag::None;
+    }
+
+    /// Returns `true` if the envelope has the given [`Stage`].
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn has_stage(&self, stage: Stage) -> bool {
+        self.params[stage.index()].is_some()
+    }
+
+    /// Get the normalized value of a [`Stage`]. This is the level of the
+    /// sustain stage and the time of every other stage.
+    ///
+    /// Returns `None` if the envelope does not have the stage.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn normal(&self, stage: Stage) -> Option<Normal> {
+        self.params[stage.index()].map(|param| param.value)
+    }
+
+    /// Set the normalized value of a [`Stage`]. This does nothing if the
+    /// envelope does not have the stage.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn set_normal(&mut self, stage: Stage, normal: Normal) {
+        if let Some(param) = &mut self.params[stage.index()] {
+            param.value = normal;
+        }
+    }
+
+    /// Set the normalized default value of a [`Stage`]. This does nothing if
+    /// the envelope does not have the stage.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn set_default(&mut self, stage: Stage, normal: Normal) {
+        if let Some(param) = &mut self.params[stage.index()] {
+            param.default = normal;
+        }
+    }
+
+    /// Get the curve of a [`Stage`].
+    ///
+    /// Returns `None` if the curve of the stage cannot be adjusted.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn curve(&self, stage: Stage) -> Option<Normal> {
+        if stage.has_curve() {
+            Some(self.curves[stage.index()].value)
+        } else {
+            None
+        }
+    }
+
+    /// Set the curve of a [`Stage`]. This does nothing if the curve of the
+    /// stage cannot be adjusted.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn set_curve(&mut self, stage: Stage, curve: Normal) {
+        if stage.has_curve() {
+            self.curves[stage.index()].value = curve;
+        }
+    }
+
+    /// Set the default curve of a [`Stage`]. This does nothing if the curve
+    /// of the stage cannot be adjusted. The default curve is a straight line.
+    ///
+    /// [`Stage`]: enum.Stage.html
+    pub fn set_curve_default(&mut self, stage: Stage, curve: Normal) {
+        if stage.has_curve() {
+            self.curves[stage.index()].default = curve;
+        }
+    }
+
+    /// Is a handle or curve of the [`ADSREnvelope`] currently being dragged?
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag != Drag::None
+    }
+
+    /// Returns the width (in pixels) of the slot of each time stage for the
+    /// given bounds.
+    fn slot_width(&self, bounds: &Rectangle) -> f32 {
+        // Every time stage plus the sustain plateau.
+        let slots = Stage::ALL
+            .iter()
+            .filter(|stage| self.has_stage(**stage))
+            .count();
+
+        bounds.width / slots as f32
+    }
+
+    fn geometry(&self, bounds: &Rectangle) -> (Vec<Handle>, Vec<Segment>) {
+        let slot_width = self.slot_width(bounds);
+
+        let bottom = bounds.y + bounds.height;
+        let top = bounds.y;
+        let sustain_y = match self.normal(Stage::Sustain) {
+            Some(sustain) => bottom - (sustain.as_f32() * bounds.height),
+            None => bottom,
+        };
+
+        let mut handles: Vec<Handle> = Vec::with_capacity(6);
+        let mut segments: Vec<Segment> = Vec::with_capacity(6);
+
+        let mut position = Point::new(bounds.x, bottom);
+
+        for stage in Stage::ALL.iter() {
+            let stage = *stage;
+
+            let normal = match self.normal(stage) {
+                Some(normal) => normal.as_f32(),
+                None => continue,
+            };
+
+            let (end, handle) = match stage {
+                Stage::Delay => {
+                    let end =
+                        Point::new(position.x + (normal * slot_width), bottom);
+                    (end, end)
+                }
+                Stage::Attack | Stage::Hold => {
+                    let end =
+                        Point::new(position.x + (normal * slot_width), top);
+                    (end, end)
+                }
+                Stage::Decay => {
+                    let end = Point::new(
+                        position.x + (normal * slot_width),
+                        sustain_y,
+                    );
+                    (end, end)
+                }
+                Stage::Sustain => {
+                    let end = Point::new(position.x + slot_width, sustain_y);
+                    (end, Point::new(position.x + (slot_width / 2.0), end.y))
+                }
+                Stage::Release => {
+                    let end =
+                        Point::new(position.x + (normal * slot_width), bottom);
+                    (end, end)
+                }
+            };
+
+            handles.push(Handle {
+                stage,
+                position: handle,
+            });
+
+            segments.push(Segment {
+                stage,
+                start: position,
+                end,
+                curve: self.curves[stage.index()].value,
+            });
+
+            position = end;
+        }
+
+        (handles, segments)
+    }
+
+    fn handle_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_position: Point,
+        radius: f32,
+    ) -> Option<Stage> {
+        let (handles, _) = self.geometry(bounds);
+
+        let radius_squared = radius * radius;
+
+        let mut nearest: Option<(Stage, f32)> = None;
+
+        for handle in handles.iter() {
+            let dx = cursor_position.x - handle.position.x;
+            let dy = cursor_position.y - handle.position.y;
+            let distance_squared = (dx * dx) + (dy * dy);
+
+            if distance_squared <= radius_squared {
+                match nearest {
+                    Some((_, nearest_distance))
+                        if nearest_distance <= distance_squared => {}
+                    _ => nearest = Some((handle.stage, distance_squared)),
+                }
+            }
+        }
+
+        nearest.map(|(stage, _)| stage)
+    }
+
+    fn curve_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_position: Point,
+    ) -> Option<Stage> {
+        let (_, segments) = self.geometry(bounds);
+
+        segments
+            .iter()
+            .find(|segment| {
+                segment.stage.has_curve()
+                    && cursor_position.x >= segment.start.x
+                    && cursor_position.x < segment.end.x
+            })
+            .map(|segment| segment.stage)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ADSREnvelope<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => match self.state.drag {
+                    Drag::Handle(stage) => {
+                        // The sustain level moves vertically, and every
+                        // other stage moves horizontally within its slot.
+                        let mut normal_delta = if stage == Stage::Sustain {
+                            if bounds.height <= 0.0 {
+                                return event::Status::Ignored;
+                            }
+
+                            (self.state.prev_drag_y - cursor_position.y)
+                                / bounds.height
+                        } else {
+                            let slot_width = self.state.slot_width(&bounds);
+                            if slot_width <= 0.0 {
+                                return event::Status::Ignored;
+                            }
+
+                            (cursor_position.x - self.state.prev_drag_x)
+                                / slot_width
+                        };
+
+                        if self.is_modifier_pressed() {
+                            normal_delta *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_x = cursor_position.x;
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        let normal = (self.state.continuous_normal
+                            + normal_delta)
+                            .max(0.0)
+                            .min(1.0);
+
+                        self.state.continuous_normal = normal;
+                        self.state.set_normal(stage, normal.into());
+
+                        messages.push((self.on_change)(stage, normal.into()));
+
+                        return event::Status::Captured;
+                    }
+                    Drag::Curve(stage) => {
+                        let mut normal_delta = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            * self.scalar;
+
+                        if self.is_modifier_pressed() {
+                            normal_delta *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        let normal = (self.state.continuous_normal
+                            - normal_delta)
+                            .max(0.0)
+                            .min(1.0);
+
+                        self.state.continuous_normal = normal;
+                        self.state.curves[stage.index()].value = normal.into();
+
+                        messages
+                            .push((self.on_curve_change)(stage, normal.into()));
+
+                        return event::Status::Captured;
+                    }
+                    Drag::None => {}
+                },
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        let hit_handle = self.state.handle_at(
+                            &bounds,
+                            cursor_position,
+                            self.handle_radius,
+                        );
+                        let hit_curve = if hit_handle.is_none() {
+                            self.state.curve_at(&bounds, cursor_position)
+                        } else {
+                            None
+                        };
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.prev_drag_x = cursor_position.x;
+                                self.state.prev_drag_y = cursor_position.y;
+
+                                if let Some(stage) = hit_handle {
+                                    self.state.drag = Drag::Handle(stage);
+                                    self.state.continuous_normal = self
+                                        .state
+                                        .normal(stage)
+                                        .map(|normal| normal.as_f32())
+                                        .unwrap_or(0.0);
+                                } else if let Some(stage) = hit_curve {
+                                    self.state.drag = Drag::Curve(stage);
+                                    self.state.continuous_normal =
+                                        self.state.curves[stage.index()]
+                                            .value
+                                            .as_f32();
+                                }
+                            }
+                            _ => {
+                                self.state.drag = Drag::None;
+
+                                if let Some(stage) = hit_handle {
+                                    if let Some(param) =
+                                        &mut self.state.params[stage.index()]
+                                    {
+                                        param.value = param.default;
+
+                                        messages.push((self.on_change)(
+                                            stage,
+                                            param.value,
+                                        ));
+                                    }
+                                } else if let Some(stage) = hit_curve {
+                                    let curve =
+                                        &mut self.state.curves[stage.index()];
+                                    curve.value = curve.default;
+
+                                    messages.push((self.on_curve_change)(
+                                        stage,
+                                        curve.value,
+                                    ));
+                                }
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.drag != Drag::None {
+                        self.state.drag = Drag::None;
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let (handles, segments) = self.state.geometry(&bounds);
+
+        let (active_handle, active_curve) = match self.state.drag {
+            Drag::Handle(stage) => (Some(stage), None),
+            Drag::Curve(stage) => (None, Some(stage)),
+            Drag::None => {
+                if bounds.contains(cursor_position) {
+                    match self.state.handle_at(
+                        &bounds,
+                        cursor_position,
+                        self.handle_radius,
+                    ) {
+                        Some(stage) => (Some(stage), None),
+                        None => (
+                            None,
+                            self.state.curve_at(&bounds, cursor_position),
+                        ),
+                    }
+                } else {
+                    (None, None)
+                }
+            }
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &handles,
+            &segments,
+            active_handle,
+            active_curve,
+            self.state.is_dragging(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`ADSREnvelope`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`ADSREnvelope`] in your user interface.
+///
+/// [`ADSREnvelope`]: struct.ADSREnvelope.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`ADSREnvelope`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ADSREnvelope`]
+    ///   * the current cursor position
+    ///   * the handle of each stage
+    ///   * the line of each stage, in order
+    ///   * the stage whose handle is hovered or being dragged
+    ///   * the stage whose curve is hovered or being dragged
+    ///   * whether a handle or curve is currently being dragged
+    ///   * the style of the [`ADSREnvelope`]
+    ///
+    /// [`ADSREnvelope`]: struct.ADSREnvelope.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        handles: &[Handle],
+        segments: &[Segment],
+        active_handle: Option<Stage>,
+        active_curve: Option<Stage>,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ADSREnvelope<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        adsr_envelope: ADSREnvelope<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(adsr_envelope)
+    }
+}
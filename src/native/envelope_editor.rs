@@ -0,0 +1,852 @@
+//! Display an editor for an envelope made of points connected by curved
+//! segments. Points can be dragged, added, and removed, and the curvature of
+//! each segment can be adjusted.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{curve, Normal};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_HANDLE_RADIUS: f32 = 6.0;
+static DEFAULT_SCALAR: f32 = 0.00385;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// A point in an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EnvelopePoint {
+    /// The position of the point along the `x` axis, where `0.0` is the left
+    /// edge and `1.0` is the right edge.
+    pub time: Normal,
+    /// The position of the point along the `y` axis, where `0.0` is the
+    /// bottom edge and `1.0` is the top edge.
+    pub value: Normal,
+    /// The curvature of the segment from this point to the next point. A
+    /// value of `0.5` represents a straight line, `0.0` is curved downward
+    /// all the way, and `1.0` is curved upward all the way.
+    ///
+    /// This is ignored for the last point.
+    pub curve: Normal,
+}
+
+impl EnvelopePoint {
+    /// Creates a new [`EnvelopePoint`] whose segment to the next point is a
+    /// straight line.
+    ///
+    /// [`EnvelopePoint`]: struct.EnvelopePoint.html
+    pub fn new(time: Normal, value: Normal) -> Self {
+        Self {
+            time,
+            value,
+            curve: Normal::center(),
+        }
+    }
+}
+
+/// Returns the value of the envelope made of `points` at the given `time`.
+///
+/// The points must be sorted by time. The value of the first point is
+/// returned before the first point, and the value of the last point is
+/// returned after the last point. `Normal::min()` is returned if there are no
+/// points.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::envelope_editor::{value_at, EnvelopePoint};
+/// use iced_audio::Normal;
+///
+/// let points = vec![
+///     EnvelopePoint::new(Normal::min(), Normal::min()),
+///     EnvelopePoint::new(Normal::max(), Normal::max()),
+/// ];
+///
+/// let value = value_at(&points, Normal::new(0.25));
+/// assert!((value.as_f32() - 0.25).abs() < 0.0001);
+/// ```
+pub fn value_at(points: &[EnvelopePoint], time: Normal) -> Normal {
+    let time = time.as_f32();
+
+    let first = match points.first() {
+        Some(first) => first,
+        None => return Normal::min(),
+    };
+
+    if time <= first.time.as_f32() {
+        return first.value;
+    }
+
+    for pair in points.windows(2) {
+        let start = pair[0];
+        let end = pair[1];
+
+        if time <= end.time.as_f32() {
+            return curve::value_at(
+                start.time.as_f32(),
+                start.value.as_f32(),
+                end.time.as_f32(),
+                end.value.as_f32(),
+                start.curve,
+                time,
+            )
+            .into();
+        }
+    }
+
+    points[points.len() - 1].value
+}
+
+/// An editor for an envelope made of points connected by curved segments.
+/// It is usually used to represent automation, envelopes, or LFO shapes.
+///
+/// * Drag a point to move it.
+/// * Double-click on an empty area to add a point.
+/// * Right-click or double-click on a point to remove it.
+/// * Drag up and down on a segment to adjust its curvature.
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[allow(missing_debug_implementations)]
+pub struct EnvelopeEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Vec<EnvelopePoint>) -> Message>,
+    scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    time_grid: Option<u16>,
+    handle_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    EnvelopeEditor<'a, Message, Renderer>
+{
+    /// Creates a new [`EnvelopeEditor`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`EnvelopeEditor`]
+    ///   * a function that will be called with the full list of points
+    /// whenever the envelope is changed
+    ///
+    /// Adding or removing a point moves the loop and sustain markers with
+    /// the points they are on, or removes them along with their point. The
+    /// message only holds the points, so read the markers back with
+    /// [`State::loop_range`] and [`State::sustain`] when it arrives.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    /// [`State::loop_range`]: struct.State.html#method.loop_range
+    /// [`State::sustain`]: struct.State.html#method.sustain
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Vec<EnvelopePoint>) -> Message,
+    {
+        EnvelopeEditor {
+            state,
+            on_change: Box::new(on_change),
+            scalar: DEFAULT_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            time_grid: None,
+            handle_radius: DEFAULT_HANDLE_RADIUS,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`EnvelopeEditor`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`EnvelopeEditor`].
+    /// The default height is `Length::from(Length::Units(100))`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`EnvelopeEditor`].
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Divides the time axis of the [`EnvelopeEditor`] into the given number
+    /// of equal divisions. The grid is displayed, and the time of points that
+    /// are added or dragged snaps to the nearest grid line.
+    ///
+    /// Set this to `0` to disable the grid. The grid is disabled by default.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn time_grid(mut self, divisions: u16) -> Self {
+        self.time_grid = if divisions == 0 {
+            None
+        } else {
+            Some(divisions)
+        };
+        self
+    }
+
+    /// Sets the radius (in pixels) around the center of a point in which the
+    /// point can be grabbed by the mouse.
+    ///
+    /// The default value is `6.0`
+    pub fn handle_radius(mut self, radius: f32) -> Self {
+        self.handle_radius = radius;
+        self
+    }
+
+    /// Sets how much the [`Normal`] curvature of a segment will change per
+    /// `y` pixel movement of the mouse.
+    ///
+    /// The default value is `0.00385`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets the modifier keys of the [`EnvelopeEditor`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a point or a segment while
+    /// holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause points and
+    /// segments to move half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn is_modifier_pressed(&self) -> bool {
+        keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.modifier_keys
+    }
+
+    fn snap_time(&self, time: f32) -> f32 {
+        match self.time_grid {
+            Some(divisions) => {
+                let divisions = f32::from(divisions);
+                (time * divisions).round() / divisions
+            }
+            None => time,
+        }
+    }
+
+    fn push_change(&self, messages: &mut Vec<Message>) {
+        messages.push((self.on_change)(self.state.points.clone()));
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Drag {
+    None,
+    Point(usize),
+    Segment(usize),
+}
+
+/// The local state of an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+#[derive(Debug, Clone)]
+pub struct State {
+    points: Vec<EnvelopePoint>,
+    loop_range: Option<(usize, usize)>,
+    sustain: Option<usize>,
+    drag: Drag,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_time: f32,
+    continuous_value: f32,
+    continuous_curve: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(vec![
+            EnvelopePoint::new(Normal::min(), Normal::min()),
+            EnvelopePoint::new(Normal::max(), Normal::max()),
+        ])
+    }
+}
+
+impl State {
+    /// Creates a new [`EnvelopeEditor`] state.
+    ///
+    /// It expects:
+    /// * the initial points of the envelope. They will be sorted by time.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn new(points: Vec<EnvelopePoint>) -> Self {
+        let mut state = Self {
+            points: Vec::new(),
+            loop_range: None,
+            sustain: None,
+            drag: Drag::None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_time: 0.0,
+            continuous_value: 0.0,
+            continuous_curve: 0.5,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        };
+
+        state.set_points(points);
+
+        state
+    }
+
+    /// Returns the points of the envelope, sorted by time.
+    pub fn points(&self) -> &[EnvelopePoint] {
+        &self.points
+    }
+
+    /// Sets the points of the envelope. They will be sorted by time.
+    ///
+    /// Loop and sustain markers that point past the end of the new points
+    /// are removed.
+    pub fn set_points(&mut self, mut points: Vec<EnvelopePoint>) {
+        points.sort_by(|a, b| {
+            a.time
+                .as_f32()
+                .partial_cmp(&b.time.as_f32())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        self.points = points;
+        self.drag = Drag::None;
+
+        let len = self.points.len();
+        if let Some((_, end)) = self.loop_range {
+            if end >= len {
+                self.loop_range = None;
+            }
+        }
+        if let Some(sustain) = self.sustain {
+            if sustain >= len {
+                self.sustain = None;
+            }
+        }
+    }
+
+    /// Returns the indexes of the points where the loop starts and ends, if
+    /// there is a loop.
+    ///
+    /// The indexes follow their points when the [`EnvelopeEditor`] adds or
+    /// removes a point.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn loop_range(&self) -> Option<(usize, usize)> {
+        self.loop_range
+    }
+
+    /// Sets the indexes of the points where the loop starts and ends. Set
+    /// this to `None` to remove the loop.
+    ///
+    /// The loop is removed if either index is out of range, or if `start` is
+    /// greater than `end`.
+    pub fn set_loop_range(&mut self, loop_range: Option<(usize, usize)>) {
+        self.loop_range = loop_range
+            .filter(|(start, end)| start <= end && *end < self.points.len());
+    }
+
+    /// Returns the index of the sustain point, if there is one.
+    ///
+    /// The index follows its point when the [`EnvelopeEditor`] adds or
+    /// removes a point.
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn sustain(&self) -> Option<usize> {
+        self.sustain
+    }
+
+    /// Sets the index of the sustain point. Set this to `None` to remove the
+    /// sustain marker.
+    ///
+    /// The sustain marker is removed if the index is out of range.
+    pub fn set_sustain(&mut self, sustain: Option<usize>) {
+        self.sustain = sustain.filter(|index| *index < self.points.len());
+    }
+
+    /// Is a point or segment of the [`EnvelopeEditor`] currently being
+    /// dragged?
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag != Drag::None
+    }
+
+    fn insert_point(&mut self, point: EnvelopePoint) -> usize {
+        let index = self
+            .points
+            .iter()
+            .position(|p| p.time.as_f32() > point.time.as_f32())
+            .unwrap_or(self.points.len());
+
+        self.points.insert(index, point);
+
+        let shift = |i: usize| if i >= index { i + 1 } else { i };
+
+        self.loop_range = self
+            .loop_range
+            .map(|(start, end)| (shift(start), shift(end)));
+        self.sustain = self.sustain.map(shift);
+
+        index
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        let _ = self.points.remove(index);
+
+        let shift = |i: usize| if i > index { i - 1 } else { i };
+
+        self.loop_range = match self.loop_range {
+            Some((start, end)) if start != index && end != index => {
+                Some((shift(start), shift(end)))
+            }
+            _ => None,
+        };
+        self.sustain = match self.sustain {
+            Some(sustain) if sustain != index => Some(shift(sustain)),
+            _ => None,
+        };
+    }
+
+    fn point_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_position: Point,
+        radius: f32,
+    ) -> Option<usize> {
+        let radius_squared = radius * radius;
+
+        let mut nearest: Option<(usize, f32)> = None;
+
+        for (i, point) in self.points.iter().enumerate() {
+            let center = point_position(bounds, point);
+
+            let dx = cursor_position.x - center.x;
+            let dy = cursor_position.y - center.y;
+            let distance_squared = (dx * dx) + (dy * dy);
+
+            if distance_squared <= radius_squared {
+                match nearest {
+                    Some((_, nearest_distance))
+                        if nearest_distance <= distance_squared => {}
+                    _ => nearest = Some((i, distance_squared)),
+                }
+            }
+        }
+
+        nearest.map(|(i, _)| i)
+    }
+
+    fn segment_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if bounds.width <= 0.0 {
+            return None;
+        }
+
+        let time = (cursor_position.x - bounds.x) / bounds.width;
+
+        self.points.windows(2).position(|pair| {
+            time >= pair[0].time.as_f32() && time < pair[1].time.as_f32()
+        })
+    }
+}
+
+fn point_position(bounds: &Rectangle, point: &EnvelopePoint) -> Point {
+    Point::new(
+        bounds.x + (point.time.as_f32() * bounds.width),
+        bounds.y + ((1.0 - point.value.as_f32()) * bounds.height),
+    )
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for EnvelopeEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => match self.state.drag {
+                    Drag::Point(index) => {
+                        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                            return event::Status::Ignored;
+                        }
+
+                        let mut movement_x = (cursor_position.x
+                            - self.state.prev_drag_x)
+                            / bounds.width;
+                        let mut movement_y = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            / bounds.height;
+
+                        if self.is_modifier_pressed() {
+                            movement_x *= self.modifier_scalar;
+                            movement_y *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_x = cursor_position.x;
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        // A point cannot be dragged past its neighbors.
+                        let min_time = if index > 0 {
+                            self.state.points[index - 1].time.as_f32()
+                        } else {
+                            0.0
+                        };
+                        let max_time = match self.state.points.get(index + 1) {
+                            Some(next) => next.time.as_f32(),
+                            None => 1.0,
+                        };
+
+                        let time = (self.state.continuous_time + movement_x)
+                            .max(min_time)
+                            .min(max_time);
+                        let value = (self.state.continuous_value - movement_y)
+                            .max(0.0)
+                            .min(1.0);
+
+                        self.state.continuous_time = time;
+                        self.state.continuous_value = value;
+
+                        let snapped_time =
+                            self.snap_time(time).max(min_time).min(max_time);
+
+                        let point = &mut self.state.points[index];
+                        point.time = snapped_time.into();
+                        point.value = value.into();
+
+                        self.push_change(messages);
+
+                        return event::Status::Captured;
+                    }
+                    Drag::Segment(index) => {
+                        let mut normal_delta = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            * self.scalar;
+
+                        if self.is_modifier_pressed() {
+                            normal_delta *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        let curve = (self.state.continuous_curve
+                            - normal_delta)
+                            .max(0.0)
+                            .min(1.0);
+
+                        self.state.continuous_curve = curve;
+                        self.state.points[index].curve = curve.into();
+
+                        self.push_change(messages);
+
+                        return event::Status::Captured;
+                    }
+                    Drag::None => {}
+                },
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        let hit_point = self.state.point_at(
+                            &bounds,
+                            cursor_position,
+                            self.handle_radius,
+                        );
+
+                        match click.kind() {
+                            // A triple-click is a new press, so that it
+                            // does not remove the point that the
+                            // double-click before it added.
+                            mouse::click::Kind::Single
+                            | mouse::click::Kind::Triple => {
+                                self.state.prev_drag_x = cursor_position.x;
+                                self.state.prev_drag_y = cursor_position.y;
+
+                                if let Some(index) = hit_point {
+                                    let point = self.state.points[index];
+
+                                    self.state.drag = Drag::Point(index);
+                                    self.state.continuous_time =
+                                        point.time.as_f32();
+                                    self.state.continuous_value =
+                                        point.value.as_f32();
+                                } else if let Some(index) = self
+                                    .state
+                                    .segment_at(&bounds, cursor_position)
+                                {
+                                    self.state.drag = Drag::Segment(index);
+                                    self.state.continuous_curve =
+                                        self.state.points[index].curve.as_f32();
+                                }
+                            }
+                            mouse::click::Kind::Double => {
+                                self.state.drag = Drag::None;
+
+                                if let Some(index) = hit_point {
+                                    self.state.remove_point(index);
+                                } else if bounds.width > 0.0
+                                    && bounds.height > 0.0
+                                {
+                                    let time = self.snap_time(
+                                        (cursor_position.x - bounds.x)
+                                            / bounds.width,
+                                    );
+                                    let value = 1.0
+                                        - ((cursor_position.y - bounds.y)
+                                            / bounds.height);
+
+                                    // The new point splits a segment, so
+                                    // both halves keep its curvature.
+                                    let curve = self
+                                        .state
+                                        .segment_at(&bounds, cursor_position)
+                                        .map(|i| self.state.points[i].curve)
+                                        .unwrap_or_else(Normal::center);
+
+                                    let _ = self.state.insert_point(
+                                        EnvelopePoint {
+                                            time: time.into(),
+                                            value: value.into(),
+                                            curve,
+                                        },
+                                    );
+                                }
+
+                                self.push_change(messages);
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    if bounds.contains(cursor_position)
+                        && self.state.drag == Drag::None
+                    {
+                        if let Some(index) = self.state.point_at(
+                            &bounds,
+                            cursor_position,
+                            self.handle_radius,
+                        ) {
+                            self.state.remove_point(index);
+
+                            self.push_change(messages);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.drag != Drag::None {
+                        self.state.drag = Drag::None;
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let (active_point, active_segment) = match self.state.drag {
+            Drag::Point(index) => (Some(index), None),
+            Drag::Segment(index) => (None, Some(index)),
+            Drag::None => {
+                if bounds.contains(cursor_position) {
+                    match self.state.point_at(
+                        &bounds,
+                        cursor_position,
+                        self.handle_radius,
+                    ) {
+                        Some(index) => (Some(index), None),
+                        None => (
+                            None,
+                            self.state.segment_at(&bounds, cursor_position),
+                        ),
+                    }
+                } else {
+                    (None, None)
+                }
+            }
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.points,
+            active_point,
+            active_segment,
+            self.state.is_dragging(),
+            self.state.loop_range,
+            self.state.sustain,
+            self.time_grid,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`EnvelopeEditor`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`EnvelopeEditor`] in your user interface.
+///
+/// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`EnvelopeEditor`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`EnvelopeEditor`]
+    ///   * the current cursor position
+    ///   * the points of the envelope, sorted by time
+    ///   * the index of the point that is hovered or being dragged
+    ///   * the index of the segment that is hovered or being dragged
+    ///   * whether a point or segment is currently being dragged
+    ///   * the indexes of the loop start and end points
+    ///   * the index of the sustain point
+    ///   * the number of divisions of the time grid
+    ///   * the style of the [`EnvelopeEditor`]
+    ///
+    /// [`EnvelopeEditor`]: struct.EnvelopeEditor.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[EnvelopePoint],
+        active_point: Option<usize>,
+        active_segment: Option<usize>,
+        is_dragging: bool,
+        loop_range: Option<(usize, usize)>,
+        sustain: Option<usize>,
+        time_grid: Option<u16>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<EnvelopeEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        envelope_editor: EnvelopeEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(envelope_editor)
+    }
+}
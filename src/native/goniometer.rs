@@ -0,0 +1,492 @@
+//! Display a goniometer (stereo vectorscope) that plots the mid / side
+//! image of a stereo signal.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+static DEFAULT_SIZE: u16 = 160;
+static DEFAULT_SAMPLE_RATE: f32 = 44_100.0;
+static DEFAULT_PERSISTENCE: f32 = 0.1;
+static DEFAULT_DECAY: f32 = 1.0;
+static DEFAULT_MAX_POINTS: usize = 2048;
+static DEFAULT_AUTO_GAIN_RELEASE: f32 = 1.0;
+static MIN_AUTO_GAIN_PEAK: f32 = 0.001;
+
+/// How the points of a [`Goniometer`] are drawn.
+///
+/// [`Goniometer`]: struct.Goniometer.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mode {
+    /// Each point is drawn as a dot, which gives a fading point cloud.
+    Dots,
+    /// Consecutive points are connected by lines, which gives a Lissajous
+    /// figure.
+    Lines,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Dots
+    }
+}
+
+/// A point plotted by a [`Goniometer`].
+///
+/// [`Goniometer`]: struct.Goniometer.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GonioPoint {
+    /// The side component, from `-1.0` (left) to `1.0` (right)
+    pub x: f32,
+    /// The mid component, from `-1.0` (bottom) to `1.0` (top)
+    pub y: f32,
+    /// How much of the color of the point is left after fading, from `0.0`
+    /// to `1.0`
+    pub opacity: f32,
+}
+
+/// A goniometer (stereo vectorscope).
+///
+/// Mono signals are drawn as a vertical line, signals that are only in the
+/// left or right channel are drawn along the diagonals, and signals with
+/// inverted phase are drawn as a horizontal line.
+#[allow(missing_debug_implementations)]
+pub struct Goniometer<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    mode: Mode,
+    polar: bool,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> Goniometer<'a, Renderer> {
+    /// Creates a new [`Goniometer`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Goniometer`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn new(state: &'a State) -> Self {
+        Goniometer {
+            state,
+            mode: Mode::default(),
+            polar: false,
+            width: Length::from(Length::Units(DEFAULT_SIZE)),
+            height: Length::from(Length::Units(DEFAULT_SIZE)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Goniometer`].
+    /// The default width is `Length::from(Length::Units(160))`.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Goniometer`].
+    /// The default height is `Length::from(Length::Units(160))`.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Mode`] of the [`Goniometer`].
+    /// The default is `Mode::Dots`.
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the [`Goniometer`] is in polar sample mode.
+    ///
+    /// In polar sample mode, points below the center are mirrored to the
+    /// top half, so the angle of a point shows its stereo position and its
+    /// distance from the center shows its amplitude. Only the top half of
+    /// the circle is drawn.
+    ///
+    /// This is `false` by default.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn polar(mut self, polar: bool) -> Self {
+        self.polar = polar;
+        self
+    }
+
+    /// Sets the style of the [`Goniometer`].
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct StoredPoint {
+    x: f32,
+    y: f32,
+    /// The index of the sample this point was taken from.
+    index: u64,
+}
+
+/// The local state of a [`Goniometer`].
+///
+/// [`Goniometer`]: struct.Goniometer.html
+#[derive(Debug, Clone)]
+pub struct State {
+    sample_rate: f32,
+    persistence: f32,
+    decay: f32,
+    max_points: usize,
+    gain: f32,
+    auto_gain: bool,
+    auto_gain_release: f32,
+    auto_gain_peak: f32,
+    points: VecDeque<StoredPoint>,
+    samples_pushed: u64,
+    last_block_start: u64,
+    decimation: u64,
+}
+
+impl State {
+    /// Creates a new [`Goniometer`] state.
+    ///
+    /// It expects:
+    /// * the sample rate of the incoming audio in Hz
+    ///
+    /// By default, points fade out over `0.1` seconds, at most `2048`
+    /// points are kept, and automatic gain is off.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `sample_rate` is not positive.
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    pub fn new(sample_rate: f32) -> Self {
+        assert!(sample_rate > 0.0, "sample_rate must be positive");
+
+        let mut state = Self {
+            sample_rate,
+            persistence: DEFAULT_PERSISTENCE,
+            decay: DEFAULT_DECAY,
+            max_points: DEFAULT_MAX_POINTS,
+            gain: 1.0,
+            auto_gain: false,
+            auto_gain_release: DEFAULT_AUTO_GAIN_RELEASE,
+            auto_gain_peak: MIN_AUTO_GAIN_PEAK,
+            points: VecDeque::new(),
+            samples_pushed: 0,
+            last_block_start: 0,
+            decimation: 1,
+        };
+
+        state.update_decimation();
+
+        state
+    }
+
+    /// Returns the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Returns the time in seconds that it takes for a point to fade out.
+    pub fn persistence(&self) -> f32 {
+        self.persistence
+    }
+
+    /// Sets the time in seconds that it takes for a point to fade out.
+    ///
+    /// The points of the most recently pushed block are always kept, so a
+    /// persistence of `0.0` shows only the latest block.
+    pub fn set_persistence(&mut self, seconds: f32) {
+        self.persistence = seconds.max(0.0);
+        self.update_decimation();
+    }
+
+    /// Sets the shape of the fade. A `decay` of `1.0` fades linearly, larger
+    /// values fade out faster at first, and smaller values hold the points
+    /// for longer before fading out quickly.
+    ///
+    /// The default `decay` is `1.0`.
+    pub fn set_decay(&mut self, decay: f32) {
+        self.decay = decay.max(0.01);
+    }
+
+    /// Sets the maximum number of points that are kept. If more samples than
+    /// this arrive during the persistence time, only every `n`th sample is
+    /// plotted.
+    ///
+    /// The default is `2048`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max_points` is `0`.
+    pub fn set_max_points(&mut self, max_points: usize) {
+        assert!(max_points > 0, "max_points must be greater than 0");
+
+        self.max_points = max_points;
+        self.update_decimation();
+    }
+
+    /// Returns the current gain. When automatic gain is on, this is the gain
+    /// that was chosen automatically.
+    pub fn gain(&self) -> f32 {
+        if self.auto_gain {
+            1.0 / self.auto_gain_peak
+        } else {
+            self.gain
+        }
+    }
+
+    /// Sets the gain that is applied to the signal before it is plotted.
+    /// This is ignored when automatic gain is on.
+    ///
+    /// The default gain is `1.0`.
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain.max(0.0);
+    }
+
+    /// Sets whether the gain is chosen automatically, so that the loudest
+    /// recent point reaches the edge of the circle.
+    ///
+    /// * `release` - the time in seconds that it takes for the gain to rise
+    /// by `60 dB` after the signal gets quieter
+    pub fn set_auto_gain(&mut self, auto_gain: bool, release: f32) {
+        self.auto_gain = auto_gain;
+        self.auto_gain_release = release.max(0.0);
+    }
+
+    /// Pushes a block of stereo samples. Only the shorter of the two
+    /// channels is used.
+    pub fn push_samples(&mut self, left: &[f32], right: &[f32]) {
+        let len = left.len().min(right.len());
+
+        if len == 0 {
+            return;
+        }
+
+        let mut block_peak: f32 = 0.0;
+
+        for (i, (l, r)) in left.iter().zip(right.iter()).enumerate() {
+            let index = self.samples_pushed + i as u64;
+
+            // Rotate by 45 degrees, so that mono is vertical.
+            let x = (r - l) * 0.5;
+            let y = (l + r) * 0.5;
+
+            block_peak = block_peak.max(((x * x) + (y * y)).sqrt());
+
+            if index % self.decimation == 0 {
+                self.points.push_back(StoredPoint { x, y, index });
+            }
+        }
+
+        self.last_block_start = self.samples_pushed;
+        self.samples_pushed += len as u64;
+
+        if self.auto_gain {
+            let seconds = len as f32 / self.sample_rate;
+            let release = if self.auto_gain_release > 0.0 {
+                // Falls by 60 dB over the release time.
+                0.001_f32.powf(seconds / self.auto_gain_release)
+            } else {
+                0.0
+            };
+
+            self.auto_gain_peak = block_peak
+                .max(self.auto_gain_peak * release)
+                .max(MIN_AUTO_GAIN_PEAK);
+        }
+
+        self.remove_old_points();
+    }
+
+    /// Removes all points.
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.auto_gain_peak = MIN_AUTO_GAIN_PEAK;
+    }
+
+    /// Returns the points to plot, from oldest to newest, with the gain
+    /// applied.
+    ///
+    /// * `polar` - whether to mirror the points below the center to the top
+    /// half
+    pub fn points(&self, polar: bool) -> Vec<GonioPoint> {
+        let gain = self.gain();
+        let persistence = self.persistence_samples();
+
+        self.points
+            .iter()
+            .map(|point| {
+                let age = (self.samples_pushed - point.index) as f32;
+                let opacity = if persistence > 0.0 {
+                    (1.0 - (age / persistence)).max(0.0).powf(self.decay)
+                } else {
+                    1.0
+                };
+
+                let (x, y) = if polar && point.y < 0.0 {
+                    (-point.x, -point.y)
+                } else {
+                    (point.x, point.y)
+                };
+
+                GonioPoint {
+                    x: (x * gain).max(-1.0).min(1.0),
+                    y: (y * gain).max(-1.0).min(1.0),
+                    opacity,
+                }
+            })
+            .collect()
+    }
+
+    fn persistence_samples(&self) -> f32 {
+        self.persistence * self.sample_rate
+    }
+
+    fn update_decimation(&mut self) {
+        let persistence = self.persistence_samples().ceil() as u64;
+
+        self.decimation = ((persistence + self.max_points as u64 - 1)
+            / self.max_points as u64)
+            .max(1);
+
+        self.remove_old_points();
+    }
+
+    fn remove_old_points(&mut self) {
+        // The most recent block is kept even if it is longer than the
+        // persistence.
+        let max_age = (self.persistence_samples().ceil() as u64)
+            .max(self.samples_pushed - self.last_block_start);
+
+        while let Some(point) = self.points.front() {
+            if self.samples_pushed - point.index > max_age
+                || self.points.len() > self.max_points
+            {
+                let _ = self.points.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_RATE)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Goniometer<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.points(self.polar),
+            self.mode,
+            self.polar,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Goniometer`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Goniometer`] in your user interface.
+///
+/// [`Goniometer`]: struct.Goniometer.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Goniometer`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Goniometer`]
+    ///   * the current cursor position
+    ///   * the points to plot, from oldest to newest
+    ///   * the [`Mode`] of the [`Goniometer`]
+    ///   * whether the [`Goniometer`] is in polar sample mode
+    ///   * the style of the [`Goniometer`]
+    ///
+    /// [`Goniometer`]: struct.Goniometer.html
+    /// [`Mode`]: enum.Mode.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        points: &[GonioPoint],
+        mode: Mode,
+        polar: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Goniometer<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        goniometer: Goniometer<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(goniometer)
+    }
+}
@@ -0,0 +1,709 @@
+//! Display an interactive horizontal slider with two or more handles on one
+//! rail, where each handle controls a [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+use crate::native::{text_marks, tick_marks};
+
+static DEFAULT_HEIGHT: u16 = 14;
+static DEFAULT_SCALAR: f32 = 0.9575;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_GRAB_WIDTH: f32 = 10.0;
+
+/// A horizontal slider GUI widget with two or more handles on one rail,
+/// where each handle controls its own [`NormalParam`]. It is usually used
+/// for key ranges, velocity ranges, or the start and end of a
+/// [`ModulationRange`].
+///
+/// * Drag a handle to move it.
+/// * Drag the region between two handles to move both of them together.
+/// * Double-click on a handle (or region) to reset it to its default value.
+///
+/// an [`HRangeSlider`] will try to fill the horizontal space of its container.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+/// [`HRangeSlider`]: struct.HRangeSlider.html
+#[allow(missing_debug_implementations)]
+pub struct HRangeSlider<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Normal) -> Message>,
+    scalar: f32,
+    wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    allow_crossing: bool,
+    grab_width: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    HRangeSlider<'a, Message, Renderer>
+{
+    /// Creates a new [`HRangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`HRangeSlider`]
+    ///   * a function that will be called with the index of the handle and
+    /// its new value when a handle is dragged.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> Message,
+    {
+        HRangeSlider {
+            state,
+            on_change: Box::new(on_change),
+            scalar: DEFAULT_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            allow_crossing: false,
+            grab_width: DEFAULT_GRAB_WIDTH,
+            width: Length::Fill,
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+            tick_marks: None,
+            text_marks: None,
+        }
+    }
+
+    /// Sets the width of the [`HRangeSlider`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`HRangeSlider`].
+    ///
+    /// The default height is `Length::Units(14)`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`HRangeSlider`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the slider per pixel.
+    ///
+    /// For example, a scalar of `0.5` will cause the slider to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.9575`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`HRangeSlider`] per line scrolled
+    /// by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the parameter.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the slider while holding down
+    /// the modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the slider to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets whether the handles are allowed to cross each other.
+    ///
+    /// When this is `false`, each handle is kept between its neighbors, so the
+    /// handles must be in ascending order. Handles that are stacked on the
+    /// same value are pulled apart by dragging: the highest one follows a
+    /// drag up, and the lowest one follows a drag down.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn allow_crossing(mut self, allow_crossing: bool) -> Self {
+        self.allow_crossing = allow_crossing;
+        self
+    }
+
+    /// Sets the width (in pixels) around the center of a handle in which the
+    /// handle is grabbed by the mouse. Clicking outside of this width between
+    /// two handles grabs the region between them.
+    ///
+    /// The default value is `10.0`.
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn grab_width(mut self, grab_width: f32) -> Self {
+        self.grab_width = grab_width;
+        self
+    }
+
+    /// Sets the tick marks to display. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/h_range_slider/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. Note your [`StyleSheet`] must
+    /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/h_range_slider/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    fn move_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal_delta: f32,
+    ) {
+        if keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.modifier_keys
+        {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        self.state.move_by(self.allow_crossing, normal_delta);
+
+        for i in self.state.drag.handles() {
+            messages.push((self.on_change)(i, self.state.params[i].value));
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Drag {
+    None,
+    Handle(usize),
+    Region(usize),
+    /// Handles from the first to the last index are stacked on the same
+    /// value. Which one is moved is picked from the direction of the first
+    /// movement.
+    Stacked(usize, usize),
+}
+
+impl Drag {
+    /// The indexes of the handles that are moved by this drag.
+    fn handles(&self) -> std::ops::Range<usize> {
+        match self {
+            Drag::None => 0..0,
+            Drag::Handle(i) => *i..(*i + 1),
+            Drag::Region(i) => *i..(*i + 2),
+            Drag::Stacked(first, last) => *first..(*last + 1),
+        }
+    }
+}
+
+/// The local state of an [`HRangeSlider`].
+///
+/// [`HRangeSlider`]: struct.HRangeSlider.html
+#[derive(Debug, Clone)]
+pub struct State {
+    params: Vec<NormalParam>,
+    drag: Drag,
+    prev_drag_x: f32,
+    continuous_normals: Vec<f32>,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
+    text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+}
+
+impl State {
+    /// Creates a new [`HRangeSlider`] state.
+    ///
+    /// It expects:
+    /// * a [`NormalParam`] for each handle, in ascending order from left to
+    /// right
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two params.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn new(params: Vec<NormalParam>) -> Self {
+        assert!(params.len() >= 2);
+
+        let continuous_normals =
+            params.iter().map(|param| param.value.as_f32()).collect();
+
+        Self {
+            params,
+            drag: Drag::None,
+            prev_drag_x: 0.0,
+            continuous_normals,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+            tick_marks_cache: Default::default(),
+            text_marks_cache: Default::default(),
+        }
+    }
+
+    /// Returns the number of handles of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn num_handles(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Set the normalized value of a handle of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn set_normal(&mut self, index: usize, normal: Normal) {
+        self.params[index].value = normal;
+        self.continuous_normals[index] = normal.into();
+    }
+
+    /// Get the normalized value of a handle of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn normal(&self, index: usize) -> Normal {
+        self.params[index].value
+    }
+
+    /// Set the normalized default value of a handle of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn set_default(&mut self, index: usize, normal: Normal) {
+        self.params[index].default = normal;
+    }
+
+    /// Get the normalized default value of a handle of the [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn default(&self, index: usize) -> Normal {
+        self.params[index].default
+    }
+
+    /// Is the [`HRangeSlider`] currently in the dragging state?
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag != Drag::None
+    }
+
+    fn grab(&self, normal: f32, grab_normal: f32) -> Drag {
+        let mut nearest = 0;
+        let mut nearest_distance = std::f32::MAX;
+
+        for (i, param) in self.params.iter().enumerate() {
+            let distance = (param.value.as_f32() - normal).abs();
+            if distance < nearest_distance {
+                nearest = i;
+                nearest_distance = distance;
+            }
+        }
+
+        if nearest_distance <= grab_normal {
+            let value = self.params[nearest].value.as_f32();
+            let last = self
+                .params
+                .iter()
+                .rposition(|param| param.value.as_f32() == value)
+                .unwrap_or(nearest);
+
+            return if last > nearest {
+                Drag::Stacked(nearest, last)
+            } else {
+                Drag::Handle(nearest)
+            };
+        }
+
+        let region = self.params.windows(2).position(|pair| {
+            let a = pair[0].value.as_f32();
+            let b = pair[1].value.as_f32();
+            normal > a.min(b) && normal < a.max(b)
+        });
+
+        match region {
+            Some(i) => Drag::Region(i),
+            None => Drag::Handle(nearest),
+        }
+    }
+
+    fn move_by(&mut self, allow_crossing: bool, normal_delta: f32) {
+        let len = self.params.len();
+
+        if let Drag::Stacked(first, last) = self.drag {
+            if normal_delta == 0.0 {
+                return;
+            }
+
+            // A negative delta moves the value up.
+            self.drag =
+                Drag::Handle(if normal_delta < 0.0 { last } else { first });
+        }
+
+        match self.drag {
+            Drag::Handle(i) => {
+                let (min, max) = if allow_crossing {
+                    (0.0, 1.0)
+                } else {
+                    (
+                        if i > 0 {
+                            self.params[i - 1].value.as_f32()
+                        } else {
+                            0.0
+                        },
+                        if i + 1 < len {
+                            self.params[i + 1].value.as_f32()
+                        } else {
+                            1.0
+                        },
+                    )
+                };
+
+                let normal = (self.continuous_normals[i] - normal_delta)
+                    .max(min)
+                    .min(max);
+
+                self.continuous_normals[i] = normal;
+                self.params[i].value = normal.into();
+            }
+            Drag::Region(i) => {
+                let (min, max) = if allow_crossing || i == 0 {
+                    (0.0, 1.0)
+                } else {
+                    (self.params[i - 1].value.as_f32(), 1.0)
+                };
+                let max = if !allow_crossing && i + 2 < len {
+                    self.params[i + 2].value.as_f32()
+                } else {
+                    max
+                };
+
+                let a = self.continuous_normals[i];
+                let b = self.continuous_normals[i + 1];
+
+                // Keep the distance between the two handles while the
+                // region is pushed against an edge.
+                let delta =
+                    (-normal_delta).max(min - a.min(b)).min(max - a.max(b));
+
+                self.continuous_normals[i] = a + delta;
+                self.continuous_normals[i + 1] = b + delta;
+                self.params[i].value = (a + delta).into();
+                self.params[i + 1].value = (b + delta).into();
+            }
+            Drag::None | Drag::Stacked(_, _) => {}
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for HRangeSlider<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if self.state.is_dragging() {
+                        let bounds_width = bounds.width;
+
+                        if bounds_width > 0.0 {
+                            let normal_delta = (cursor_position.x
+                                - self.state.prev_drag_x)
+                                / bounds_width
+                                * -self.scalar;
+
+                            self.state.prev_drag_x = cursor_position.x;
+
+                            self.move_virtual_slider(messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if bounds.contains(cursor_position) && bounds.width > 0.0 {
+                        let lines = match delta {
+                            iced_native::mouse::ScrollDelta::Lines {
+                                y,
+                                ..
+                            } => y,
+                            iced_native::mouse::ScrollDelta::Pixels {
+                                y,
+                                ..
+                            } => {
+                                if y > 0.0 {
+                                    1.0
+                                } else if y < 0.0 {
+                                    -1.0
+                                } else {
+                                    0.0
+                                }
+                            }
+                        };
+
+                        if lines != 0.0 {
+                            let normal_delta = -lines * self.wheel_scalar;
+
+                            let prev_drag = self.state.drag;
+
+                            self.state.drag = self.state.grab(
+                                (cursor_position.x - bounds.x) / bounds.width,
+                                self.grab_width / 2.0 / bounds.width,
+                            );
+
+                            self.move_virtual_slider(messages, normal_delta);
+
+                            self.state.drag = prev_drag;
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) && bounds.width > 0.0 {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        let grabbed = self.state.grab(
+                            (cursor_position.x - bounds.x) / bounds.width,
+                            self.grab_width / 2.0 / bounds.width,
+                        );
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.drag = grabbed;
+                                self.state.prev_drag_x = cursor_position.x;
+                            }
+                            _ => {
+                                self.state.drag = Drag::None;
+
+                                for i in grabbed.handles() {
+                                    let param = &mut self.state.params[i];
+                                    param.value = param.default;
+                                    self.state.continuous_normals[i] =
+                                        param.value.as_f32();
+
+                                    messages.push((self.on_change)(
+                                        i,
+                                        self.state.params[i].value,
+                                    ));
+                                }
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging() {
+                        self.state.drag = Drag::None;
+
+                        for (continuous, param) in self
+                            .state
+                            .continuous_normals
+                            .iter_mut()
+                            .zip(self.state.params.iter())
+                        {
+                            *continuous = param.value.as_f32();
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let normals: Vec<Normal> =
+            self.state.params.iter().map(|param| param.value).collect();
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &normals,
+            self.state.is_dragging(),
+            self.tick_marks,
+            self.text_marks,
+            &self.style,
+            &self.state.tick_marks_cache,
+            &self.state.text_marks_cache,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`HRangeSlider`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`HRangeSlider`] in your user interface.
+///
+/// [`HRangeSlider`]: struct.HRangeSlider.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`HRangeSlider`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`HRangeSlider`]
+    ///   * the current cursor position
+    ///   * the current normal of each handle of the [`HRangeSlider`]
+    ///   * whether the slider is currently being dragged
+    ///   * any tick marks to display
+    ///   * any text marks to display
+    ///   * the style of the [`HRangeSlider`]
+    ///
+    /// [`HRangeSlider`]: struct.HRangeSlider.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normals: &[Normal],
+        is_dragging: bool,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style: &Self::Style,
+        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
+        text_marks_cache: &crate::text_marks::PrimitiveCache,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<HRangeSlider<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        h_range_slider: HRangeSlider<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(h_range_slider)
+    }
+}
@@ -43,6 +43,7 @@ pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    modulation: Option<Normal>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
@@ -73,6 +74,7 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            modulation: None,
         }
     }
 
@@ -197,6 +199,19 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the current modulated value to display, such as the value of
+    /// the parameter after an LFO or envelope has been applied to it. It is
+    /// drawn as set by `modulation_style(&self) -> Option<ModulationStyle>`
+    /// in your [`StyleSheet`].
+    ///
+    /// This only changes what is drawn, so it is cheap to set on every frame.
+    ///
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn modulation(mut self, normal: Normal) -> Self {
+        self.modulation = Some(normal);
+        self
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
@@ -467,6 +482,7 @@ where
             self.state.is_dragging,
             self.mod_range_1,
             self.mod_range_2,
+            self.modulation,
             self.tick_marks,
             self.text_marks,
             &self.style,
@@ -502,6 +518,7 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current normal of the [`HSlider`]
     ///   * the height of the handle in pixels
     ///   * whether the slider is currently being dragged
+    ///   * the modulated normal to display, if any
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`HSlider`]
@@ -515,6 +532,7 @@ pub trait Renderer: iced_native::Renderer {
         is_dragging: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        modulation: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
         style: &Self::Style,
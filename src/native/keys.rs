@@ -0,0 +1,605 @@
+//! Display an interactive piano keyboard that emits note-on and note-off
+//! messages.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::Normal;
+
+static DEFAULT_HEIGHT: u16 = 60;
+static DEFAULT_BLACK_KEY_LENGTH: f32 = 0.62;
+static DEFAULT_BLACK_KEY_WIDTH: f32 = 0.58;
+
+/// The highest MIDI note number.
+const MAX_NOTE: u8 = 127;
+
+/// The lowest velocity of a note-on, which is the lowest non-zero MIDI
+/// velocity. A MIDI note-on with a velocity of `0` means note-off.
+const MIN_VELOCITY: f32 = 1.0 / 127.0;
+
+/// Returns `true` if the given MIDI note number is a black key.
+pub fn is_black_key(note: u8) -> bool {
+    match note % 12 {
+        1 | 3 | 6 | 8 | 10 => true,
+        _ => false,
+    }
+}
+
+/// A message emitted by [`Keys`] when a key is pressed or released.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NoteEvent {
+    /// A key was pressed.
+    On {
+        /// The MIDI note number of the key
+        note: u8,
+        /// The velocity of the note, taken from how far down the key was
+        /// clicked. The front of a key is `1.0`, and the back is `1 / 127`
+        /// (the lowest non-zero MIDI velocity) so the note-on is never
+        /// mistaken for a note-off.
+        velocity: Normal,
+    },
+    /// A key was released.
+    Off {
+        /// The MIDI note number of the key
+        note: u8,
+    },
+}
+
+/// The orientation of [`Keys`].
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    /// Low notes are on the left, and the front of each key points down.
+    Horizontal,
+    /// Low notes are at the bottom, and the front of each key points right.
+    /// This is the layout usually used next to a piano roll.
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Horizontal
+    }
+}
+
+/// A single key of [`Keys`], as it is laid out on the screen.
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Key {
+    /// The MIDI note number of this key
+    pub note: u8,
+    /// The bounds of this key
+    pub bounds: Rectangle,
+    /// Whether this is a black key
+    pub is_black: bool,
+    /// Whether this key is pressed by the mouse
+    pub is_pressed: bool,
+    /// Whether this key is highlighted, e.g. because the note is played by
+    /// an incoming MIDI message
+    pub is_highlighted: bool,
+    /// Whether this key is inside the shaded key range
+    pub is_shaded: bool,
+}
+
+/// A piano keyboard GUI widget.
+///
+/// * Click on a key to play it. The further down (towards the front of the
+/// key) it is clicked, the higher the velocity.
+/// * Drag across keys to play a glissando.
+///
+/// Keys can also be highlighted from the outside (e.g. for notes played by
+/// incoming MIDI messages) with [`State::set_highlighted`], and a range of
+/// keys can be shaded with [`State::set_shaded_range`].
+///
+/// [`State::set_highlighted`]: struct.State.html#method.set_highlighted
+/// [`State::set_shaded_range`]: struct.State.html#method.set_shaded_range
+#[allow(missing_debug_implementations)]
+pub struct Keys<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_note: Box<dyn Fn(NoteEvent) -> Message>,
+    orientation: Orientation,
+    black_key_length: f32,
+    black_key_width: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Keys<'a, Message, Renderer> {
+    /// Creates a new [`Keys`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Keys`]
+    ///   * a function that will be called with a [`NoteEvent`] when a key is
+    /// pressed or released
+    ///
+    /// [`State`]: struct.State.html
+    /// [`NoteEvent`]: enum.NoteEvent.html
+    /// [`Keys`]: struct.Keys.html
+    pub fn new<F>(state: &'a mut State, on_note: F) -> Self
+    where
+        F: 'static + Fn(NoteEvent) -> Message,
+    {
+        Keys {
+            state,
+            on_note: Box::new(on_note),
+            orientation: Orientation::default(),
+            black_key_length: DEFAULT_BLACK_KEY_LENGTH,
+            black_key_width: DEFAULT_BLACK_KEY_WIDTH,
+            width: Length::Fill,
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Keys`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Keys`].
+    /// The default height is `Length::from(Length::Units(60))`.
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`Keys`].
+    /// The default orientation is `Orientation::Horizontal`.
+    ///
+    /// The width and height are not swapped automatically, so a vertical
+    /// keyboard will usually want a fixed width and a height of
+    /// `Length::Fill`.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Keys`]: struct.Keys.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the length of the black keys, relative to the length of the
+    /// white keys.
+    ///
+    /// The default value is `0.62`
+    pub fn black_key_length(mut self, length: f32) -> Self {
+        self.black_key_length = length;
+        self
+    }
+
+    /// Sets the width of the black keys, relative to the width of the white
+    /// keys.
+    ///
+    /// The default value is `0.58`
+    pub fn black_key_width(mut self, width: f32) -> Self {
+        self.black_key_width = width;
+        self
+    }
+
+    /// Sets the style of the [`Keys`].
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn layout_keys(&self, bounds: &Rectangle) -> Vec<Key> {
+        self.state.layout_keys(
+            bounds,
+            self.orientation,
+            self.black_key_length,
+            self.black_key_width,
+        )
+    }
+
+    /// Returns the key under the cursor and the velocity at that point.
+    fn key_at(
+        &self,
+        keys: &[Key],
+        cursor_position: Point,
+    ) -> Option<(u8, Normal)> {
+        // Black keys are laid out last and sit on top of the white keys.
+        keys.iter()
+            .rev()
+            .find(|key| key.bounds.contains(cursor_position))
+            .map(|key| {
+                let position = match self.orientation {
+                    Orientation::Horizontal => {
+                        (cursor_position.y - key.bounds.y) / key.bounds.height
+                    }
+                    Orientation::Vertical => {
+                        (cursor_position.x - key.bounds.x) / key.bounds.width
+                    }
+                };
+
+                let velocity = MIN_VELOCITY + (1.0 - MIN_VELOCITY) * position;
+
+                (key.note, Normal::from(velocity))
+            })
+    }
+}
+
+/// The local state of [`Keys`].
+///
+/// [`Keys`]: struct.Keys.html
+#[derive(Debug, Clone)]
+pub struct State {
+    lowest: u8,
+    highest: u8,
+    highlighted: [bool; MAX_NOTE as usize + 1],
+    shaded_range: Option<(u8, u8)>,
+    pressed: Option<u8>,
+}
+
+impl State {
+    /// Creates a new [`Keys`] state.
+    ///
+    /// It expects:
+    /// * the MIDI note number of the lowest key
+    /// * the MIDI note number of the highest key
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `lowest` is greater than `highest`, or if
+    /// `highest` is greater than `127`.
+    ///
+    /// [`Keys`]: struct.Keys.html
+    pub fn new(lowest: u8, highest: u8) -> Self {
+        assert!(lowest <= highest, "lowest must be <= highest");
+        assert!(highest <= MAX_NOTE, "highest must be <= 127");
+
+        Self {
+            lowest,
+            highest,
+            highlighted: [false; MAX_NOTE as usize + 1],
+            shaded_range: None,
+            pressed: None,
+        }
+    }
+
+    /// Returns the MIDI note number of the lowest key.
+    pub fn lowest(&self) -> u8 {
+        self.lowest
+    }
+
+    /// Returns the MIDI note number of the highest key.
+    pub fn highest(&self) -> u8 {
+        self.highest
+    }
+
+    /// Sets the range of displayed keys.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `lowest` is greater than `highest`, or if
+    /// `highest` is greater than `127`.
+    pub fn set_range(&mut self, lowest: u8, highest: u8) {
+        assert!(lowest <= highest, "lowest must be <= highest");
+        assert!(highest <= MAX_NOTE, "highest must be <= 127");
+
+        self.lowest = lowest;
+        self.highest = highest;
+    }
+
+    /// Highlights or un-highlights a key, e.g. for a note played by an
+    /// incoming MIDI message. Notes above `127` are ignored.
+    pub fn set_highlighted(&mut self, note: u8, highlighted: bool) {
+        if let Some(h) = self.highlighted.get_mut(usize::from(note)) {
+            *h = highlighted;
+        }
+    }
+
+    /// Returns `true` if the given key is highlighted.
+    pub fn is_highlighted(&self, note: u8) -> bool {
+        self.highlighted
+            .get(usize::from(note))
+            .cloned()
+            .unwrap_or(false)
+    }
+
+    /// Un-highlights all keys.
+    pub fn clear_highlighted(&mut self) {
+        self.highlighted = [false; MAX_NOTE as usize + 1];
+    }
+
+    /// Returns the range of shaded keys (inclusive), if any.
+    pub fn shaded_range(&self) -> Option<(u8, u8)> {
+        self.shaded_range
+    }
+
+    /// Sets the range of shaded keys (inclusive), such as the playable range
+    /// of an instrument or the range of a key zone. Set this to `None` to
+    /// remove the shading.
+    pub fn set_shaded_range(&mut self, range: Option<(u8, u8)>) {
+        self.shaded_range = range;
+    }
+
+    /// Returns the MIDI note number of the key currently pressed by the
+    /// mouse, if any.
+    pub fn pressed_note(&self) -> Option<u8> {
+        self.pressed
+    }
+
+    fn layout_keys(
+        &self,
+        bounds: &Rectangle,
+        orientation: Orientation,
+        black_key_length: f32,
+        black_key_width: f32,
+    ) -> Vec<Key> {
+        let notes = self.lowest..=self.highest;
+
+        let white_count =
+            notes.clone().filter(|note| !is_black_key(*note)).count();
+
+        // The length of the keyboard, and the length of each white key.
+        let (keyboard_length, key_length) = match orientation {
+            Orientation::Horizontal => (bounds.width, bounds.height),
+            Orientation::Vertical => (bounds.height, bounds.width),
+        };
+
+        let white_width = keyboard_length / white_count.max(1) as f32;
+        let black_width = white_width * black_key_width;
+
+        // Maps a span along the keyboard and a length across it to the
+        // bounds of a key.
+        let to_bounds = |start: f32, end: f32, length: f32| -> Rectangle {
+            let start = start.max(0.0);
+            let end = end.min(keyboard_length);
+
+            match orientation {
+                Orientation::Horizontal => Rectangle {
+                    x: bounds.x + start,
+                    y: bounds.y,
+                    width: end - start,
+                    height: length,
+                },
+                Orientation::Vertical => Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + bounds.height - end,
+                    width: length,
+                    height: end - start,
+                },
+            }
+        };
+
+        let is_shaded = |note: u8| -> bool {
+            match self.shaded_range {
+                Some((low, high)) => note >= low && note <= high,
+                None => false,
+            }
+        };
+
+        let mut white_keys: Vec<Key> = Vec::with_capacity(white_count);
+        let mut black_keys: Vec<Key> = Vec::new();
+
+        let mut whites_before = 0;
+
+        for note in notes {
+            let key_bounds = if is_black_key(note) {
+                // A black key sits on the boundary between two white keys.
+                let center = whites_before as f32 * white_width;
+
+                to_bounds(
+                    center - (black_width / 2.0),
+                    center + (black_width / 2.0),
+                    key_length * black_key_length,
+                )
+            } else {
+                let start = whites_before as f32 * white_width;
+                whites_before += 1;
+
+                to_bounds(start, start + white_width, key_length)
+            };
+
+            let key = Key {
+                note,
+                bounds: key_bounds,
+                is_black: is_black_key(note),
+                is_pressed: self.pressed == Some(note),
+                is_highlighted: self.is_highlighted(note),
+                is_shaded: is_shaded(note),
+            };
+
+            if key.is_black {
+                black_keys.push(key);
+            } else {
+                white_keys.push(key);
+            }
+        }
+
+        white_keys.append(&mut black_keys);
+
+        white_keys
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        // C3 to C6
+        Self::new(48, 84)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Keys<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(mouse_event) = event {
+            match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(pressed) = self.state.pressed {
+                        let keys = self.layout_keys(&bounds);
+
+                        if let Some((note, velocity)) =
+                            self.key_at(&keys, cursor_position)
+                        {
+                            if note != pressed {
+                                messages.push((self.on_note)(NoteEvent::Off {
+                                    note: pressed,
+                                }));
+                                messages.push((self.on_note)(NoteEvent::On {
+                                    note,
+                                    velocity,
+                                }));
+
+                                self.state.pressed = Some(note);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) {
+                        let keys = self.layout_keys(&bounds);
+
+                        if let Some((note, velocity)) =
+                            self.key_at(&keys, cursor_position)
+                        {
+                            if let Some(pressed) = self.state.pressed {
+                                messages.push((self.on_note)(NoteEvent::Off {
+                                    note: pressed,
+                                }));
+                            }
+
+                            messages.push((self.on_note)(NoteEvent::On {
+                                note,
+                                velocity,
+                            }));
+
+                            self.state.pressed = Some(note);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if let Some(pressed) = self.state.pressed.take() {
+                        messages.push((self.on_note)(NoteEvent::Off {
+                            note: pressed,
+                        }));
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let keys = self.layout_keys(&bounds);
+
+        renderer.draw(bounds, cursor_position, &keys, &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of [`Keys`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use [`Keys`] in your user interface.
+///
+/// [`Keys`]: struct.Keys.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws [`Keys`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Keys`]
+    ///   * the current cursor position
+    ///   * every key, with the white keys first and the black keys last
+    ///   * the style of the [`Keys`]
+    ///
+    /// [`Keys`]: struct.Keys.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        keys: &[Key],
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Keys<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        keys: Keys<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(keys)
+    }
+}
@@ -36,8 +36,11 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    modulation: Option<Normal>,
     use_radial_interaction: bool,
     angle_range: KnobAngleRange,
+    value_text: Option<Box<dyn Fn(Normal) -> String + 'a>>,
+    label: Option<&'a str>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
@@ -67,8 +70,11 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            modulation: None,
             use_radial_interaction: false,
             angle_range: KnobAngleRange::default(),
+            value_text: None,
+            label: None,
         }
     }
 
@@ -206,6 +212,47 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the current modulated value to display, such as the value of
+    /// the parameter after an LFO or envelope has been applied to it. It is
+    /// drawn as set by `modulation_style(&self) -> Option<ModulationStyle>`
+    /// in your [`StyleSheet`].
+    ///
+    /// This only changes what is drawn, so it is cheap to set on every frame.
+    ///
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn modulation(mut self, normal: Normal) -> Self {
+        self.modulation = Some(normal);
+        self
+    }
+
+    /// Sets a closure that formats the value of the [`Knob`] into the text
+    /// drawn in its center, such as `"440 Hz"`. Note your [`StyleSheet`] must
+    /// also implement `value_text_style(&self) -> Option<ValueTextStyle>` for
+    /// the text to display.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn value_text<F>(mut self, formatter: F) -> Self
+    where
+        F: 'a + Fn(Normal) -> String,
+    {
+        self.value_text = Some(Box::new(formatter));
+        self
+    }
+
+    /// Sets a label to draw in the center of the [`Knob`], such as the name
+    /// of its parameter. It is shown when the `content` of the
+    /// `ValueTextStyle` from your [`StyleSheet`] is
+    /// `ValueTextContent::Label`, so the label can be swapped for the value
+    /// when the [`Knob`] is hovered or dragged.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
@@ -512,8 +559,11 @@ where
             self.state.is_dragging,
             self.mod_range_1,
             self.mod_range_2,
+            self.modulation,
             self.tick_marks,
             self.text_marks,
+            self.value_text.as_deref(),
+            self.label,
             &self.style,
             &self.state.tick_marks_cache,
             &self.state.text_marks_cache,
@@ -545,8 +595,11 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current cursor position
     ///   * the current normal of the [`Knob`]
     ///   * whether the knob is currently being dragged
+    ///   * the modulated normal to display, if any
     ///   * any tick marks to display
     ///   * any text marks to display
+    ///   * the closure that formats the value text, if any
+    ///   * the label, if any
     ///   * the style of the [`Knob`]
     ///
     /// [`Knob`]: struct.Knob.html
@@ -558,8 +611,11 @@ pub trait Renderer: iced_native::Renderer {
         is_dragging: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
+        modulation: Option<Normal>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        value_text: Option<&dyn Fn(Normal) -> String>,
+        label: Option<&str>,
         style: &Self::Style,
         tick_marks_cache: &crate::tick_marks::PrimitiveCache,
         text_marks_cache: &crate::text_marks::PrimitiveCache,
@@ -0,0 +1,632 @@
+//! Display an interactive vertical wheel that controls a [`NormalParam`],
+//! like the mod wheel and pitch wheel of a hardware synthesizer
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+
+static DEFAULT_WIDTH: u16 = 24;
+static DEFAULT_HEIGHT: u16 = 100;
+static DEFAULT_SCALAR: f32 = 0.9575;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_RETURN_MILLIS: u64 = 120;
+
+/// The behavior of a [`ModWheel`] when the mouse button is released.
+///
+/// [`ModWheel`]: struct.ModWheel.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mode {
+    /// The wheel stays where it is left, like a mod wheel.
+    Mod,
+    /// The wheel springs back to its default value (usually the center)
+    /// when the mouse button is released, like a pitch wheel.
+    Pitch,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Mod
+    }
+}
+
+/// A vertical wheel GUI widget that controls a [`NormalParam`]
+///
+/// * Drag up and down to turn the wheel.
+/// * Double-click to reset the wheel to its default value.
+///
+/// In [`Mode::Pitch`], the wheel springs back to its default value when the
+/// mouse button is released. It eases back over the return duration of its
+/// [`State`], and the value of the parameter follows the drawn wheel.
+///
+/// The return is driven by your application: while [`State::is_returning`]
+/// is `true`, call [`State::tick`] on every frame (e.g. from a subscription
+/// to `iced::time::every`) and handle the value it returns the same way as a
+/// value from `on_change`. Without it, the wheel stays where it was released.
+/// A return duration of zero makes the wheel jump back on release instead,
+/// and `on_change` is called with the default value.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`Mode::Pitch`]: enum.Mode.html#variant.Pitch
+/// [`State`]: struct.State.html
+/// [`State::is_returning`]: struct.State.html#method.is_returning
+/// [`State::tick`]: struct.State.html#method.tick
+#[allow(missing_debug_implementations)]
+pub struct ModWheel<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(Normal) -> Message>,
+    scalar: f32,
+    wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> ModWheel<'a, Message, Renderer> {
+    /// Creates a new [`ModWheel`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ModWheel`]
+    ///   * a function that will be called when the [`ModWheel`] is turned,
+    /// or when it is reset to its default value.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        ModWheel {
+            state,
+            on_change: Box::new(on_change),
+            scalar: DEFAULT_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ModWheel`].
+    /// The default width is `Length::Units(24)`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ModWheel`].
+    /// The default height is `Length::Units(100)`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`ModWheel`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the wheel per pixel.
+    ///
+    /// For example, a scalar of `0.5` will cause the wheel to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.9575`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn scalar(mut self, scalar: f32) -> Self {
+        self.scalar = scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`ModWheel`]
+    /// per line scrolled by the mouse wheel. The mouse wheel is ignored in
+    /// [`Mode::Pitch`].
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// parameter.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    /// [`Mode::Pitch`]: enum.Mode.html#variant.Pitch
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the wheel while holding
+    /// down the modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the wheel to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn move_virtual_wheel(
+        &mut self,
+        messages: &mut Vec<Message>,
+        mut normal_delta: f32,
+    ) {
+        if keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.modifier_keys
+        {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        let mut normal = self.state.continuous_normal - normal_delta;
+
+        if normal < 0.0 {
+            normal = 0.0;
+        } else if normal > 1.0 {
+            normal = 1.0;
+        }
+
+        self.state.continuous_normal = normal;
+
+        self.state.set_value(normal.into());
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Return {
+    from: Normal,
+    start: Option<Instant>,
+}
+
+/// The local state of a [`ModWheel`].
+///
+/// [`ModWheel`]: struct.ModWheel.html
+#[derive(Debug, Clone)]
+pub struct State {
+    normal_param: NormalParam,
+    mode: Mode,
+    return_duration: Duration,
+    returning: Option<Return>,
+    is_dragging: bool,
+    prev_drag_y: f32,
+    continuous_normal: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`ModWheel`] state.
+    ///
+    /// It expects:
+    /// * a [`NormalParam`] to assign to this widget
+    /// * the [`Mode`] of the wheel
+    ///
+    /// In [`Mode::Pitch`], the wheel springs back to the default value of
+    /// the [`NormalParam`]. Use [`State::pitch`] for a wheel centered at
+    /// `0.5`.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`Mode`]: enum.Mode.html
+    /// [`Mode::Pitch`]: enum.Mode.html#variant.Pitch
+    /// [`State::pitch`]: struct.State.html#method.pitch
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn new(normal_param: NormalParam, mode: Mode) -> Self {
+        Self {
+            normal_param,
+            mode,
+            return_duration: Duration::from_millis(DEFAULT_RETURN_MILLIS),
+            returning: None,
+            is_dragging: false,
+            prev_drag_y: 0.0,
+            continuous_normal: normal_param.value.as_f32(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Creates a new [`ModWheel`] state in [`Mode::Pitch`] with both the
+    /// value and the default value at the center (`0.5`).
+    ///
+    /// [`Mode::Pitch`]: enum.Mode.html#variant.Pitch
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn pitch() -> Self {
+        Self::new(
+            NormalParam {
+                value: Normal::center(),
+                default: Normal::center(),
+            },
+            Mode::Pitch,
+        )
+    }
+
+    /// Returns the [`Mode`] of the [`ModWheel`].
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Sets the [`Mode`] of the [`ModWheel`].
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Sets how long it takes the wheel to spring back to its default
+    /// value in [`Mode::Pitch`]. A duration of zero disables the animation.
+    ///
+    /// The default duration is 120 milliseconds.
+    ///
+    /// [`Mode::Pitch`]: enum.Mode.html#variant.Pitch
+    pub fn set_return_duration(&mut self, duration: Duration) {
+        self.return_duration = duration;
+    }
+
+    /// Set the normalized value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.set_value(normal);
+        self.continuous_normal = normal.into();
+    }
+
+    /// Get the normalized value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Set the normalized default value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn set_default(&mut self, normal: Normal) {
+        self.normal_param.default = normal;
+    }
+
+    /// Get the normalized default value of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn default(&self) -> Normal {
+        self.normal_param.default
+    }
+
+    /// Returns `true` while the wheel is springing back to its default
+    /// value.
+    pub fn is_returning(&self) -> bool {
+        self.returning.is_some()
+    }
+
+    /// Advances the return animation to the given time. The first call after
+    /// the mouse button is released starts the animation.
+    ///
+    /// Returns the new value of the wheel while it is springing back, or
+    /// `None` if it is not. Handle it the same way as a value from the
+    /// `on_change` function of the [`ModWheel`].
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    pub fn tick(&mut self, now: Instant) -> Option<Normal> {
+        let returning = self.returning.as_mut()?;
+
+        let start = *returning.start.get_or_insert(now);
+        let from = returning.from.as_f32();
+        let to = self.normal_param.default.as_f32();
+
+        let progress = if self.return_duration > Duration::from_secs(0) {
+            now.saturating_duration_since(start).as_secs_f32()
+                / self.return_duration.as_secs_f32()
+        } else {
+            1.0
+        };
+
+        if progress >= 1.0 {
+            self.normal_param.value = self.normal_param.default;
+            self.returning = None;
+        } else {
+            // Ease out so the wheel slows down as it reaches the center.
+            let eased = 1.0 - ((1.0 - progress) * (1.0 - progress));
+
+            self.normal_param.value = (from + ((to - from) * eased)).into();
+        }
+
+        self.continuous_normal = self.normal_param.value.as_f32();
+
+        Some(self.normal_param.value)
+    }
+
+    fn set_value(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.returning = None;
+    }
+
+    /// Starts springing back to the default value. Returns `true` if the
+    /// wheel jumped there right away.
+    fn spring_back(&mut self) -> bool {
+        if self.return_duration > Duration::from_secs(0) {
+            self.returning = Some(Return {
+                from: self.normal_param.value,
+                start: None,
+            });
+
+            false
+        } else {
+            let default = self.normal_param.default;
+            self.set_normal(default);
+
+            true
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ModWheel<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if self.state.is_dragging {
+                        let bounds_height = layout.bounds().height;
+
+                        if bounds_height > 0.0 {
+                            let normal_delta = (cursor_position.y
+                                - self.state.prev_drag_y)
+                                / bounds_height
+                                * self.scalar;
+
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            self.move_virtual_wheel(messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0
+                        || self.state.mode == Mode::Pitch
+                    {
+                        return event::Status::Ignored;
+                    }
+
+                    if layout.bounds().contains(cursor_position) {
+                        let lines = match delta {
+                            iced_native::mouse::ScrollDelta::Lines {
+                                y,
+                                ..
+                            } => y,
+                            iced_native::mouse::ScrollDelta::Pixels {
+                                y,
+                                ..
+                            } => {
+                                if y > 0.0 {
+                                    1.0
+                                } else if y < 0.0 {
+                                    -1.0
+                                } else {
+                                    0.0
+                                }
+                            }
+                        };
+
+                        if lines != 0.0 {
+                            let normal_delta = -lines * self.wheel_scalar;
+
+                            self.move_virtual_wheel(messages, normal_delta);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if layout.bounds().contains(cursor_position) {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.is_dragging = true;
+                                self.state.prev_drag_y = cursor_position.y;
+
+                                // Grab the wheel where it is if it is still
+                                // springing back. Its value already follows
+                                // the return, so there is nothing to report.
+                                if self.state.is_returning() {
+                                    let normal = self.state.normal();
+                                    self.state.set_normal(normal);
+                                }
+                            }
+                            _ => {
+                                self.state.is_dragging = false;
+
+                                let default = self.state.normal_param.default;
+                                self.state.set_normal(default);
+
+                                messages.push((self.on_change)(default));
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if self.state.mode == Mode::Pitch {
+                            if self.state.spring_back() {
+                                messages.push((self.on_change)(
+                                    self.state.normal_param.value,
+                                ));
+                            }
+                        } else {
+                            self.state.continuous_normal =
+                                self.state.normal_param.value.as_f32();
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.state.normal_param.value,
+            self.state.is_dragging,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ModWheel`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ModWheel`] in your user interface.
+///
+/// [`ModWheel`]: struct.ModWheel.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ModWheel`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ModWheel`]
+    ///   * the current cursor position
+    ///   * the normal of the wheel
+    ///   * whether the wheel is currently being dragged
+    ///   * the style of the [`ModWheel`]
+    ///
+    /// [`ModWheel`]: struct.ModWheel.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        normal: Normal,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ModWheel<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        mod_wheel: ModWheel<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mod_wheel)
+    }
+}
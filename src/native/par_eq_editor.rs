@@ -0,0 +1,990 @@
+//! Display an editor for a parametric equalizer. Each band is controlled by
+//! a draggable node over a logarithmic frequency axis and a gain axis.
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::biquad::{self, Coefficients, FilterType, Response};
+use crate::core::{
+    analysis, FreqRange, LogDBRange, Normal, NormalParam, SkewedRange,
+};
+
+static DEFAULT_WIDTH: u16 = 400;
+static DEFAULT_HEIGHT: u16 = 160;
+static DEFAULT_NODE_RADIUS: f32 = 7.0;
+static DEFAULT_MIN_Q: f32 = 0.1;
+static DEFAULT_MAX_Q: f32 = 18.0;
+static DEFAULT_CENTER_Q: f32 = 0.707;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.02;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_SPECTRUM_FLOOR_DB: f32 = -90.0;
+static DEFAULT_SPECTRUM_CEILING_DB: f32 = 0.0;
+
+/// The slope of a high-pass or low-pass band.
+///
+/// The band is a Butterworth filter of the given order. A Q of `0.707`
+/// gives a flat passband, and a higher Q adds a resonant peak at the cutoff.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Slope {
+    /// 6 dB per octave (first order)
+    Db6,
+    /// 12 dB per octave (second order)
+    Db12,
+    /// 18 dB per octave (third order)
+    Db18,
+    /// 24 dB per octave (fourth order)
+    Db24,
+    /// 36 dB per octave (sixth order)
+    Db36,
+    /// 48 dB per octave (eighth order)
+    Db48,
+}
+
+impl Slope {
+    /// Returns the order of the filter, where each order adds 6 dB per
+    /// octave to the slope.
+    pub fn order(&self) -> usize {
+        match self {
+            Slope::Db6 => 1,
+            Slope::Db12 => 2,
+            Slope::Db18 => 3,
+            Slope::Db24 => 4,
+            Slope::Db36 => 6,
+            Slope::Db48 => 8,
+        }
+    }
+}
+
+/// The type of a band in a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BandType {
+    /// Boosts or cuts the frequencies around the center frequency
+    Bell,
+    /// Boosts or cuts the frequencies below the corner frequency
+    LowShelf,
+    /// Boosts or cuts the frequencies above the corner frequency
+    HighShelf,
+    /// Removes the frequencies below the cutoff frequency
+    HighPass(Slope),
+    /// Removes the frequencies above the cutoff frequency
+    LowPass(Slope),
+    /// Removes a narrow band of frequencies around the center frequency
+    Notch,
+}
+
+impl BandType {
+    /// Returns `true` if the gain of this type of band can be adjusted. This
+    /// is `true` for bell and shelf bands.
+    pub fn has_gain(&self) -> bool {
+        match self {
+            BandType::Bell | BandType::LowShelf | BandType::HighShelf => true,
+            _ => false,
+        }
+    }
+}
+
+/// A band in a [`ParEqEditor`].
+///
+/// The parameters are mapped with the ranges given to the [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Band {
+    /// The type of the band
+    pub band_type: BandType,
+    /// The center, corner, or cutoff frequency of the band
+    pub frequency: NormalParam,
+    /// The gain of the band. This is ignored by band types without gain.
+    pub gain: NormalParam,
+    /// The Q (bandwidth or resonance) of the band
+    pub q: NormalParam,
+}
+
+impl Band {
+    /// Creates a new [`Band`].
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn new(
+        band_type: BandType,
+        frequency: NormalParam,
+        gain: NormalParam,
+        q: NormalParam,
+    ) -> Self {
+        Self {
+            band_type,
+            frequency,
+            gain,
+            q,
+        }
+    }
+}
+
+/// The response of a [`Band`] as it is laid out on the screen.
+///
+/// [`Band`]: struct.Band.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandCurve {
+    /// The type of the band
+    pub band_type: BandType,
+    /// The position of the center of the node of the band
+    pub node: Point,
+    /// The magnitude response of the band at each pixel along the `x` axis,
+    /// mapped to the gain axis, where `0.0` is the bottom and `1.0` is the
+    /// top
+    pub response: Vec<Normal>,
+}
+
+/// An editor for a parametric equalizer.
+///
+/// * Drag a node to change the frequency and gain of its band.
+/// * Scroll over a node to change the Q of its band.
+/// * Double-click on an empty area to request a new band.
+/// * Right-click or double-click on a node to request its removal.
+///
+/// Bands are not added or removed by the widget itself. Instead, the
+/// messages set with [`on_add`] and [`on_remove`] are emitted, and the
+/// application updates the [`State`] with [`State::add_band`] and
+/// [`State::remove_band`].
+///
+/// [`on_add`]: struct.ParEqEditor.html#method.on_add
+/// [`on_remove`]: struct.ParEqEditor.html#method.on_remove
+/// [`State`]: struct.State.html
+/// [`State::add_band`]: struct.State.html#method.add_band
+/// [`State::remove_band`]: struct.State.html#method.remove_band
+#[allow(missing_debug_implementations)]
+pub struct ParEqEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Band) -> Message>,
+    on_add: Option<Box<dyn Fn(Band) -> Message>>,
+    on_remove: Option<Box<dyn Fn(usize) -> Message>>,
+    freq_range: FreqRange,
+    gain_range: LogDBRange,
+    q_range: SkewedRange,
+    sample_rate: Option<f32>,
+    spectrum_floor_db: f32,
+    spectrum_ceiling_db: f32,
+    wheel_scalar: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    node_radius: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> ParEqEditor<'a, Message, Renderer> {
+    /// Creates a new [`ParEqEditor`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ParEqEditor`]
+    ///   * a function that will be called with the index of a band and its
+    /// new parameters when the band is changed
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Band) -> Message,
+    {
+        ParEqEditor {
+            state,
+            on_change: Box::new(on_change),
+            on_add: None,
+            on_remove: None,
+            freq_range: FreqRange::default(),
+            gain_range: LogDBRange::default(),
+            q_range: SkewedRange::with_center(
+                DEFAULT_MIN_Q,
+                DEFAULT_MAX_Q,
+                DEFAULT_CENTER_Q,
+            ),
+            sample_rate: None,
+            spectrum_floor_db: DEFAULT_SPECTRUM_FLOOR_DB,
+            spectrum_ceiling_db: DEFAULT_SPECTRUM_CEILING_DB,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            node_radius: DEFAULT_NODE_RADIUS,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user double-clicks on an empty
+    /// area. It is called with a new bell band at the clicked frequency and
+    /// gain.
+    pub fn on_add<F>(mut self, on_add: F) -> Self
+    where
+        F: 'static + Fn(Band) -> Message,
+    {
+        self.on_add = Some(Box::new(on_add));
+        self
+    }
+
+    /// Sets the message to emit when the user right-clicks or double-clicks
+    /// on a node. It is called with the index of the band.
+    pub fn on_remove<F>(mut self, on_remove: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the range of the frequency axis. The frequency parameter of
+    /// every band is mapped with this range.
+    ///
+    /// The default range is `FreqRange::default()`.
+    pub fn freq_range(mut self, freq_range: FreqRange) -> Self {
+        self.freq_range = freq_range;
+        self
+    }
+
+    /// Sets the range of the gain axis. The gain parameter of every band is
+    /// mapped with this range.
+    ///
+    /// The default range is `LogDBRange::default()`.
+    pub fn gain_range(mut self, gain_range: LogDBRange) -> Self {
+        self.gain_range = gain_range;
+        self
+    }
+
+    /// Sets the range of the Q of each band. The Q parameter of every band
+    /// is mapped with this range.
+    ///
+    /// The default range is from `0.1` to `18.0`, centered at `0.707`.
+    pub fn q_range(mut self, q_range: SkewedRange) -> Self {
+        self.q_range = q_range;
+        self
+    }
+
+    /// Sets the sample rate (in Hz) of the equalizer. When this is set, the
+    /// response of each band is computed from the digital filter designed
+    /// with the formulas from the "Audio EQ Cookbook", including the
+    /// cramping near the Nyquist frequency.
+    ///
+    /// By default, the response of the analog prototype of each band is
+    /// drawn, which matches an analog-matched equalizer.
+    pub fn sample_rate(mut self, sample_rate: f32) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Sets the range (in decibels) of the spectrum underlay set with
+    /// [`State::set_spectrum`].
+    ///
+    /// The default range is from `-90.0` to `0.0`.
+    ///
+    /// [`State::set_spectrum`]: struct.State.html#method.set_spectrum
+    pub fn spectrum_range(mut self, floor_db: f32, ceiling_db: f32) -> Self {
+        self.spectrum_floor_db = floor_db;
+        self.spectrum_ceiling_db = ceiling_db;
+        self
+    }
+
+    /// Sets the width of the [`ParEqEditor`].
+    /// The default width is `Length::from(Length::Units(400))`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ParEqEditor`].
+    /// The default height is `Length::from(Length::Units(160))`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the radius (in pixels) around the center of a node in which the
+    /// node can be grabbed by the mouse.
+    ///
+    /// The default value is `7.0`
+    pub fn node_radius(mut self, radius: f32) -> Self {
+        self.node_radius = radius;
+        self
+    }
+
+    /// Sets how much the [`Normal`] Q of a band will change per line
+    /// scrolled by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel.
+    ///
+    /// The default value is `0.02`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets the modifier keys of the [`ParEqEditor`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a node or scrolls while
+    /// holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause nodes to move
+    /// half as fast when the modifier key is down.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn is_modifier_pressed(&self) -> bool {
+        keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.modifier_keys
+    }
+
+    fn node_position(&self, bounds: &Rectangle, band: &Band) -> Point {
+        let gain = if band.band_type.has_gain() {
+            band.gain.value
+        } else {
+            self.gain_range.map_to_normal(0.0)
+        };
+
+        Point::new(
+            bounds.x + (band.frequency.value.as_f32() * bounds.width),
+            bounds.y + ((1.0 - gain.as_f32()) * bounds.height),
+        )
+    }
+
+    fn node_at(
+        &self,
+        bounds: &Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        let radius_squared = self.node_radius * self.node_radius;
+
+        let mut nearest: Option<(usize, f32)> = None;
+
+        for (i, band) in self.state.bands.iter().enumerate() {
+            let position = self.node_position(bounds, band);
+
+            let dx = cursor_position.x - position.x;
+            let dy = cursor_position.y - position.y;
+            let distance_squared = (dx * dx) + (dy * dy);
+
+            if distance_squared <= radius_squared {
+                match nearest {
+                    Some((_, nearest_distance))
+                        if nearest_distance <= distance_squared => {}
+                    _ => nearest = Some((i, distance_squared)),
+                }
+            }
+        }
+
+        nearest.map(|(i, _)| i)
+    }
+
+    /// Returns a function that computes the response of a band at a given
+    /// frequency.
+    fn band_response(&self, band: &Band) -> Box<dyn Fn(f32) -> Response> {
+        let f0 = self.freq_range.unmap_to_value(band.frequency.value);
+        let gain_db = self.gain_range.unmap_to_value(band.gain.value);
+        let q = self.q_range.unmap_to_value(band.q.value);
+
+        let sections = band_sections(band.band_type, gain_db, q);
+
+        match self.sample_rate {
+            Some(sample_rate) => {
+                let coefficients: Vec<Coefficients> = sections
+                    .iter()
+                    .map(|(filter_type, q)| {
+                        Coefficients::rbj(*filter_type, f0, *q, sample_rate)
+                    })
+                    .collect();
+
+                Box::new(move |frequency| {
+                    coefficients.iter().fold(Response::UNITY, |r, c| {
+                        r * c.response(frequency, sample_rate)
+                    })
+                })
+            }
+            None => Box::new(move |frequency| {
+                sections
+                    .iter()
+                    .fold(Response::UNITY, |r, (filter_type, q)| {
+                        r * biquad::analog_response(
+                            *filter_type,
+                            f0,
+                            *q,
+                            frequency,
+                        )
+                    })
+            }),
+        }
+    }
+
+    fn band_curves(&self, bounds: &Rectangle) -> (Vec<BandCurve>, Vec<Normal>) {
+        let width = bounds.width.max(0.0).round() as usize;
+
+        let frequencies = biquad::frequency_grid(width, &self.freq_range);
+
+        let mut combined_db: Vec<f32> = vec![0.0; width];
+
+        let curves = self
+            .state
+            .bands
+            .iter()
+            .map(|band| {
+                let response_at = self.band_response(band);
+
+                let response = frequencies
+                    .iter()
+                    .zip(combined_db.iter_mut())
+                    .map(|(frequency, combined)| {
+                        let db = response_at(*frequency).magnitude_db();
+                        *combined += db;
+
+                        self.gain_range.map_to_normal(db)
+                    })
+                    .collect();
+
+                BandCurve {
+                    band_type: band.band_type,
+                    node: self.node_position(bounds, band),
+                    response,
+                }
+            })
+            .collect();
+
+        let combined = combined_db
+            .iter()
+            .map(|db| self.gain_range.map_to_normal(*db))
+            .collect();
+
+        (curves, combined)
+    }
+
+    fn push_change(&self, index: usize, messages: &mut Vec<Message>) {
+        messages.push((self.on_change)(index, self.state.bands[index]));
+    }
+}
+
+/// The local state of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+#[derive(Debug, Clone)]
+pub struct State {
+    bands: Vec<Band>,
+    spectrum: Vec<f32>,
+    drag: Option<usize>,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    continuous_frequency: f32,
+    continuous_gain: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`ParEqEditor`] state.
+    ///
+    /// It expects:
+    /// * the bands of the equalizer
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn new(bands: Vec<Band>) -> Self {
+        Self {
+            bands,
+            spectrum: Vec::new(),
+            drag: None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            continuous_frequency: 0.0,
+            continuous_gain: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Returns the bands of the equalizer.
+    pub fn bands(&self) -> &[Band] {
+        &self.bands
+    }
+
+    /// Replaces the band at `index`. This does nothing if there is no band
+    /// at `index`.
+    pub fn set_band(&mut self, index: usize, band: Band) {
+        if let Some(b) = self.bands.get_mut(index) {
+            *b = band;
+        }
+    }
+
+    /// Adds a band to the end of the equalizer.
+    pub fn add_band(&mut self, band: Band) {
+        self.bands.push(band);
+    }
+
+    /// Removes the band at `index`. This does nothing if there is no band
+    /// at `index`.
+    pub fn remove_band(&mut self, index: usize) {
+        if index < self.bands.len() {
+            let _ = self.bands.remove(index);
+            self.drag = None;
+        }
+    }
+
+    /// Sets the spectrum drawn underneath the response curves, in decibels.
+    ///
+    /// The values must be evenly spaced according to the frequency range of
+    /// the [`ParEqEditor`], such as the output of
+    /// [`SpectrumAnalyzer::resample_to_pixels`]. They are stretched to the
+    /// width of the widget. Set an empty spectrum to remove the underlay.
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    /// [`SpectrumAnalyzer::resample_to_pixels`]: ../../core/analysis/struct.SpectrumAnalyzer.html#method.resample_to_pixels
+    pub fn set_spectrum(&mut self, spectrum_db: &[f32]) {
+        self.spectrum.clear();
+        self.spectrum.extend_from_slice(spectrum_db);
+    }
+
+    /// Is a node of the [`ParEqEditor`] currently being dragged?
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ParEqEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(index) = self.state.drag {
+                        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                            return event::Status::Ignored;
+                        }
+
+                        let mut delta_x = (cursor_position.x
+                            - self.state.prev_drag_x)
+                            / bounds.width;
+                        let mut delta_y = (cursor_position.y
+                            - self.state.prev_drag_y)
+                            / bounds.height;
+
+                        if self.is_modifier_pressed() {
+                            delta_x *= self.modifier_scalar;
+                            delta_y *= self.modifier_scalar;
+                        }
+
+                        self.state.prev_drag_x = cursor_position.x;
+                        self.state.prev_drag_y = cursor_position.y;
+
+                        let frequency = (self.state.continuous_frequency
+                            + delta_x)
+                            .max(0.0)
+                            .min(1.0);
+                        let gain = (self.state.continuous_gain - delta_y)
+                            .max(0.0)
+                            .min(1.0);
+
+                        self.state.continuous_frequency = frequency;
+                        self.state.continuous_gain = gain;
+
+                        let band = &mut self.state.bands[index];
+                        band.frequency.value = frequency.into();
+                        if band.band_type.has_gain() {
+                            band.gain.value = gain.into();
+                        }
+
+                        self.push_change(index, messages);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.wheel_scalar == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    if bounds.contains(cursor_position) {
+                        let index = match self.state.drag {
+                            Some(index) => Some(index),
+                            None => self.node_at(&bounds, cursor_position),
+                        };
+
+                        let index = match index {
+                            Some(index) => index,
+                            None => return event::Status::Ignored,
+                        };
+
+                        let lines = match delta {
+                            mouse::ScrollDelta::Lines { y, .. } => y,
+                            mouse::ScrollDelta::Pixels { y, .. } => {
+                                if y > 0.0 {
+                                    1.0
+                                } else if y < 0.0 {
+                                    -1.0
+                                } else {
+                                    0.0
+                                }
+                            }
+                        };
+
+                        if lines != 0.0 {
+                            let mut normal_delta = lines * self.wheel_scalar;
+
+                            if self.is_modifier_pressed() {
+                                normal_delta *= self.modifier_scalar;
+                            }
+
+                            let q = &mut self.state.bands[index].q;
+                            q.value = (q.value.as_f32() + normal_delta).into();
+
+                            self.push_change(index, messages);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        let hit = self.node_at(&bounds, cursor_position);
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                if let Some(index) = hit {
+                                    let band = &self.state.bands[index];
+
+                                    self.state.drag = Some(index);
+                                    self.state.prev_drag_x = cursor_position.x;
+                                    self.state.prev_drag_y = cursor_position.y;
+                                    self.state.continuous_frequency =
+                                        band.frequency.value.as_f32();
+                                    self.state.continuous_gain =
+                                        band.gain.value.as_f32();
+                                }
+                            }
+                            _ => {
+                                self.state.drag = None;
+
+                                match hit {
+                                    Some(index) => {
+                                        if let Some(on_remove) = &self.on_remove
+                                        {
+                                            messages.push(on_remove(index));
+                                        }
+                                    }
+                                    None => {
+                                        if let Some(on_add) = &self.on_add {
+                                            let frequency = Normal::from(
+                                                (cursor_position.x - bounds.x)
+                                                    / bounds.width,
+                                            );
+                                            let gain = Normal::from(
+                                                1.0 - ((cursor_position.y
+                                                    - bounds.y)
+                                                    / bounds.height),
+                                            );
+                                            // The center of the Q range.
+                                            let q = NormalParam {
+                                                value: Normal::center(),
+                                                default: Normal::center(),
+                                            };
+
+                                            messages.push(on_add(Band::new(
+                                                BandType::Bell,
+                                                NormalParam {
+                                                    value: frequency,
+                                                    default: frequency,
+                                                },
+                                                NormalParam {
+                                                    value: gain,
+                                                    default: gain,
+                                                },
+                                                q,
+                                            )));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    if bounds.contains(cursor_position)
+                        && self.state.drag.is_none()
+                    {
+                        if let Some(index) =
+                            self.node_at(&bounds, cursor_position)
+                        {
+                            if let Some(on_remove) = &self.on_remove {
+                                messages.push(on_remove(index));
+
+                                return event::Status::Captured;
+                            }
+                        }
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.drag.is_some() {
+                        self.state.drag = None;
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let (bands, combined) = self.band_curves(&bounds);
+
+        let spectrum: Vec<Normal> = self
+            .state
+            .spectrum
+            .iter()
+            .map(|db| {
+                analysis::db_to_normal(
+                    *db,
+                    self.spectrum_floor_db,
+                    self.spectrum_ceiling_db,
+                )
+            })
+            .collect();
+
+        let active_band = match self.state.drag {
+            Some(index) => Some(index),
+            None => {
+                if bounds.contains(cursor_position) {
+                    self.node_at(&bounds, cursor_position)
+                } else {
+                    None
+                }
+            }
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &bands,
+            &combined,
+            &spectrum,
+            self.gain_range.map_to_normal(0.0),
+            active_band,
+            self.state.is_dragging(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// Returns the filter sections that make up a band, with the Q of each
+/// section.
+fn band_sections(
+    band_type: BandType,
+    gain_db: f32,
+    q: f32,
+) -> Vec<(FilterType, f32)> {
+    match band_type {
+        BandType::Bell => vec![(FilterType::Peak { gain_db }, q)],
+        BandType::LowShelf => vec![(FilterType::LowShelf { gain_db }, q)],
+        BandType::HighShelf => vec![(FilterType::HighShelf { gain_db }, q)],
+        BandType::Notch => vec![(FilterType::Notch, q)],
+        BandType::LowPass(slope) | BandType::HighPass(slope) => {
+            let (second_order, first_order) = match band_type {
+                BandType::HighPass(_) => {
+                    (FilterType::HighPass, FilterType::FirstOrderHighPass)
+                }
+                _ => (FilterType::LowPass, FilterType::FirstOrderLowPass),
+            };
+
+            let order = slope.order();
+
+            // The sections form a Butterworth cascade. The Q of the most
+            // resonant section is scaled by the Q of the band, so a Q of
+            // `0.707` is a flat Butterworth response.
+            let mut sections: Vec<(FilterType, f32)> = (0..(order / 2))
+                .map(|k| {
+                    let angle = (2 * k + 1) as f32 * std::f32::consts::PI
+                        / (2 * order) as f32;
+                    let butterworth_q = 1.0 / (2.0 * angle.sin());
+
+                    if k == 0 {
+                        (
+                            second_order,
+                            butterworth_q * q * std::f32::consts::SQRT_2,
+                        )
+                    } else {
+                        (second_order, butterworth_q)
+                    }
+                })
+                .collect();
+
+            if order % 2 == 1 {
+                sections.push((first_order, q));
+            }
+
+            sections
+        }
+    }
+}
+
+/// The renderer of a [`ParEqEditor`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ParEqEditor`] in your user interface.
+///
+/// [`ParEqEditor`]: struct.ParEqEditor.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ParEqEditor`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ParEqEditor`]
+    ///   * the current cursor position
+    ///   * the node and response of each band
+    ///   * the combined response of all bands at each pixel along the `x`
+    /// axis
+    ///   * the spectrum underlay, evenly spaced along the `x` axis
+    ///   * the position of `0 dB` on the gain axis
+    ///   * the index of the band whose node is hovered or being dragged
+    ///   * whether a node is currently being dragged
+    ///   * the style of the [`ParEqEditor`]
+    ///
+    /// [`ParEqEditor`]: struct.ParEqEditor.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        bands: &[BandCurve],
+        combined: &[Normal],
+        spectrum: &[Normal],
+        zero_db: Normal,
+        active_band: Option<usize>,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ParEqEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        par_eq_editor: ParEqEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(par_eq_editor)
+    }
+}
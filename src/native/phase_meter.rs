@@ -0,0 +1,270 @@
+//! Display a horizontal meter of the phase correlation (and optionally the
+//! balance) of a stereo signal.
+
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::correlation::CorrelationMeter;
+
+static DEFAULT_HEIGHT: u16 = 24;
+
+/// How the values of a [`PhaseMeter`] are drawn.
+///
+/// [`PhaseMeter`]: struct.PhaseMeter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mode {
+    /// A bar that grows from the center to the value.
+    Bar,
+    /// A thin needle at the value.
+    Needle,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Bar
+    }
+}
+
+/// A horizontal meter that displays the phase correlation of a stereo
+/// signal from `-1.0` on the left to `1.0` on the right, with an optional
+/// balance meter below it.
+///
+/// The values are read from the [`State`]. They are usually measured with a
+/// [`CorrelationMeter`], whose integration time sets how smoothly the meter
+/// moves.
+///
+/// [`State`]: struct.State.html
+/// [`CorrelationMeter`]: ../../core/correlation/struct.CorrelationMeter.html
+#[allow(missing_debug_implementations)]
+pub struct PhaseMeter<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    mode: Mode,
+    show_balance: bool,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> PhaseMeter<'a, Renderer> {
+    /// Creates a new [`PhaseMeter`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`PhaseMeter`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn new(state: &'a State) -> Self {
+        PhaseMeter {
+            state,
+            mode: Mode::default(),
+            show_balance: false,
+            width: Length::Fill,
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`PhaseMeter`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`PhaseMeter`]. When the balance meter is
+    /// shown, it takes up the bottom half of this height.
+    /// The default height is `Length::from(Length::Units(24))`.
+    ///
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Mode`] of the [`PhaseMeter`].
+    /// The default is `Mode::Bar`.
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether a balance meter is shown below the correlation meter.
+    /// The balance meter goes from the left channel on the left to the
+    /// right channel on the right.
+    ///
+    /// This is `false` by default.
+    pub fn show_balance(mut self, show_balance: bool) -> Self {
+        self.show_balance = show_balance;
+        self
+    }
+
+    /// Sets the style of the [`PhaseMeter`].
+    ///
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`PhaseMeter`].
+///
+/// [`PhaseMeter`]: struct.PhaseMeter.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct State {
+    correlation: f32,
+    balance: f32,
+}
+
+impl State {
+    /// Creates a new [`PhaseMeter`] state with a correlation and balance of
+    /// `0.0`.
+    ///
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the displayed phase correlation, from `-1.0` to `1.0`.
+    pub fn correlation(&self) -> f32 {
+        self.correlation
+    }
+
+    /// Sets the displayed phase correlation. It is clamped to the range
+    /// `-1.0` to `1.0`.
+    pub fn set_correlation(&mut self, correlation: f32) {
+        self.correlation = correlation.max(-1.0).min(1.0);
+    }
+
+    /// Returns the displayed balance, from `-1.0` (left) to `1.0` (right).
+    pub fn balance(&self) -> f32 {
+        self.balance
+    }
+
+    /// Sets the displayed balance. It is clamped to the range `-1.0` (left)
+    /// to `1.0` (right).
+    pub fn set_balance(&mut self, balance: f32) {
+        self.balance = balance.max(-1.0).min(1.0);
+    }
+
+    /// Sets the displayed correlation and balance to the current values of
+    /// a [`CorrelationMeter`].
+    ///
+    /// [`CorrelationMeter`]: ../../core/correlation/struct.CorrelationMeter.html
+    pub fn update(&mut self, meter: &CorrelationMeter) {
+        self.set_correlation(meter.correlation());
+        self.set_balance(meter.balance());
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for PhaseMeter<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let balance = if self.show_balance {
+            Some(self.state.balance)
+        } else {
+            None
+        };
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.state.correlation,
+            balance,
+            self.mode,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`PhaseMeter`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`PhaseMeter`] in your user interface.
+///
+/// [`PhaseMeter`]: struct.PhaseMeter.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`PhaseMeter`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`PhaseMeter`]
+    ///   * the current cursor position
+    ///   * the phase correlation, from `-1.0` to `1.0`
+    ///   * the balance from `-1.0` to `1.0`, if the balance meter is shown
+    ///   * the [`Mode`] of the [`PhaseMeter`]
+    ///   * the style of the [`PhaseMeter`]
+    ///
+    /// [`PhaseMeter`]: struct.PhaseMeter.html
+    /// [`Mode`]: enum.Mode.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        correlation: f32,
+        balance: Option<f32>,
+        mode: Mode,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<PhaseMeter<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        phase_meter: PhaseMeter<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(phase_meter)
+    }
+}
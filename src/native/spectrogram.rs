@@ -0,0 +1,387 @@
+//! Display a scrolling spectrogram (waterfall) of magnitude bins over time.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::analysis::{db_to_normal, SpectrumAnalyzer};
+use crate::core::{FreqRange, Normal};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 150;
+static DEFAULT_RESOLUTION: usize = 256;
+static DEFAULT_CAPACITY: usize = 300;
+
+/// The direction in which a [`Spectrogram`] scrolls.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScrollDirection {
+    /// Time runs along the `x` axis with the newest column on the right.
+    /// Frequency runs along the `y` axis with low frequencies at the bottom.
+    Horizontal,
+    /// Time runs along the `y` axis with the newest column at the top.
+    /// Frequency runs along the `x` axis with low frequencies on the left.
+    Vertical,
+}
+
+impl Default for ScrollDirection {
+    fn default() -> Self {
+        ScrollDirection::Horizontal
+    }
+}
+
+/// A single column of magnitudes in a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// A unique, increasing id for this column. This is used to cache the
+    /// rendered column.
+    pub id: u64,
+    /// The magnitudes in this column, evenly spaced along the
+    /// [`FreqRange`] of the [`State`] from low to high frequency.
+    ///
+    /// [`FreqRange`]: ../../core/struct.FreqRange.html
+    /// [`State`]: struct.State.html
+    pub magnitudes: Vec<Normal>,
+}
+
+/// A scrolling spectrogram widget. New columns of magnitude bins are pushed
+/// into the [`State`], and older columns scroll away.
+///
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct Spectrogram<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    width: Length,
+    height: Length,
+    direction: ScrollDirection,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> Spectrogram<'a, Renderer> {
+    /// Creates a new [`Spectrogram`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Spectrogram`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn new(state: &'a State) -> Self {
+        Spectrogram {
+            state,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            direction: ScrollDirection::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Spectrogram`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Spectrogram`].
+    /// The default height is `Length::from(Length::Units(150))`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ScrollDirection`] of the [`Spectrogram`].
+    /// The default is `ScrollDirection::Horizontal`.
+    ///
+    /// [`ScrollDirection`]: enum.ScrollDirection.html
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn direction(mut self, direction: ScrollDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the style of the [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Clone)]
+pub struct State {
+    freq_range: FreqRange,
+    resolution: usize,
+    capacity: usize,
+    columns: VecDeque<Column>,
+    next_id: u64,
+    scratch: Vec<f32>,
+    cache: crate::graphics::spectrogram::PrimitiveCache,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(FreqRange::default(), DEFAULT_RESOLUTION, DEFAULT_CAPACITY)
+    }
+}
+
+impl State {
+    /// Creates a new [`Spectrogram`] state.
+    ///
+    /// It expects:
+    /// * the [`FreqRange`] that spaces the frequency axis
+    /// * the number of magnitudes in each column
+    /// * the maximum number of columns kept in the history
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `resolution` or `capacity` is `0`.
+    ///
+    /// [`FreqRange`]: ../../core/struct.FreqRange.html
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn new(
+        freq_range: FreqRange,
+        resolution: usize,
+        capacity: usize,
+    ) -> Self {
+        assert!(resolution > 0);
+        assert!(capacity > 0);
+
+        Self {
+            freq_range,
+            resolution,
+            capacity,
+            columns: VecDeque::with_capacity(capacity),
+            next_id: 0,
+            scratch: Vec::with_capacity(resolution),
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns the [`FreqRange`] that spaces the frequency axis.
+    ///
+    /// [`FreqRange`]: ../../core/struct.FreqRange.html
+    pub fn freq_range(&self) -> &FreqRange {
+        &self.freq_range
+    }
+
+    /// Returns the number of magnitudes in each column.
+    pub fn resolution(&self) -> usize {
+        self.resolution
+    }
+
+    /// Returns the maximum number of columns kept in the history.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of columns kept in the history. The oldest
+    /// columns are dropped if there are more than `capacity` columns.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `capacity` is `0`.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        assert!(capacity > 0);
+
+        self.capacity = capacity;
+        while self.columns.len() > capacity {
+            let _ = self.columns.pop_front();
+        }
+    }
+
+    /// Returns the columns in the history, from oldest to newest.
+    pub fn columns(&self) -> &VecDeque<Column> {
+        &self.columns
+    }
+
+    /// Pushes a new column of magnitudes. The magnitudes must be evenly
+    /// spaced along the [`FreqRange`] of this state, from low to high
+    /// frequency. They are resampled if their length does not equal the
+    /// resolution of this state.
+    ///
+    /// [`FreqRange`]: ../../core/struct.FreqRange.html
+    pub fn push_column(&mut self, magnitudes: &[Normal]) {
+        let resolution = self.resolution;
+
+        let magnitudes: Vec<Normal> = if magnitudes.len() == resolution {
+            magnitudes.to_vec()
+        } else if magnitudes.is_empty() {
+            vec![Normal::min(); resolution]
+        } else {
+            let scale = magnitudes.len() as f32 / resolution as f32;
+            (0..resolution)
+                .map(|i| {
+                    let index = ((i as f32 + 0.5) * scale) as usize;
+                    magnitudes[index.min(magnitudes.len() - 1)]
+                })
+                .collect()
+        };
+
+        self.push(magnitudes);
+    }
+
+    /// Pushes a new column from the current bins of a
+    /// [`SpectrumAnalyzer`].
+    ///
+    /// * `floor_db` - the magnitude (in decibels) drawn with the lowest color
+    /// * `ceiling_db` - the magnitude (in decibels) drawn with the highest
+    /// color
+    ///
+    /// [`SpectrumAnalyzer`]: ../../core/analysis/struct.SpectrumAnalyzer.html
+    pub fn push_analyzer(
+        &mut self,
+        analyzer: &SpectrumAnalyzer,
+        floor_db: f32,
+        ceiling_db: f32,
+    ) {
+        let mut scratch = std::mem::take(&mut self.scratch);
+
+        analyzer.resample_to_pixels(
+            self.resolution,
+            &self.freq_range,
+            &mut scratch,
+        );
+
+        let magnitudes = scratch
+            .iter()
+            .map(|db| db_to_normal(*db, floor_db, ceiling_db))
+            .collect();
+
+        self.scratch = scratch;
+
+        self.push(magnitudes);
+    }
+
+    /// Removes all columns from the history.
+    pub fn clear(&mut self) {
+        self.columns.clear();
+    }
+
+    fn push(&mut self, magnitudes: Vec<Normal>) {
+        if self.columns.len() == self.capacity {
+            let _ = self.columns.pop_front();
+        }
+
+        self.columns.push_back(Column {
+            id: self.next_id,
+            magnitudes,
+        });
+
+        self.next_id += 1;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Spectrogram<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.columns,
+            self.state.capacity,
+            self.direction,
+            &self.style,
+            &self.state.cache,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Spectrogram`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Spectrogram`] in your user interface.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Spectrogram`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Spectrogram`]
+    ///   * the current cursor position
+    ///   * the columns to display, from oldest to newest
+    ///   * the maximum number of columns in the history
+    ///   * the scroll direction of the [`Spectrogram`]
+    ///   * the style of the [`Spectrogram`]
+    ///   * a cache of the rendered columns
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        columns: &VecDeque<Column>,
+        capacity: usize,
+        direction: ScrollDirection,
+        style: &Self::Style,
+        cache: &crate::graphics::spectrogram::PrimitiveCache,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spectrogram<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        spectrogram: Spectrogram<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(spectrogram)
+    }
+}
@@ -3,12 +3,14 @@
 use std::fmt::Debug;
 
 use crate::core::Normal;
+use crate::style::text_marks::RadialAlign;
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
 pub struct Group {
     /// The group of text marks.
     pub group: Vec<(Normal, String)>,
+    radial_aligns: Vec<(usize, RadialAlign)>,
     hashed: u64,
 }
 
@@ -44,6 +46,7 @@ impl Group {
 
         Self {
             group,
+            radial_aligns: Vec::new(),
             hashed: hasher.finish(),
         }
     }
@@ -152,6 +155,54 @@ impl Group {
         vec.into()
     }
 
+    /// Returns the [`Group`] with the alignment of the text mark at `index`
+    /// around an arc set to `align`. This overrides the alignment of the
+    /// `RadialLayout` for that text mark.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::text_marks::{Group, RadialAlign};
+    ///
+    /// // Keep the first label from hanging past the start of the arc.
+    /// let group = Group::evenly_spaced(&["-inf", "-12", "0"])
+    ///     .with_radial_align(0, RadialAlign::Start);
+    ///
+    /// assert_eq!(group.radial_align(0), Some(RadialAlign::Start));
+    /// assert_eq!(group.radial_align(1), None);
+    /// ```
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn with_radial_align(
+        mut self,
+        index: usize,
+        align: RadialAlign,
+    ) -> Self {
+        use std::hash::{Hash, Hasher};
+
+        self.radial_aligns.retain(|(i, _)| *i != index);
+        self.radial_aligns.push((index, align));
+
+        let mut hasher = iced_native::Hasher::default();
+        self.hashed.hash(&mut hasher);
+        index.hash(&mut hasher);
+        align.hash(&mut hasher);
+        self.hashed = hasher.finish();
+
+        self
+    }
+
+    /// Returns the alignment around an arc that was set for the text mark
+    /// at `index` with [`with_radial_align`], if any.
+    ///
+    /// [`with_radial_align`]: #method.with_radial_align
+    pub fn radial_align(&self, index: usize) -> Option<RadialAlign> {
+        self.radial_aligns
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, align)| *align)
+    }
+
     /// Returns the hashed value of the internal data.
     pub(crate) fn hashed(&self) -> u64 {
         self.hashed
@@ -0,0 +1,850 @@
+//! Display the peak amplitude of an audio clip, with zooming, scrolling, a
+//! selection region, and draggable markers.
+
+use std::cell::RefCell;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::core::peak_cache::{self, PeakCache};
+use crate::core::Normal;
+
+pub use crate::core::peak_cache::Peak;
+
+static DEFAULT_WIDTH: u16 = 400;
+static DEFAULT_HEIGHT: u16 = 120;
+static DEFAULT_ZOOM_SCALAR: f32 = 0.2;
+static DEFAULT_SCROLL_SCALAR: f32 = 0.1;
+static DEFAULT_MARKER_GRAB_WIDTH: f32 = 4.0;
+static MIN_VISIBLE_SAMPLES: f64 = 16.0;
+
+/// A source of peaks that can be displayed by a [`WaveformView`].
+///
+/// This is implemented for `[f32]` and `Vec<f32>`, which scan every sample
+/// in the visible range, and for [`PeakCache`], which reads precomputed
+/// summaries and stays fast for clips with tens of millions of samples.
+/// The peaks are only recomputed when the view is zoomed, scrolled, or
+/// resized, or when the length of the source changes.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+/// [`PeakCache`]: ../../core/peak_cache/struct.PeakCache.html
+pub trait PeakSource {
+    /// Returns the number of samples in the clip.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no samples in the clip.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fills `peaks` with the peaks of evenly sized ranges from `start` to
+    /// `end`. `start` and `end` are positions in samples, and may be
+    /// fractional when the view is zoomed in further than one sample per
+    /// pixel.
+    fn peaks(&self, start: f64, end: f64, peaks: &mut [Peak]);
+}
+
+impl PeakSource for [f32] {
+    fn len(&self) -> usize {
+        <[f32]>::len(self)
+    }
+
+    fn peaks(&self, start: f64, end: f64, peaks: &mut [Peak]) {
+        peak_cache::peaks_of_samples(self, start, end, peaks)
+    }
+}
+
+impl PeakSource for Vec<f32> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn peaks(&self, start: f64, end: f64, peaks: &mut [Peak]) {
+        self.as_slice().peaks(start, end, peaks)
+    }
+}
+
+impl PeakSource for PeakCache {
+    fn len(&self) -> usize {
+        PeakCache::len(self)
+    }
+
+    fn peaks(&self, start: f64, end: f64, peaks: &mut [Peak]) {
+        PeakCache::peaks(self, start, end, peaks)
+    }
+}
+
+fn pixels_to_lines(pixels: f32) -> f32 {
+    if pixels > 0.0 {
+        1.0
+    } else if pixels < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+/// A marker in a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Marker {
+    /// The playhead
+    Playhead,
+    /// The start of the loop region
+    LoopStart,
+    /// The end of the loop region
+    LoopEnd,
+    /// The start of the sample. The area before it is shaded.
+    SampleStart,
+    /// The end of the sample. The area after it is shaded.
+    SampleEnd,
+}
+
+impl Marker {
+    /// All markers, in the order they are drawn.
+    pub const ALL: [Marker; 5] = [
+        Marker::SampleStart,
+        Marker::SampleEnd,
+        Marker::LoopStart,
+        Marker::LoopEnd,
+        Marker::Playhead,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Marker::Playhead => 0,
+            Marker::LoopStart => 1,
+            Marker::LoopEnd => 2,
+            Marker::SampleStart => 3,
+            Marker::SampleEnd => 4,
+        }
+    }
+}
+
+/// An event emitted by a [`WaveformView`]. All positions are relative to
+/// the whole clip, where `0.0` is the first sample and `1.0` is the end of
+/// the clip.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WaveformEvent {
+    /// The selection region was changed by dragging.
+    Selected {
+        /// The start of the selection
+        start: Normal,
+        /// The end of the selection
+        end: Normal,
+    },
+    /// The selection region was removed.
+    SelectionCleared,
+    /// The user clicked without dragging, such as to move the playhead.
+    Clicked(Normal),
+    /// A marker was dragged to a new position.
+    MarkerMoved {
+        /// The marker that was moved
+        marker: Marker,
+        /// The new position of the marker
+        position: Normal,
+    },
+}
+
+/// A view of the peak amplitude of an audio clip.
+///
+/// Scroll the mouse wheel to scroll through the clip, and hold down the
+/// zoom modifier key (`Ctrl` by default) while scrolling to zoom in and out
+/// around the cursor. Drag to select a region, and drag a marker to move
+/// it.
+#[allow(missing_debug_implementations)]
+pub struct WaveformView<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    source: &'a dyn PeakSource,
+    on_event: Box<dyn Fn(WaveformEvent) -> Message>,
+    zoom_scalar: f32,
+    scroll_scalar: f32,
+    zoom_modifier_keys: keyboard::Modifiers,
+    marker_grab_width: f32,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    WaveformView<'a, Message, Renderer>
+{
+    /// Creates a new [`WaveformView`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`WaveformView`]
+    ///   * the [`PeakSource`] of the clip to display
+    ///   * a function that will be called when the selection or a marker
+    /// is changed
+    ///
+    /// [`State`]: struct.State.html
+    /// [`PeakSource`]: trait.PeakSource.html
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new<F>(
+        state: &'a mut State,
+        source: &'a dyn PeakSource,
+        on_event: F,
+    ) -> Self
+    where
+        F: 'static + Fn(WaveformEvent) -> Message,
+    {
+        WaveformView {
+            state,
+            source,
+            on_event: Box::new(on_event),
+            zoom_scalar: DEFAULT_ZOOM_SCALAR,
+            scroll_scalar: DEFAULT_SCROLL_SCALAR,
+            zoom_modifier_keys: keyboard::Modifiers::default()
+                | keyboard::Modifiers::CTRL,
+            marker_grab_width: DEFAULT_MARKER_GRAB_WIDTH,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`WaveformView`].
+    /// The default width is `Length::from(Length::Units(400))`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WaveformView`].
+    /// The default height is `Length::from(Length::Units(120))`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WaveformView`].
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets how much the view is zoomed for each line scrolled while the
+    /// zoom modifier key is down. For example, a `zoom_scalar` of `0.2`
+    /// shows 20% less of the clip each time the wheel is scrolled up.
+    ///
+    /// This can be set to `0.0` to disable zooming with the scroll wheel.
+    ///
+    /// The default value is `0.2`
+    pub fn zoom_scalar(mut self, zoom_scalar: f32) -> Self {
+        self.zoom_scalar = zoom_scalar.max(0.0).min(0.95);
+        self
+    }
+
+    /// Sets how far the view is scrolled for each line scrolled, as a
+    /// fraction of the visible width.
+    ///
+    /// This can be set to `0.0` to disable scrolling with the scroll wheel.
+    ///
+    /// The default value is `0.1`
+    pub fn scroll_scalar(mut self, scroll_scalar: f32) -> Self {
+        self.scroll_scalar = scroll_scalar;
+        self
+    }
+
+    /// Sets the modifier keys that must be held down to zoom with the scroll
+    /// wheel.
+    ///
+    /// The default modifier key is `Ctrl`.
+    pub fn zoom_modifier_keys(
+        mut self,
+        modifier_keys: keyboard::Modifiers,
+    ) -> Self {
+        self.zoom_modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets how close (in pixels) the cursor has to be to a marker to grab
+    /// it.
+    ///
+    /// The default value is `4.0`
+    pub fn marker_grab_width(mut self, width: f32) -> Self {
+        self.marker_grab_width = width;
+        self
+    }
+
+    fn is_zoom_modifier_pressed(&self) -> bool {
+        keyboard::Modifiers::empty()
+            != self.state.pressed_modifiers & self.zoom_modifier_keys
+    }
+
+    /// The smallest fraction of the clip that can be shown.
+    fn min_span(&self) -> f64 {
+        let len = self.source.len();
+
+        if len == 0 {
+            1.0
+        } else {
+            (MIN_VISIBLE_SAMPLES / len as f64).min(1.0)
+        }
+    }
+
+    /// Converts an `x` coordinate to a position in the clip.
+    fn position_at(&self, bounds: &Rectangle, x: f32) -> f64 {
+        if bounds.width <= 0.0 {
+            return self.state.view_start;
+        }
+
+        let t = f64::from((x - bounds.x) / bounds.width);
+        let span = self.state.view_end - self.state.view_start;
+
+        (self.state.view_start + (t * span)).max(0.0).min(1.0)
+    }
+
+    /// Converts a position in the clip to an `x` coordinate. The coordinate
+    /// is outside of the bounds if the position is not visible.
+    fn x_at(&self, bounds: &Rectangle, position: Normal) -> f32 {
+        let span = self.state.view_end - self.state.view_start;
+
+        if span <= 0.0 {
+            return bounds.x;
+        }
+
+        let t = (f64::from(position.as_f32()) - self.state.view_start) / span;
+
+        bounds.x + (t as f32 * bounds.width)
+    }
+
+    fn marker_at(&self, bounds: &Rectangle, x: f32) -> Option<Marker> {
+        let mut nearest: Option<(Marker, f32)> = None;
+
+        // Search in reverse so that the markers drawn on top are picked
+        // first.
+        for marker in Marker::ALL.iter().rev() {
+            if let Some(position) = self.state.marker(*marker) {
+                let distance = (self.x_at(bounds, position) - x).abs();
+
+                if distance <= self.marker_grab_width {
+                    match nearest {
+                        Some((_, nearest_distance))
+                            if nearest_distance <= distance => {}
+                        _ => nearest = Some((*marker, distance)),
+                    }
+                }
+            }
+        }
+
+        nearest.map(|(marker, _)| marker)
+    }
+
+    /// Moves a marker, keeping the start of each region before its end.
+    fn move_marker(&mut self, marker: Marker, position: f64) -> Normal {
+        let state = &self.state;
+        let bound = |other: Marker, default: f64| -> f64 {
+            state
+                .marker(other)
+                .map(|normal| f64::from(normal.as_f32()))
+                .unwrap_or(default)
+        };
+
+        let position = match marker {
+            Marker::Playhead => position,
+            Marker::LoopStart => position.min(bound(Marker::LoopEnd, 1.0)),
+            Marker::LoopEnd => position.max(bound(Marker::LoopStart, 0.0)),
+            Marker::SampleStart => position.min(bound(Marker::SampleEnd, 1.0)),
+            Marker::SampleEnd => position.max(bound(Marker::SampleStart, 0.0)),
+        };
+
+        let normal = Normal::from(position as f32);
+        self.state.set_marker(marker, Some(normal));
+
+        normal
+    }
+
+    fn zoom(&mut self, bounds: &Rectangle, x: f32, lines: f32) -> bool {
+        let span = self.state.view_end - self.state.view_start;
+        let factor = f64::from(1.0 - self.zoom_scalar).powf(f64::from(lines));
+        let new_span = (span * factor).max(self.min_span()).min(1.0);
+
+        if (new_span - span).abs() <= std::f64::EPSILON {
+            return false;
+        }
+
+        // Keep the position under the cursor in place.
+        let anchor = self.position_at(bounds, x);
+        let t = if bounds.width > 0.0 {
+            f64::from(((x - bounds.x) / bounds.width).max(0.0).min(1.0))
+        } else {
+            0.0
+        };
+
+        self.state.set_view(anchor - (t * new_span), new_span);
+
+        true
+    }
+
+    fn scroll(&mut self, lines: f32) -> bool {
+        let span = self.state.view_end - self.state.view_start;
+
+        if span >= 1.0 {
+            return false;
+        }
+
+        let start = self.state.view_start
+            - (f64::from(lines * self.scroll_scalar) * span);
+
+        let prev_start = self.state.view_start;
+        self.state.set_view(start, span);
+
+        (self.state.view_start - prev_start).abs() > std::f64::EPSILON
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Drag {
+    Select { anchor: f64, moved: bool },
+    Marker(Marker),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnsKey {
+    len: usize,
+    width: usize,
+    view_start: f64,
+    view_end: f64,
+    revision: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Columns {
+    key: Option<ColumnsKey>,
+    peaks: Vec<Peak>,
+}
+
+/// The local state of a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Clone)]
+pub struct State {
+    view_start: f64,
+    view_end: f64,
+    selection: Option<(Normal, Normal)>,
+    markers: [Option<Normal>; 5],
+    drag: Option<Drag>,
+    pressed_modifiers: keyboard::Modifiers,
+    revision: u64,
+    columns: RefCell<Columns>,
+}
+
+impl State {
+    /// Creates a new [`WaveformView`] state that shows the whole clip, with
+    /// no selection and no markers.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new() -> Self {
+        Self {
+            view_start: 0.0,
+            view_end: 1.0,
+            selection: None,
+            markers: [None; 5],
+            drag: None,
+            pressed_modifiers: Default::default(),
+            revision: 0,
+            columns: Default::default(),
+        }
+    }
+
+    /// Returns the start and end of the visible part of the clip.
+    pub fn visible_range(&self) -> (Normal, Normal) {
+        (
+            Normal::from(self.view_start as f32),
+            Normal::from(self.view_end as f32),
+        )
+    }
+
+    /// Zooms in to show the part of the clip from `start` to `end`, such as
+    /// the current selection. This does nothing if `end` is not greater
+    /// than `start`.
+    pub fn zoom_to(&mut self, start: Normal, end: Normal) {
+        let start = f64::from(start.as_f32());
+        let end = f64::from(end.as_f32());
+
+        if end > start {
+            self.set_view(start, end - start);
+        }
+    }
+
+    /// Zooms out to show the whole clip.
+    pub fn zoom_to_fit(&mut self) {
+        self.set_view(0.0, 1.0);
+    }
+
+    /// Returns the selection region, if there is one.
+    pub fn selection(&self) -> Option<(Normal, Normal)> {
+        self.selection
+    }
+
+    /// Sets the selection region. The start and end are swapped if they are
+    /// in the wrong order.
+    pub fn set_selection(&mut self, selection: Option<(Normal, Normal)>) {
+        self.selection = selection.map(|(start, end)| {
+            if start.as_f32() <= end.as_f32() {
+                (start, end)
+            } else {
+                (end, start)
+            }
+        });
+    }
+
+    /// Returns the position of a [`Marker`], or `None` if it is hidden.
+    ///
+    /// [`Marker`]: enum.Marker.html
+    pub fn marker(&self, marker: Marker) -> Option<Normal> {
+        self.markers[marker.index()]
+    }
+
+    /// Sets the position of a [`Marker`]. Set it to `None` to hide it.
+    ///
+    /// [`Marker`]: enum.Marker.html
+    pub fn set_marker(&mut self, marker: Marker, position: Option<Normal>) {
+        self.markers[marker.index()] = position;
+    }
+
+    /// Is the selection or a marker currently being dragged?
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Recomputes the displayed peaks on the next redraw. Call this after
+    /// the samples of the [`PeakSource`] are changed without changing its
+    /// length.
+    ///
+    /// [`PeakSource`]: trait.PeakSource.html
+    pub fn refresh(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    fn set_view(&mut self, start: f64, span: f64) {
+        let span = span.max(0.0).min(1.0);
+        let start = start.max(0.0).min(1.0 - span);
+
+        self.view_start = start;
+        self.view_end = start + span;
+    }
+
+    fn update_columns(&self, source: &dyn PeakSource, width: usize) {
+        let len = source.len();
+
+        let key = ColumnsKey {
+            len,
+            width,
+            view_start: self.view_start,
+            view_end: self.view_end,
+            revision: self.revision,
+        };
+
+        let mut columns = self.columns.borrow_mut();
+
+        if columns.key.as_ref() == Some(&key) {
+            return;
+        }
+
+        columns.peaks.clear();
+        columns.peaks.resize(width, Peak::default());
+
+        if len > 0 {
+            source.peaks(
+                self.view_start * len as f64,
+                self.view_end * len as f64,
+                &mut columns.peaks,
+            );
+        }
+
+        columns.key = Some(key);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WaveformView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => match self.state.drag {
+                    Some(Drag::Marker(marker)) => {
+                        let position =
+                            self.position_at(&bounds, cursor_position.x);
+                        let position = self.move_marker(marker, position);
+
+                        messages.push((self.on_event)(
+                            WaveformEvent::MarkerMoved { marker, position },
+                        ));
+
+                        return event::Status::Captured;
+                    }
+                    Some(Drag::Select { anchor, .. }) => {
+                        let position =
+                            self.position_at(&bounds, cursor_position.x);
+
+                        let start = Normal::from(anchor.min(position) as f32);
+                        let end = Normal::from(anchor.max(position) as f32);
+
+                        self.state.drag = Some(Drag::Select {
+                            anchor,
+                            moved: true,
+                        });
+                        self.state.selection = Some((start, end));
+
+                        messages.push((self.on_event)(
+                            WaveformEvent::Selected { start, end },
+                        ));
+
+                        return event::Status::Captured;
+                    }
+                    None => {}
+                },
+                mouse::Event::WheelScrolled { delta } => {
+                    if !bounds.contains(cursor_position) {
+                        return event::Status::Ignored;
+                    }
+
+                    let (x, y) = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x, y),
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            (pixels_to_lines(x), pixels_to_lines(y))
+                        }
+                    };
+
+                    let changed = if self.is_zoom_modifier_pressed() {
+                        self.zoom_scalar != 0.0
+                            && y != 0.0
+                            && self.zoom(&bounds, cursor_position.x, y)
+                    } else {
+                        let lines = if x != 0.0 { x } else { y };
+
+                        self.scroll_scalar != 0.0
+                            && lines != 0.0
+                            && self.scroll(lines)
+                    };
+
+                    if changed {
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if bounds.contains(cursor_position) {
+                        self.state.drag = match self
+                            .marker_at(&bounds, cursor_position.x)
+                        {
+                            Some(marker) => Some(Drag::Marker(marker)),
+                            None => Some(Drag::Select {
+                                anchor: self
+                                    .position_at(&bounds, cursor_position.x),
+                                moved: false,
+                            }),
+                        };
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    match self.state.drag.take() {
+                        Some(Drag::Select {
+                            anchor,
+                            moved: false,
+                        }) => {
+                            if self.state.selection.take().is_some() {
+                                messages.push((self.on_event)(
+                                    WaveformEvent::SelectionCleared,
+                                ));
+                            }
+
+                            messages.push((self.on_event)(
+                                WaveformEvent::Clicked(Normal::from(
+                                    anchor as f32,
+                                )),
+                            ));
+
+                            return event::Status::Captured;
+                        }
+                        Some(_) => return event::Status::Captured,
+                        None => {}
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        self.state.update_columns(
+            self.source,
+            bounds.width.max(0.0).round() as usize,
+        );
+
+        let selection = self.state.selection.map(|(start, end)| {
+            (self.x_at(&bounds, start), self.x_at(&bounds, end))
+        });
+
+        let markers: Vec<(Marker, f32)> = Marker::ALL
+            .iter()
+            .filter_map(|marker| {
+                self.state
+                    .marker(*marker)
+                    .map(|position| (*marker, self.x_at(&bounds, position)))
+            })
+            .collect();
+
+        let active_marker = match self.state.drag {
+            Some(Drag::Marker(marker)) => Some(marker),
+            Some(Drag::Select { .. }) => None,
+            None => {
+                if bounds.contains(cursor_position) {
+                    self.marker_at(&bounds, cursor_position.x)
+                } else {
+                    None
+                }
+            }
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.columns.borrow().peaks,
+            selection,
+            &markers,
+            active_marker,
+            self.state.is_dragging(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`WaveformView`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`WaveformView`] in your user interface.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`WaveformView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`WaveformView`]
+    ///   * the current cursor position
+    ///   * the peak of each pixel along the `x` axis
+    ///   * the `x` coordinates of the start and end of the selection. These
+    /// are outside of the bounds if they are scrolled out of view.
+    ///   * the `x` coordinate of each visible marker. These are outside of
+    /// the bounds if they are scrolled out of view.
+    ///   * the marker that is hovered or being dragged
+    ///   * whether the selection or a marker is currently being dragged
+    ///   * the style of the [`WaveformView`]
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        peaks: &[Peak],
+        selection: Option<(f32, f32)>,
+        markers: &[(Marker, f32)],
+        active_marker: Option<Marker>,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<WaveformView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        waveform_view: WaveformView<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(waveform_view)
+    }
+}
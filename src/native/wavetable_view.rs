@@ -0,0 +1,590 @@
+//! Display a single cycle of a waveform, optionally as a stack of the frames
+//! of a wavetable. In draw mode, the current frame can be sketched with the
+//! mouse.
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Vector, Widget,
+};
+
+static DEFAULT_WIDTH: u16 = 300;
+static DEFAULT_HEIGHT: u16 = 150;
+static DEFAULT_FRAME_LEN: usize = 256;
+static DEFAULT_MAX_STACK_FRAMES: usize = 64;
+
+/// Smooths a single-cycle waveform with a moving average of
+/// `2 * radius + 1` samples. The waveform is treated as a loop, so the first
+/// and last samples are averaged with each other.
+///
+/// A `radius` of `0` leaves the samples unchanged.
+pub fn smooth(samples: &mut [f32], radius: usize) {
+    let len = samples.len();
+    smooth_range(samples, 0, len, radius);
+}
+
+/// Scales a waveform so that its highest absolute sample is `1.0`. Silence
+/// is left unchanged.
+pub fn normalize(samples: &mut [f32]) {
+    let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+
+    if peak > std::f32::EPSILON {
+        for sample in samples.iter_mut() {
+            *sample /= peak;
+        }
+    }
+}
+
+/// Smooths `count` samples starting at `start`. Indexes past the end of the
+/// waveform wrap around to the start, like the neighbors of each sample.
+fn smooth_range(
+    samples: &mut [f32],
+    start: usize,
+    count: usize,
+    radius: usize,
+) {
+    let len = samples.len();
+
+    if radius == 0 || len < 3 {
+        return;
+    }
+
+    let radius = radius.min((len - 1) / 2);
+    let source = samples.to_vec();
+    let window = (2 * radius + 1) as f32;
+
+    for i in (start..start + count.min(len)).map(|i| i % len) {
+        let sum: f32 = (0..=(2 * radius))
+            .map(|offset| source[(i + len + offset - radius) % len])
+            .sum();
+
+        samples[i] = sum / window;
+    }
+}
+
+/// Returns the bounds that a frame of a [`WavetableView`] is drawn in.
+///
+/// * `bounds` - the bounds of the [`WavetableView`]
+/// * `stack_offset` - the offset of the back frame from the front frame,
+/// or `None` if the frames are not drawn as a stack
+/// * `depth` - the position of the frame in the stack, from `0.0` (front)
+/// to `1.0` (back)
+///
+/// [`WavetableView`]: struct.WavetableView.html
+pub fn frame_bounds(
+    bounds: &Rectangle,
+    stack_offset: Option<Vector>,
+    depth: f32,
+) -> Rectangle {
+    let offset = stack_offset.unwrap_or(Vector::new(0.0, 0.0));
+
+    let width = (bounds.width - offset.x.abs()).max(0.0);
+    let height = (bounds.height - offset.y.abs()).max(0.0);
+
+    Rectangle {
+        x: bounds.x + (-offset.x).max(0.0) + (offset.x * depth),
+        y: bounds.y + (-offset.y).max(0.0) + (offset.y * depth),
+        width,
+        height,
+    }
+}
+
+/// Returns the position of a frame in a stack of `num_frames` frames, from
+/// `0.0` (front) to `1.0` (back).
+pub fn frame_depth(frame: usize, num_frames: usize) -> f32 {
+    if num_frames > 1 {
+        frame as f32 / (num_frames - 1) as f32
+    } else {
+        0.0
+    }
+}
+
+/// A view of a single-cycle waveform, or of the frames of a wavetable.
+///
+/// The frames can be drawn as a pseudo-3D stack, with the current frame
+/// highlighted. In draw mode, the current frame can be sketched by dragging
+/// the mouse across it. When the mouse button is released, the sketched
+/// range is smoothed and the frame is normalized, if enabled.
+#[allow(missing_debug_implementations)]
+pub struct WavetableView<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_edit: Box<dyn Fn(usize, Vec<f32>) -> Message>,
+    draw_mode: bool,
+    smoothing: usize,
+    normalize: bool,
+    stack_offset: Option<Vector>,
+    max_stack_frames: usize,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    WavetableView<'a, Message, Renderer>
+{
+    /// Creates a new [`WavetableView`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`WavetableView`]
+    ///   * a function that will be called with the index of the current
+    /// frame and its samples whenever the frame is edited in draw mode
+    ///
+    /// [`State`]: struct.State.html
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn new<F>(state: &'a mut State, on_edit: F) -> Self
+    where
+        F: 'static + Fn(usize, Vec<f32>) -> Message,
+    {
+        WavetableView {
+            state,
+            on_edit: Box::new(on_edit),
+            draw_mode: false,
+            smoothing: 0,
+            normalize: false,
+            stack_offset: None,
+            max_stack_frames: DEFAULT_MAX_STACK_FRAMES,
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`WavetableView`].
+    /// The default width is `Length::from(Length::Units(300))`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WavetableView`].
+    /// The default height is `Length::from(Length::Units(150))`.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WavetableView`].
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the current frame can be sketched with the mouse.
+    ///
+    /// This is `false` by default.
+    pub fn draw_mode(mut self, draw_mode: bool) -> Self {
+        self.draw_mode = draw_mode;
+        self
+    }
+
+    /// Sets the radius (in samples) of the moving average that smooths the
+    /// sketched range when the mouse button is released. The average also
+    /// reaches `radius` samples past each end of the range, so the edit
+    /// blends into the rest of the frame.
+    ///
+    /// The default is `0`, which disables smoothing.
+    pub fn smoothing(mut self, radius: usize) -> Self {
+        self.smoothing = radius;
+        self
+    }
+
+    /// Sets whether the current frame is normalized to a peak of `1.0` when
+    /// the mouse button is released after sketching.
+    ///
+    /// This is `false` by default.
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Draws the frames as a pseudo-3D stack. The first frame is drawn at
+    /// the front, and the last frame is drawn at the back, moved by
+    /// `offset` (in pixels). For example, `Vector::new(40.0, -40.0)` stacks
+    /// the frames up and to the right.
+    ///
+    /// By default only the current frame is drawn.
+    pub fn stack(mut self, offset: Vector) -> Self {
+        self.stack_offset = Some(offset);
+        self
+    }
+
+    /// Sets the maximum number of frames drawn in the stack. When there are
+    /// more frames than this, an evenly spaced subset is drawn. The current
+    /// frame is always drawn.
+    ///
+    /// The default is `64`.
+    pub fn max_stack_frames(mut self, max_stack_frames: usize) -> Self {
+        self.max_stack_frames = max_stack_frames.max(2);
+        self
+    }
+
+    fn current_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        frame_bounds(
+            bounds,
+            self.stack_offset,
+            frame_depth(self.state.current_frame, self.state.frames.len()),
+        )
+    }
+
+    fn push_edit(&self, messages: &mut Vec<Message>) {
+        let index = self.state.current_frame;
+
+        messages.push((self.on_edit)(index, self.state.frames[index].clone()));
+    }
+}
+
+/// Returns the index of the sample and the value under `cursor_position` in
+/// a frame of `len` samples.
+fn sample_at(
+    frame_bounds: &Rectangle,
+    len: usize,
+    cursor_position: Point,
+) -> (usize, f32) {
+    let normal_x = ((cursor_position.x - frame_bounds.x) / frame_bounds.width)
+        .max(0.0)
+        .min(1.0);
+    let normal_y = ((cursor_position.y - frame_bounds.y) / frame_bounds.height)
+        .max(0.0)
+        .min(1.0);
+
+    let index = (normal_x * (len - 1) as f32).round() as usize;
+
+    (index, 1.0 - (normal_y * 2.0))
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Sketch {
+    last_index: usize,
+    last_value: f32,
+    first_edited: usize,
+    last_edited: usize,
+}
+
+/// The local state of a [`WavetableView`].
+///
+/// [`WavetableView`]: struct.WavetableView.html
+#[derive(Debug, Clone)]
+pub struct State {
+    frames: Vec<Vec<f32>>,
+    current_frame: usize,
+    sketch: Option<Sketch>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let sine = (0..DEFAULT_FRAME_LEN)
+            .map(|i| {
+                (std::f32::consts::PI * 2.0 * i as f32
+                    / DEFAULT_FRAME_LEN as f32)
+                    .sin()
+            })
+            .collect();
+
+        Self::new(vec![sine])
+    }
+}
+
+impl State {
+    /// Creates a new [`WavetableView`] state.
+    ///
+    /// It expects:
+    /// * the frames of the wavetable. Each frame is a single cycle of a
+    /// waveform, with samples from `-1.0` to `1.0`. A single-cycle waveform
+    /// is a wavetable with one frame.
+    ///
+    /// The first frame is the current frame.
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    pub fn new(frames: Vec<Vec<f32>>) -> Self {
+        Self {
+            frames,
+            current_frame: 0,
+            sketch: None,
+        }
+    }
+
+    /// Returns the frames of the wavetable.
+    pub fn frames(&self) -> &[Vec<f32>] {
+        &self.frames
+    }
+
+    /// Replaces the frames of the wavetable. The current frame is moved to
+    /// the last frame if it is out of range.
+    pub fn set_frames(&mut self, frames: Vec<Vec<f32>>) {
+        self.frames = frames;
+        self.current_frame =
+            self.current_frame.min(self.frames.len().saturating_sub(1));
+        self.sketch = None;
+    }
+
+    /// Replaces the samples of a single frame. This does nothing if `index`
+    /// is out of range.
+    pub fn set_frame(&mut self, index: usize, samples: Vec<f32>) {
+        if let Some(frame) = self.frames.get_mut(index) {
+            *frame = samples;
+
+            if index == self.current_frame {
+                self.sketch = None;
+            }
+        }
+    }
+
+    /// Returns the index of the current frame.
+    pub fn current_frame(&self) -> usize {
+        self.current_frame
+    }
+
+    /// Sets the index of the current frame. It is clamped to the range of
+    /// frames.
+    pub fn set_current_frame(&mut self, index: usize) {
+        self.current_frame = index.min(self.frames.len().saturating_sub(1));
+        self.sketch = None;
+    }
+
+    /// Returns the samples of the current frame, if there are any frames.
+    pub fn current_samples(&self) -> Option<&[f32]> {
+        self.frames.get(self.current_frame).map(|f| f.as_slice())
+    }
+
+    /// Is the current frame being sketched with the mouse?
+    pub fn is_drawing(&self) -> bool {
+        self.sketch.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WavetableView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if !self.draw_mode {
+            return event::Status::Ignored;
+        }
+
+        let frame_bounds = self.current_bounds(&layout.bounds());
+
+        let len = match self.state.current_samples() {
+            Some(samples) => samples.len(),
+            None => return event::Status::Ignored,
+        };
+
+        if len == 0 || frame_bounds.width <= 0.0 || frame_bounds.height <= 0.0 {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Mouse(mouse_event) = event {
+            match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(mut sketch) = self.state.sketch {
+                        let (index, value) =
+                            sample_at(&frame_bounds, len, cursor_position);
+
+                        let samples =
+                            &mut self.state.frames[self.state.current_frame];
+
+                        // Fill in every sample between the previous and the
+                        // current position, so fast movements leave no gaps.
+                        let (from, to, from_value, to_value) = if index
+                            >= sketch.last_index
+                        {
+                            (sketch.last_index, index, sketch.last_value, value)
+                        } else {
+                            (index, sketch.last_index, value, sketch.last_value)
+                        };
+
+                        let span = (to - from) as f32;
+                        for (i, sample) in
+                            samples[from..=to].iter_mut().enumerate()
+                        {
+                            let amount =
+                                if span > 0.0 { i as f32 / span } else { 1.0 };
+
+                            *sample =
+                                from_value + ((to_value - from_value) * amount);
+                        }
+
+                        sketch.last_index = index;
+                        sketch.last_value = value;
+                        sketch.first_edited = sketch.first_edited.min(from);
+                        sketch.last_edited = sketch.last_edited.max(to);
+
+                        self.state.sketch = Some(sketch);
+
+                        self.push_edit(messages);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    if frame_bounds.contains(cursor_position) {
+                        let (index, value) =
+                            sample_at(&frame_bounds, len, cursor_position);
+
+                        self.state.frames[self.state.current_frame][index] =
+                            value;
+
+                        self.state.sketch = Some(Sketch {
+                            last_index: index,
+                            last_value: value,
+                            first_edited: index,
+                            last_edited: index,
+                        });
+
+                        self.push_edit(messages);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if let Some(sketch) = self.state.sketch.take() {
+                        if self.smoothing > 0 || self.normalize {
+                            let samples = &mut self.state.frames
+                                [self.state.current_frame];
+
+                            if self.smoothing > 0 {
+                                let radius = self.smoothing.min(len);
+
+                                // Smooth a little past each end of the edit
+                                // so that it blends into the rest of the
+                                // frame.
+                                smooth_range(
+                                    samples,
+                                    (sketch.first_edited + len - radius) % len,
+                                    sketch.last_edited - sketch.first_edited
+                                        + 1
+                                        + (2 * radius),
+                                    radius,
+                                );
+                            }
+
+                            if self.normalize {
+                                normalize(samples);
+                            }
+
+                            self.push_edit(messages);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &self.state.frames,
+            self.state.current_frame,
+            self.stack_offset,
+            self.max_stack_frames,
+            self.state.is_drawing(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`WavetableView`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`WavetableView`] in your user interface.
+///
+/// [`WavetableView`]: struct.WavetableView.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`WavetableView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`WavetableView`]
+    ///   * the current cursor position
+    ///   * the frames of the wavetable
+    ///   * the index of the current frame
+    ///   * the offset of the back frame of the stack, if the frames are
+    /// drawn as a stack
+    ///   * the maximum number of frames drawn in the stack
+    ///   * whether the current frame is being sketched
+    ///   * the style of the [`WavetableView`]
+    ///
+    /// [`WavetableView`]: struct.WavetableView.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        frames: &[Vec<f32>],
+        current_frame: usize,
+        stack_offset: Option<Vector>,
+        max_stack_frames: usize,
+        is_drawing: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<WavetableView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wavetable_view: WavetableView<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wavetable_view)
+    }
+}
@@ -0,0 +1,125 @@
+//! Various styles for the [`ADSREnvelope`] widget
+//!
+//! [`ADSREnvelope`]: ../native/adsr_envelope/struct.ADSREnvelope.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the envelope lines
+    pub line_width: f32,
+    /// The color of the envelope lines
+    pub line_color: Color,
+    /// The color of the line whose curve is hovered or being dragged
+    pub active_line_color: Color,
+    /// The color of the area under the envelope lines. Set this to
+    /// `Color::TRANSPARENT` to disable the fill.
+    pub fill_color: Color,
+    /// The style of the handles
+    pub handle: HandleStyle,
+    /// The style of the handle that is hovered or being dragged
+    pub active_handle: HandleStyle,
+}
+
+/// The appearance of a handle in the [`Style`] of an [`ADSREnvelope`]
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+/// [`Style`]: struct.Style.html
+#[derive(Debug, Clone)]
+pub struct HandleStyle {
+    /// The color of the circle
+    pub color: Color,
+    /// The diameter of the circle
+    pub diameter: f32,
+    /// The width of the border of the circle
+    pub border_width: f32,
+    /// The color of the border of the circle
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of an [`ADSREnvelope`].
+///
+/// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ADSREnvelope`].
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of an [`ADSREnvelope`] that is being dragged.
+    ///
+    /// [`ADSREnvelope`]: ../../native/adsr_envelope/struct.ADSREnvelope.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 2.0,
+        line_color: default_colors::BORDER,
+        active_line_color: default_colors::ENVELOPE_ACTIVE,
+        fill_color: default_colors::ENVELOPE_FILL,
+        handle: HandleStyle {
+            color: default_colors::LIGHT_BACK,
+            diameter: 9.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        active_handle: HandleStyle {
+            color: default_colors::ENVELOPE_ACTIVE,
+            diameter: 11.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -50,6 +50,8 @@ pub const TEXT_MARK: Color = Color {
 
 pub const KNOB_BACK_HOVER: Color = Color::from_rgb(0.96, 0.96, 0.96);
 
+pub const MODULATION: Color = Color::from_rgb(0.96, 0.55, 0.16);
+
 pub const RAMP_BACK_HOVER: Color = Color::from_rgb(0.95, 0.95, 0.95);
 
 pub const XY_PAD_RAIL: Color = Color {
@@ -65,6 +67,133 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     a: 0.5,
 };
 
+pub const RANGE_SLIDER_FILLED: Color = Color::from_rgb(0.5, 0.67, 0.9);
+pub const RANGE_SLIDER_FILLED_HOVER: Color = Color::from_rgb(0.55, 0.72, 0.95);
+
+pub const ENVELOPE_ACTIVE: Color = Color::from_rgb(0.5, 0.67, 0.9);
+pub const ENVELOPE_GRID: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.3,
+};
+pub const ENVELOPE_LOOP: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.15,
+};
+pub const ENVELOPE_LOOP_LINE: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.8,
+};
+pub const ENVELOPE_FILL: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.2,
+};
+pub const ENVELOPE_SUSTAIN_LINE: Color = Color {
+    r: 0.9,
+    g: 0.6,
+    b: 0.3,
+    a: 0.8,
+};
+
+pub const KEYS_WHITE: Color = Color::from_rgb(0.95, 0.95, 0.95);
+pub const KEYS_BLACK: Color = Color::from_rgb(0.15, 0.15, 0.15);
+pub const KEYS_WHITE_PRESSED: Color = Color::from_rgb(0.72, 0.8, 0.93);
+pub const KEYS_BLACK_PRESSED: Color = Color::from_rgb(0.3, 0.42, 0.6);
+pub const KEYS_HIGHLIGHT: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.6,
+};
+pub const KEYS_SHADE: Color = Color {
+    r: 0.9,
+    g: 0.6,
+    b: 0.3,
+    a: 0.25,
+};
+
+pub const MOD_WHEEL_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const MOD_WHEEL: Color = Color::from_rgb(0.55, 0.55, 0.55);
+pub const MOD_WHEEL_HOVER: Color = Color::from_rgb(0.6, 0.6, 0.6);
+pub const MOD_WHEEL_RIDGE: Color = Color::from_rgb(0.38, 0.38, 0.38);
+pub const MOD_WHEEL_NOTCH: Color = Color::from_rgb(0.95, 0.95, 0.95);
+pub const MOD_WHEEL_SHADE: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.18,
+};
+
+pub const PAR_EQ_BAND_LINE: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.6,
+};
+pub const PAR_EQ_SPECTRUM: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.2,
+};
+
+pub const WAVEFORM: Color = Color::from_rgb(0.3, 0.45, 0.7);
+pub const WAVEFORM_RMS: Color = Color::from_rgb(0.45, 0.6, 0.85);
+pub const WAVEFORM_SELECTION: Color = Color {
+    r: 0.5,
+    g: 0.67,
+    b: 0.9,
+    a: 0.3,
+};
+pub const WAVEFORM_LOOP: Color = Color {
+    r: 0.9,
+    g: 0.72,
+    b: 0.3,
+    a: 0.15,
+};
+pub const WAVEFORM_LOOP_MARKER: Color = Color::from_rgb(0.85, 0.62, 0.2);
+pub const WAVEFORM_PLAYHEAD: Color = Color::from_rgb(0.9, 0.3, 0.25);
+pub const WAVEFORM_OUTSIDE: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.12,
+};
+
+pub const GONIOMETER_DOT: Color = Color::from_rgb(0.45, 0.85, 0.55);
+pub const GONIOMETER_GRID: Color = Color {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 0.3,
+};
+
+pub const PHASE_METER_BACK: Color = Color::from_rgb(0.35, 0.35, 0.35);
+pub const PHASE_METER_POSITIVE: Color = Color::from_rgb(0.435, 0.886, 0.11);
+pub const PHASE_METER_NEGATIVE: Color = Color::from_rgb(1.0, 0.071, 0.071);
+pub const PHASE_METER_BALANCE: Color = Color::from_rgb(0.3, 0.6, 0.95);
+pub const PHASE_METER_CENTER_LINE: Color = Color::from_rgb(0.92, 0.92, 0.92);
+
+pub const WAVETABLE_FILL: Color = Color {
+    r: 0.3,
+    g: 0.55,
+    b: 0.9,
+    a: 0.2,
+};
+pub const WAVETABLE_STACK: Color = Color {
+    r: 0.3,
+    g: 0.3,
+    b: 0.3,
+    a: 0.35,
+};
+
 /*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
 pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
@@ -79,5 +208,4 @@ pub const DB_METER_CLIP_MARKER: Color = Color {
     a: 0.28,
 };
 pub const DB_METER_GAP: Color = Color::from_rgb(0.25, 0.25, 0.25);
-pub const PHASE_METER_CENTER_LINE: Color = Color::from_rgb(0.92, 0.92, 0.92);
 */
@@ -0,0 +1,109 @@
+//! Drop shadows, inner shadows, and glows for widget styles
+//!
+//! Style fields of these types are drawn from layers of translucent
+//! primitives around (or inside) the shape they belong to.
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::style::effects::{Glow, GlowIntensity};
+//! use iced_audio::Normal;
+//! use iced_native::Color;
+//!
+//! // A glow that is faint at low values, and bright near the top.
+//! let glow = Glow {
+//!     color: Color::from_rgb(0.3, 0.7, 1.0),
+//!     width: 6.0,
+//!     intensity: GlowIntensity::Value { min: 0.2, max: 1.0 },
+//! };
+//!
+//! assert_eq!(glow.intensity_at(Normal::min()), 0.2);
+//! assert_eq!(glow.intensity_at(Normal::max()), 1.0);
+//! ```
+
+use iced_native::Color;
+
+use crate::core::{Normal, Offset};
+
+/// A soft shadow that is drawn underneath a shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The color of the shadow where it is the darkest
+    pub color: Color,
+    /// The offset of the shadow from the shape
+    pub offset: Offset,
+    /// The width of the soft edge of the shadow in pixels
+    pub blur: f32,
+    /// How far the shadow is expanded past the edges of the shape in pixels
+    /// before the soft edge starts
+    pub spread: f32,
+}
+
+/// A soft shadow that is drawn along the inside of the edges of a shape,
+/// making it look sunken in
+///
+/// The shadow is the same width all around the shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InnerShadow {
+    /// The color of the shadow at the edges of the shape
+    pub color: Color,
+    /// The width of the shadow in pixels
+    pub width: f32,
+}
+
+/// A soft glow that is drawn around a shape
+///
+/// To make a glow follow the dragging state of a widget, return a stronger
+/// glow from the `dragging()` method of its `StyleSheet`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glow {
+    /// The color of the glow at the edges of the shape
+    pub color: Color,
+    /// The width of the glow in pixels
+    pub width: f32,
+    /// The intensity of the glow
+    pub intensity: GlowIntensity,
+}
+
+impl Glow {
+    /// Returns the intensity of the glow from `0.0` to `1.0` at the given
+    /// value of the widget.
+    pub fn intensity_at(&self, value: Normal) -> f32 {
+        let intensity = match self.intensity {
+            GlowIntensity::Fixed(intensity) => intensity,
+            GlowIntensity::Value { min, max } => {
+                min + (max - min) * value.as_f32()
+            }
+            GlowIntensity::Bipolar { min, max } => {
+                min + (max - min) * (value.as_f32() - 0.5).abs() * 2.0
+            }
+        };
+
+        intensity.max(0.0).min(1.0)
+    }
+}
+
+/// The intensity of a [`Glow`], which multiplies the alpha of its color
+///
+/// [`Glow`]: struct.Glow.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlowIntensity {
+    /// The same intensity at every value.
+    Fixed(f32),
+    /// Blends from `min` at the lowest value of the widget to `max` at its
+    /// highest value.
+    Value {
+        /// The intensity at the lowest value
+        min: f32,
+        /// The intensity at the highest value
+        max: f32,
+    },
+    /// Blends from `min` at the center value of the widget to `max` at
+    /// both of its ends.
+    Bipolar {
+        /// The intensity at the center value
+        min: f32,
+        /// The intensity at the lowest and highest values
+        max: f32,
+    },
+}
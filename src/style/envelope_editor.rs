@@ -0,0 +1,139 @@
+//! Various styles for the [`EnvelopeEditor`] widget
+//!
+//! [`EnvelopeEditor`]: ../native/envelope_editor/struct.EnvelopeEditor.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the lines connecting the points
+    pub line_width: f32,
+    /// The color of the lines connecting the points
+    pub line_color: Color,
+    /// The color of the line that is hovered or being dragged
+    pub active_line_color: Color,
+    /// The style of the points
+    pub point: PointStyle,
+    /// The style of the point that is hovered or being dragged
+    pub active_point: PointStyle,
+    /// The width of the time grid lines
+    pub grid_line_width: f32,
+    /// The color of the time grid lines
+    pub grid_line_color: Color,
+    /// The width of the loop and sustain marker lines
+    pub marker_line_width: f32,
+    /// The color of the area between the loop start and end points
+    pub loop_color: Color,
+    /// The color of the loop start and end marker lines
+    pub loop_line_color: Color,
+    /// The color of the sustain marker line
+    pub sustain_line_color: Color,
+}
+
+/// The appearance of a point in the [`Style`] of an [`EnvelopeEditor`]
+///
+/// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+/// [`Style`]: struct.Style.html
+#[derive(Debug, Clone)]
+pub struct PointStyle {
+    /// The color of the circle
+    pub color: Color,
+    /// The diameter of the circle
+    pub diameter: f32,
+    /// The width of the border of the circle
+    pub border_width: f32,
+    /// The color of the border of the circle
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of an [`EnvelopeEditor`].
+///
+/// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`EnvelopeEditor`].
+    ///
+    /// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`EnvelopeEditor`].
+    ///
+    /// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of an [`EnvelopeEditor`] that is being dragged.
+    ///
+    /// [`EnvelopeEditor`]: ../../native/envelope_editor/struct.EnvelopeEditor.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        line_width: 2.0,
+        line_color: default_colors::BORDER,
+        active_line_color: default_colors::ENVELOPE_ACTIVE,
+        point: PointStyle {
+            color: default_colors::LIGHT_BACK,
+            diameter: 9.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        active_point: PointStyle {
+            color: default_colors::ENVELOPE_ACTIVE,
+            diameter: 11.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        grid_line_width: 1.0,
+        grid_line_color: default_colors::ENVELOPE_GRID,
+        marker_line_width: 2.0,
+        loop_color: default_colors::ENVELOPE_LOOP,
+        loop_line_color: default_colors::ENVELOPE_LOOP_LINE,
+        sustain_line_color: default_colors::ENVELOPE_SUSTAIN_LINE,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
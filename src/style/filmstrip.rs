@@ -0,0 +1,58 @@
+//! Styles for widgets drawn from a filmstrip (sprite sheet) texture
+//!
+//! A filmstrip is a single image made of a row or column of equally sized
+//! frames, each of which shows the widget at a different value. This is
+//! the format that most commercial plugin skins are exported in.
+
+use iced_native::image;
+
+use crate::core::Normal;
+
+/// The direction in which the frames of a [`Filmstrip`] are laid out
+///
+/// [`Filmstrip`]: struct.Filmstrip.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    /// The frames are stacked from top to bottom
+    Vertical,
+    /// The frames are placed from left to right
+    Horizontal,
+}
+
+/// A filmstrip (sprite sheet) texture
+///
+/// The first frame is shown at the minimum value, and the last frame is
+/// shown at the maximum value.
+#[derive(Debug, Clone)]
+pub struct Filmstrip {
+    /// The [`Handle`] to the image texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+    pub image_handle: image::Handle,
+    /// The number of frames in the texture
+    pub num_frames: u16,
+    /// The width of a single frame in the texture in pixels
+    pub frame_width: u16,
+    /// The height of a single frame in the texture in pixels
+    pub frame_height: u16,
+    /// The direction in which the frames are laid out
+    pub orientation: Orientation,
+}
+
+impl Filmstrip {
+    /// Returns the index of the frame to show for the given [`Normal`].
+    ///
+    /// The range of the [`Normal`] is evenly divided between the frames,
+    /// and it is rounded to the nearest frame.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn frame_index(&self, normal: Normal) -> u16 {
+        if self.num_frames < 2 {
+            return 0;
+        }
+
+        let last = self.num_frames - 1;
+
+        ((normal.as_f32() * f32::from(last)).round() as u16).min(last)
+    }
+}
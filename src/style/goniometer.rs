@@ -0,0 +1,95 @@
+//! Various styles for the [`Goniometer`] widget
+//!
+//! [`Goniometer`]: ../native/goniometer/struct.Goniometer.html
+
+use iced_graphics::Font;
+use iced_native::Color;
+
+use crate::style::default_colors;
+use crate::style::text_marks;
+
+/// The appearance of a [`Goniometer`].
+///
+/// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the dots in `Mode::Dots`. Older dots fade out from
+    /// this color.
+    pub dot_color: Color,
+    /// The size of the dots in `Mode::Dots`
+    pub dot_size: f32,
+    /// The color of the lines in `Mode::Lines`. Older lines fade out from
+    /// this color.
+    pub line_color: Color,
+    /// The width of the lines in `Mode::Lines`
+    pub line_width: f32,
+    /// The color of the outer circle and of the L, R, M, and S axes
+    pub grid_color: Color,
+    /// The width of the outer circle and of the L, R, M, and S axes
+    pub grid_width: f32,
+    /// The style of the L, R, M, and S labels at the ends of the axes. Set
+    /// this to `None` to hide the labels.
+    pub labels: Option<text_marks::Style>,
+    /// The space between the outer circle and the edge of the background
+    /// rectangle
+    pub padding: f32,
+}
+
+/// A set of rules that dictate the style of a [`Goniometer`].
+///
+/// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+pub trait StyleSheet {
+    /// Produces the style of a [`Goniometer`].
+    ///
+    /// [`Goniometer`]: ../../native/goniometer/struct.Goniometer.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: Color::BLACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        dot_color: default_colors::GONIOMETER_DOT,
+        dot_size: 1.5,
+        line_color: default_colors::GONIOMETER_DOT,
+        line_width: 1.0,
+        grid_color: default_colors::GONIOMETER_GRID,
+        grid_width: 1.0,
+        labels: Some(text_marks::Style {
+            color: default_colors::GONIOMETER_GRID,
+            text_size: 11,
+            font: Font::Default,
+            bounds_width: 14,
+            bounds_height: 14,
+        }),
+        padding: 14.0,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,162 @@
+//! Gradients and value-dependent color ramps for widget styles
+//!
+//! Style fields of the type `Option<Gradient>` draw the [`Gradient`] in
+//! place of the flat color they sit next to when they are set.
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::style::gradient::{ColorRamp, Gradient};
+//! use iced_native::Color;
+//!
+//! // A meter that is green at low values, and turns red near the top.
+//! let ramp = ColorRamp::new(&[
+//!     (0.0, Color::from_rgb(0.2, 0.8, 0.3)),
+//!     (0.7, Color::from_rgb(0.9, 0.8, 0.2)),
+//!     (1.0, Color::from_rgb(0.9, 0.2, 0.2)),
+//! ]);
+//!
+//! assert_eq!(ramp.color_at(0.0), Color::from_rgb(0.2, 0.8, 0.3));
+//! assert_eq!(ramp.color_at(1.0), Color::from_rgb(0.9, 0.2, 0.2));
+//!
+//! let gradient = Gradient::Range(ramp);
+//! ```
+//!
+//! [`Gradient`]: enum.Gradient.html
+
+use iced_native::Color;
+
+/// The maximum number of stops in a [`ColorRamp`]
+///
+/// [`ColorRamp`]: struct.ColorRamp.html
+pub const MAX_STOPS: usize = 8;
+
+/// A list of up to [`MAX_STOPS`] `(position, color)` stops that maps a
+/// position from `0.0` to `1.0` to a color
+///
+/// The stops are stored inline, so that a `ColorRamp` (and the styles that
+/// hold one) can be `Copy`.
+///
+/// [`MAX_STOPS`]: constant.MAX_STOPS.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorRamp {
+    stops: [(f32, Color); MAX_STOPS],
+    len: usize,
+}
+
+impl ColorRamp {
+    /// Creates a new `ColorRamp` from a list of `(position, color)` stops.
+    ///
+    /// The positions must be sorted from `0.0` to `1.0`. Two stops at the
+    /// same position make a hard edge.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are more than [`MAX_STOPS`] stops.
+    ///
+    /// [`MAX_STOPS`]: constant.MAX_STOPS.html
+    pub fn new(stops: &[(f32, Color)]) -> Self {
+        assert!(
+            stops.len() <= MAX_STOPS,
+            "a ColorRamp can have at most {} stops",
+            MAX_STOPS
+        );
+
+        let mut ramp = Self {
+            stops: [(0.0, Color::TRANSPARENT); MAX_STOPS],
+            len: stops.len(),
+        };
+        ramp.stops[..stops.len()].copy_from_slice(stops);
+
+        ramp
+    }
+
+    /// Creates a new `ColorRamp` that blends from `start` to `end`.
+    pub const fn between(start: Color, end: Color) -> Self {
+        let mut stops = [(0.0, Color::TRANSPARENT); MAX_STOPS];
+        stops[0] = (0.0, start);
+        stops[1] = (1.0, end);
+
+        Self { stops, len: 2 }
+    }
+
+    /// Returns the `(position, color)` stops of the ramp.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops[..self.len]
+    }
+
+    /// Returns the color at `position`.
+    ///
+    /// Positions before the first stop and after the last stop take the
+    /// color of that stop.
+    pub fn color_at(&self, position: f32) -> Color {
+        interpolate_stops(self.stops(), position)
+    }
+}
+
+/// A fill whose color changes across a shape, or with the value of a widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A linear gradient across the shape.
+    Linear {
+        /// The direction of the gradient in radians. `0.0` goes from left to
+        /// right, and positive angles turn clockwise.
+        angle: f32,
+        /// The colors of the gradient, from the start to the end
+        ramp: ColorRamp,
+    },
+    /// A radial gradient from the center of the shape (`0.0`) to its
+    /// farthest edge (`1.0`).
+    Radial(ColorRamp),
+    /// Each part of the shape takes the color of the value that it
+    /// represents, such as a meter that is green at low levels and red near
+    /// the top. Shapes that do not represent a range of values, such as the
+    /// body of a knob, are drawn like [`Value`].
+    ///
+    /// [`Value`]: #variant.Value
+    Range(ColorRamp),
+    /// The whole shape takes the color of the current value of the widget.
+    Value(ColorRamp),
+}
+
+pub(crate) fn interpolate_stops(
+    stops: &[(f32, Color)],
+    position: f32,
+) -> Color {
+    match stops.len() {
+        0 => Color::TRANSPARENT,
+        1 => stops[0].1,
+        _ => {
+            if position <= stops[0].0 {
+                return stops[0].1;
+            }
+
+            if position >= stops[stops.len() - 1].0 {
+                return stops[stops.len() - 1].1;
+            }
+
+            for pair in stops.windows(2) {
+                let (start_pos, start) = pair[0];
+                let (end_pos, end) = pair[1];
+
+                if position <= end_pos {
+                    let span = end_pos - start_pos;
+                    let t = if span > 0.0 {
+                        (position - start_pos) / span
+                    } else {
+                        1.0
+                    };
+
+                    return Color {
+                        r: start.r + (end.r - start.r) * t,
+                        g: start.g + (end.g - start.g) * t,
+                        b: start.b + (end.b - start.b) * t,
+                        a: start.a + (end.a - start.a) * t,
+                    };
+                }
+            }
+
+            stops[stops.len() - 1].1
+        }
+    }
+}
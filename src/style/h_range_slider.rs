@@ -0,0 +1,144 @@
+//! Various styles for the [`HRangeSlider`] widget
+//!
+//! [`HRangeSlider`]: ../native/h_range_slider/struct.HRangeSlider.html
+
+use crate::core::Offset;
+use crate::style::{default_colors, text_marks, tick_marks};
+
+pub use crate::style::h_slider::{RectStyle, TextMarksStyle, TickMarksStyle};
+
+/// The appearance of an [`HRangeSlider`].
+///
+/// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+#[derive(Debug, Clone)]
+pub enum Style {
+    /// The same look as the `Rect` style of an [`HSlider`], with a handle
+    /// for each parameter. The region between the first and last handles is
+    /// filled.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    Rect(RectStyle),
+}
+
+/// A set of rules that dictate the style of an [`HRangeSlider`].
+///
+/// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`HRangeSlider`].
+    ///
+    /// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of an [`HRangeSlider`] that is being dragged.
+    ///
+    /// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+    fn dragging(&self) -> Style;
+
+    /// The style of tick marks for an [`HRangeSlider`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of text marks for an [`HRangeSlider`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`HRangeSlider`]: ../../native/h_range_slider/struct.HRangeSlider.html
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        None
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: RectStyle = RectStyle {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: default_colors::BORDER,
+        filled_color: default_colors::RANGE_SLIDER_FILLED,
+        filled_gradient: None,
+        handle_color: default_colors::BORDER,
+        handle_width: 4,
+        handle_filled_gap: 1.0,
+        handle_shadow: None,
+        handle_glow: None,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::Rect(Self::ACTIVE_STYLE)
+    }
+
+    fn hovered(&self) -> Style {
+        Style::Rect(RectStyle {
+            filled_color: default_colors::RANGE_SLIDER_FILLED_HOVER,
+            handle_width: 5,
+            ..Self::ACTIVE_STYLE
+        })
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: tick_marks::Style {
+                tier_1: tick_marks::Shape::Line {
+                    length: 24.0,
+                    width: 2.0,
+                    color: default_colors::TICK_TIER_1,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 22.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_2,
+                },
+                tier_3: tick_marks::Shape::Line {
+                    length: 18.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_3,
+                },
+            },
+            placement: tick_marks::Placement::Center {
+                offset: Offset::ZERO,
+                fill_length: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+        })
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -2,10 +2,12 @@
 //!
 //! [`HSlider`]: ../native/h_slider/struct.HSlider.html
 
-use iced_native::{image, Color, Rectangle};
+use iced_native::{image, svg, Color, Rectangle};
 
 use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::effects::{Glow, InnerShadow, Shadow};
+use crate::style::gradient::Gradient;
+use crate::style::{default_colors, filmstrip, text_marks, tick_marks};
 
 /// The appearance of an [`HSlider`].
 ///
@@ -14,6 +16,10 @@ use crate::style::{default_colors, text_marks, tick_marks};
 pub enum Style {
     /// uses an image texture for the handle
     Texture(TextureStyle),
+    /// drawn from the frames of a filmstrip texture
+    Filmstrip(FilmstripStyle),
+    /// drawn from layers of SVG images
+    Svg(SvgStyle),
     /// modeled after hardware sliders
     Classic(ClassicStyle),
     /// a modern style with a line inside a filled rectangle
@@ -43,6 +49,9 @@ pub struct ClassicRail {
 pub struct TextureStyle {
     /// The rail style
     pub rail: ClassicRail,
+    /// An optional image texture for the rail. If this is set, it is drawn
+    /// instead of `rail`.
+    pub rail_texture: Option<TextureRail>,
     /// The [`Handle`] to the image texture
     pub image_handle: image::Handle,
     /// The effective width of the handle (not including any padding on the texture)
@@ -52,6 +61,113 @@ pub struct TextureStyle {
     pub image_bounds: Rectangle,
 }
 
+/// An image texture for the rail of an [`HSlider`]. The texture is stretched
+/// along the length of the rail.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+#[derive(Debug, Clone)]
+pub struct TextureRail {
+    /// The [`Handle`] to the image texture
+    pub image_handle: image::Handle,
+    /// The width (thickness) of the rail
+    pub width: f32,
+    /// The padding from the rail to the left and right edges of the widget
+    pub rail_padding: f32,
+}
+
+/// A [`Style`] for an [`HSlider`] that is drawn from the frames of a
+/// [`Filmstrip`] texture, such as a rendering of a whole fader. The frame is
+/// picked from the current value of the [`HSlider`], and it is scaled to fit
+/// the size of the [`HSlider`].
+///
+/// [`Style`]: enum.Style.html
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Filmstrip`]: ../filmstrip/struct.Filmstrip.html
+#[derive(Debug, Clone)]
+pub struct FilmstripStyle {
+    /// The filmstrip texture
+    pub filmstrip: filmstrip::Filmstrip,
+    /// The effective width of the handle in a frame of the texture in
+    /// pixels. This is used to line up tick marks, text marks, and
+    /// modulation ranges with the handle.
+    pub handle_width: u16,
+}
+
+/// A [`Style`] for an [`HSlider`] that is drawn from layers of SVG images,
+/// which stay sharp at any size.
+///
+/// The `svg` feature of `iced` must be enabled for the images to be drawn.
+///
+/// The sizes of the layers and the handle are [`StyleLength`]s, so they
+/// can scale with the height of the [`HSlider`]. The handle moves with the
+/// current value.
+///
+/// [`Style`]: enum.Style.html
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`StyleLength`]: enum.StyleLength.html
+#[derive(Debug, Clone)]
+pub struct SvgStyle {
+    /// An optional layer drawn underneath the handle, such as the rail
+    pub background: Option<SvgLayer>,
+    /// The handle that moves with the value of the slider
+    pub handle: SvgHandle,
+    /// An optional layer drawn on top of the handle, such as a lighting
+    /// highlight
+    pub foreground: Option<SvgLayer>,
+}
+
+/// An SVG layer of an [`HSlider`]. It spans the width of the slider, and
+/// it is centered on the slider vertically.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct SvgLayer {
+    /// The [`Handle`] to the SVG image
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/svg/struct.Handle.html
+    pub handle: svg::Handle,
+    /// The height of the layer
+    pub height: StyleLength,
+}
+
+/// The SVG handle of an [`HSlider`], centered on the slider vertically
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct SvgHandle {
+    /// The [`Handle`] to the SVG image
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/svg/struct.Handle.html
+    pub handle: svg::Handle,
+    /// The width of the handle
+    pub width: StyleLength,
+    /// The height of the handle
+    pub height: StyleLength,
+}
+
+/// A length in an [`HSlider`] stylesheet
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub enum StyleLength {
+    /// The height of the slider scaled to this value
+    Scaled(f32),
+    /// Absolute length in pixels
+    Units(f32),
+}
+
+impl StyleLength {
+    /// Returns the length based on the given slider height
+    #[inline]
+    pub fn from_slider_height(&self, slider_height: f32) -> f32 {
+        match self {
+            StyleLength::Scaled(scale) => slider_height * *scale,
+            StyleLength::Units(units) => *units,
+        }
+    }
+}
+
 /// A classic [`Style`] for an [`HSlider`], modeled after hardware sliders
 ///
 /// [`Style`]: enum.Style.html
@@ -85,6 +201,19 @@ pub struct ClassicHandle {
     pub border_width: f32,
     /// color of the background rectangle border
     pub border_color: Color,
+    /// an optional [`Shadow`] underneath the handle
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub shadow: Option<Shadow>,
+    /// an optional [`InnerShadow`] along the inside of the border of the
+    /// handle
+    ///
+    /// [`InnerShadow`]: ../effects/struct.InnerShadow.html
+    pub inner_shadow: Option<InnerShadow>,
+    /// an optional [`Glow`] around the handle
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    pub glow: Option<Glow>,
 }
 
 /// A modern [`Style`] for an [`HSlider`]. It is composed of a background
@@ -104,6 +233,15 @@ pub struct RectStyle {
     pub back_border_color: Color,
     /// color of a filled portion in the background rectangle
     pub filled_color: Color,
+    /// a [`Gradient`] that is drawn in place of `filled_color` when it is
+    /// set. A [`Gradient::Range`] spans the whole rectangle. The corners of
+    /// the filled portion are only rounded when it has a single color. In
+    /// a `HRangeSlider`, a [`Gradient::Value`] follows the highest value.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    /// [`Gradient::Value`]: ../gradient/enum.Gradient.html#variant.Value
+    pub filled_gradient: Option<Gradient>,
     /// color of the handle rectangle
     pub handle_color: Color,
     /// width of the handle rectangle
@@ -111,6 +249,14 @@ pub struct RectStyle {
     /// width of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
+    /// an optional [`Shadow`] underneath the handle
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub handle_shadow: Option<Shadow>,
+    /// an optional [`Glow`] around the handle
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    pub handle_glow: Option<Glow>,
 }
 
 /// A modern [`Style`] for an [`HSlider`]. It is composed of a background
@@ -135,6 +281,14 @@ pub struct RectBipolarStyle {
     /// color of a filled portion in the background
     /// rectangle on the right side of the center
     pub right_filled_color: Color,
+    /// a [`Gradient`] that is drawn in place of `left_filled_color` and
+    /// `right_filled_color` when it is set. A [`Gradient::Range`] spans the
+    /// whole rectangle. The corners of the filled portion are only rounded
+    /// when it has a single color.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    pub filled_gradient: Option<Gradient>,
     /// color of the handle rectangle when it is on the
     /// left side of the center
     pub handle_left_color: Color,
@@ -148,6 +302,14 @@ pub struct RectBipolarStyle {
     /// width of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
+    /// an optional [`Shadow`] underneath the handle
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub handle_shadow: Option<Shadow>,
+    /// an optional [`Glow`] around the handle
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    pub handle_glow: Option<Glow>,
 }
 
 /// The position of a [`ModRangeStyle`] ring for an [`HSlider`]
@@ -208,6 +370,49 @@ pub struct ModRangeStyle {
     /// `start`.
     pub filled_inverse_color: Color,
 }
+
+/// A style for the modulated value of an [`HSlider`]
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub enum ModulationStyle {
+    /// A dot at the modulated value
+    Dot(ModulationDot),
+    /// A thin line across the widget at the modulated value, like a ghost
+    /// of the notch of the handle
+    Notch(ModulationNotch),
+}
+
+/// A dot at the modulated value of an [`HSlider`]
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct ModulationDot {
+    /// The color of the dot
+    pub color: Color,
+    /// The diameter of the dot
+    pub diameter: f32,
+    /// The width of the border
+    pub border_width: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The vertical offset of the dot from the center of the widget
+    pub offset: f32,
+}
+
+/// A line across an [`HSlider`] at the modulated value
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct ModulationNotch {
+    /// The color of the line
+    pub color: Color,
+    /// The width (thickness) of the line
+    pub width: f32,
+    /// The padding from the top and bottom edges of the widget
+    pub edge_padding: f32,
+}
+
 /// Style of tick marks for an [`HSlider`].
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
@@ -278,6 +483,23 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of the modulated value of an [`HSlider`], which is set
+    /// with `modulation()`
+    ///
+    /// The default is a dot in the center of the widget. For no modulated
+    /// value, set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn modulation_style(&self) -> Option<ModulationStyle> {
+        Some(ModulationStyle::Dot(ModulationDot {
+            color: default_colors::MODULATION,
+            diameter: 6.0,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+            offset: 0.0,
+        }))
+    }
+
     /// The style of text marks for an [`HSlider`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -304,6 +526,9 @@ impl Default {
             border_radius: 2.0,
             border_color: default_colors::BORDER,
             border_width: 1.0,
+            shadow: None,
+            inner_shadow: None,
+            glow: None,
         },
     };
 }
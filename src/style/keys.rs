@@ -0,0 +1,104 @@
+//! Various styles for the [`Keys`] widget
+//!
+//! [`Keys`]: ../native/keys/struct.Keys.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a single key of [`Keys`].
+///
+/// [`Keys`]: ../../native/keys/struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KeyStyle {
+    /// The color of the key
+    pub color: Color,
+    /// The width of the border of the key
+    pub border_width: f32,
+    /// The radius of the corners of the key
+    pub border_radius: f32,
+    /// The color of the border of the key
+    pub border_color: Color,
+}
+
+/// The appearance of [`Keys`].
+///
+/// [`Keys`]: ../../native/keys/struct.Keys.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Style {
+    /// The style of a white key
+    pub white_key: KeyStyle,
+    /// The style of a black key
+    pub black_key: KeyStyle,
+    /// The style of a white key that is pressed by the mouse
+    pub pressed_white_key: KeyStyle,
+    /// The style of a black key that is pressed by the mouse
+    pub pressed_black_key: KeyStyle,
+    /// The color drawn over a key that is highlighted, e.g. because its
+    /// note is played by an incoming MIDI message
+    pub highlight_color: Color,
+    /// The color drawn over the keys inside the shaded key range
+    pub shade_color: Color,
+}
+
+/// A set of rules that dictate the style of [`Keys`].
+///
+/// [`Keys`]: ../../native/keys/struct.Keys.html
+pub trait StyleSheet {
+    /// Produces the style of [`Keys`].
+    ///
+    /// [`Keys`]: ../../native/keys/struct.Keys.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        white_key: KeyStyle {
+            color: default_colors::KEYS_WHITE,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        black_key: KeyStyle {
+            color: default_colors::KEYS_BLACK,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        pressed_white_key: KeyStyle {
+            color: default_colors::KEYS_WHITE_PRESSED,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        pressed_black_key: KeyStyle {
+            color: default_colors::KEYS_BLACK_PRESSED,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        highlight_color: default_colors::KEYS_HIGHLIGHT,
+        shade_color: default_colors::KEYS_SHADE,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
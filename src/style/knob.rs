@@ -2,12 +2,16 @@
 //!
 //! [`Knob`]: ../native/knob/struct.Knob.html
 
-use iced_native::Color;
-//use iced_native::image;
+use std::sync::Arc;
+
+use iced_native::{image, svg, Color, Font, Rectangle};
 
 pub use iced_graphics::canvas::LineCap;
 
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::core::{Normal, Offset};
+use crate::style::effects::{Glow, InnerShadow, Shadow};
+use crate::style::gradient::Gradient;
+use crate::style::{default_colors, filmstrip, text_marks, tick_marks};
 use crate::KnobAngleRange;
 
 /// The appearance of a [`Knob`],
@@ -15,7 +19,12 @@ use crate::KnobAngleRange;
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
 pub enum Style {
-    //Texture(TextureStyle),
+    /// A style drawn from layers of image textures
+    Texture(TextureStyle),
+    /// A style drawn from the frames of a filmstrip texture
+    Filmstrip(FilmstripStyle),
+    /// A style drawn from layers of SVG images
+    Svg(SvgStyle),
     /// A classic circular style
     Circle(CircleStyle),
     /// A modern arc style
@@ -25,26 +34,345 @@ pub enum Style {
     ArcBipolar(ArcBipolarStyle),
 }
 
-/*
-/// A [`Style`] for a [`Knob`] that uses an image texture for the knob
+/// A [`Style`] for a [`Knob`] that is drawn from a single image texture
+/// that rotates to the angle of the current value across the
+/// [`KnobAngleRange`], with optional static layers underneath and on top of
+/// it.
+///
+/// To draw a knob from pre-rendered rotated images, use a
+/// [`FilmstripStyle`].
 ///
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
-/// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
+/// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+/// [`FilmstripStyle`]: struct.FilmstripStyle.html
 #[derive(Debug, Clone)]
 pub struct TextureStyle {
-    /// the [`Handle`] to the image texture
-    pub texture: image::Handle,
-    /// the width of the knob, not including padding
-    pub knob_width: u16,
-    /// the height of the knob, not including padding
-    pub knob_height: u16,
-    /// the texture padding around the knob bounding
-    /// rectangle. This is useful when the texture is of a glowing handle or has
-    /// a drop shadow, etc.
-    pub texture_padding: Option<TexturePadding>,
-}
-*/
+    /// An optional static layer drawn underneath everything else, such as
+    /// a panel or a scale printed around the knob
+    pub background: Option<TextureLayer>,
+    /// An optional static layer drawn underneath the knob, such as a drop
+    /// shadow
+    pub shadow: Option<TextureLayer>,
+    /// The image of the knob, which rotates with the value of the knob
+    pub knob: RotatingTexture,
+    /// An optional notch drawn on top of the knob at the angle of the
+    /// value. Use `NotchShape::None` if the image has its own indicator.
+    pub notch: NotchShape,
+    /// An optional static layer drawn on top of the knob and the notch,
+    /// such as a lighting highlight
+    pub highlight: Option<TextureLayer>,
+}
+
+/// A [`Style`] for a [`Knob`] that is drawn from the frames of a
+/// [`Filmstrip`] texture. The frame is picked from the current value of the
+/// [`Knob`], and it is scaled to fit the size of the [`Knob`].
+///
+/// [`Style`]: enum.Style.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Filmstrip`]: ../filmstrip/struct.Filmstrip.html
+#[derive(Debug, Clone)]
+pub struct FilmstripStyle {
+    /// The filmstrip texture
+    pub filmstrip: filmstrip::Filmstrip,
+    /// An optional static layer drawn underneath the frame, such as a drop
+    /// shadow
+    pub shadow: Option<TextureLayer>,
+    /// An optional static layer drawn on top of the frame, such as a
+    /// lighting highlight
+    pub highlight: Option<TextureLayer>,
+}
+
+/// A static image layer of a [`Knob`]
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub struct TextureLayer {
+    /// The [`Handle`] to the image texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+    pub image_handle: image::Handle,
+    /// The bounds of the image texture, where the origin is in the
+    /// center of the knob. This lets a layer such as a shadow extend past
+    /// the edges of the knob.
+    pub image_bounds: Rectangle,
+}
+
+/// An image texture of a [`Knob`] that rotates with the value of the knob
+///
+/// The image should show the knob with its indicator pointing straight up.
+/// It is rotated around its center, so the corners of a square image are
+/// cut off at some angles.
+///
+/// The renderer cannot rotate image textures, so the range of the value is
+/// divided into a number of frames, and every frame is rotated from the
+/// pixels of the image on the CPU when the `RotatingTexture` is created.
+/// This is too slow to do on every draw, so create it once (e.g. when your
+/// application starts) and clone it into the [`Style`] returned by your
+/// [`StyleSheet`]. Clones share the same rotated frames.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::style::knob::RotatingTexture;
+/// use iced_audio::{KnobAngleRange, Normal};
+/// use iced_native::Rectangle;
+///
+/// // A 64 x 64 image centered on the knob, turning in 128 steps.
+/// let texture = RotatingTexture::new(
+///     64,
+///     64,
+///     &vec![255; 64 * 64 * 4],
+///     Rectangle {
+///         x: -32.0,
+///         y: -32.0,
+///         width: 64.0,
+///         height: 64.0,
+///     },
+///     128,
+///     &KnobAngleRange::default(),
+/// );
+///
+/// assert_eq!(texture.frame_index(Normal::min()), 0);
+/// assert_eq!(texture.frame_index(Normal::max()), 127);
+/// ```
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Style`]: enum.Style.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+#[derive(Debug, Clone)]
+pub struct RotatingTexture {
+    frames: Arc<[image::Handle]>,
+    image_bounds: Rectangle,
+}
+
+impl RotatingTexture {
+    /// Creates a new [`RotatingTexture`], rotating all of its frames.
+    ///
+    /// It expects:
+    /// * the width and height of the image in pixels
+    /// * the pixels of the image in BGRA order, like
+    /// [`Handle::from_pixels`]
+    /// * the bounds of the image, where the origin is in the center of the
+    /// knob
+    /// * the number of frames the range of the value is divided into. More
+    /// frames turn more smoothly, but use more memory and take longer to
+    /// create. There are at least `2` frames.
+    /// * the [`KnobAngleRange`] of the knob. This must be the same range
+    /// that the [`StyleSheet`] returns.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the length of `pixels` is not
+    /// `width * height * 4`.
+    ///
+    /// [`RotatingTexture`]: struct.RotatingTexture.html
+    /// [`Handle::from_pixels`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html#method.from_pixels
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    pub fn new(
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+        image_bounds: Rectangle,
+        num_frames: u16,
+        angle_range: &KnobAngleRange,
+    ) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "pixels must hold width * height BGRA pixels"
+        );
+
+        let last = f32::from(num_frames.max(2) - 1);
+        let span = angle_range.max() - angle_range.min();
+
+        let frames = (0..num_frames.max(2))
+            .map(|index| {
+                // The image points straight up when it is not rotated, and
+                // the minimum angle of the range points straight down.
+                let angle = angle_range.min()
+                    + std::f32::consts::PI
+                    + (f32::from(index) / last) * span;
+
+                image::Handle::from_pixels(
+                    width,
+                    height,
+                    rotate_pixels(
+                        width as usize,
+                        height as usize,
+                        pixels,
+                        angle,
+                    ),
+                )
+            })
+            .collect();
+
+        Self {
+            frames,
+            image_bounds,
+        }
+    }
+
+    /// Returns the bounds of the image, where the origin is in the center
+    /// of the knob.
+    pub fn image_bounds(&self) -> Rectangle {
+        self.image_bounds
+    }
+
+    /// Returns the number of frames the range of the value is divided into.
+    pub fn num_frames(&self) -> u16 {
+        self.frames.len() as u16
+    }
+
+    /// Returns the index of the frame to show for the given [`Normal`],
+    /// rounded to the nearest frame.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn frame_index(&self, normal: Normal) -> u16 {
+        let last = self.num_frames() - 1;
+
+        ((normal.as_f32() * f32::from(last)).round() as u16).min(last)
+    }
+
+    /// Returns the rotated image of the frame at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is not less than the number of frames.
+    pub fn frame(&self, index: u16) -> image::Handle {
+        self.frames[usize::from(index)].clone()
+    }
+}
+
+/// Rotates the BGRA pixels of an image clockwise by `angle` radians around
+/// its center, with bilinear filtering.
+fn rotate_pixels(
+    width: usize,
+    height: usize,
+    src: &[u8],
+    angle: f32,
+) -> Vec<u8> {
+    let mut dst = vec![0; src.len()];
+
+    let (sin, cos) = angle.sin_cos();
+    let center_x = width as f32 / 2.0;
+    let center_y = height as f32 / 2.0;
+
+    // Returns a pixel with premultiplied alpha, or transparent outside of
+    // the image.
+    let sample = |x: isize, y: isize| -> [f32; 4] {
+        if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+            return [0.0; 4];
+        }
+
+        let i = (y as usize * width + x as usize) * 4;
+        let a = f32::from(src[i + 3]) / 255.0;
+
+        [
+            f32::from(src[i]) * a,
+            f32::from(src[i + 1]) * a,
+            f32::from(src[i + 2]) * a,
+            a,
+        ]
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f32 + 0.5 - center_x;
+            let dy = y as f32 + 0.5 - center_y;
+
+            // Rotate the destination pixel back to find it in the source.
+            let src_x = dx * cos + dy * sin + center_x - 0.5;
+            let src_y = -dx * sin + dy * cos + center_y - 0.5;
+
+            let x0 = src_x.floor();
+            let y0 = src_y.floor();
+            let fx = src_x - x0;
+            let fy = src_y - y0;
+            let (x0, y0) = (x0 as isize, y0 as isize);
+
+            let top_left = sample(x0, y0);
+            let top_right = sample(x0 + 1, y0);
+            let bottom_left = sample(x0, y0 + 1);
+            let bottom_right = sample(x0 + 1, y0 + 1);
+
+            let mut pixel = [0.0; 4];
+            for c in 0..4 {
+                let top = top_left[c] + (top_right[c] - top_left[c]) * fx;
+                let bottom =
+                    bottom_left[c] + (bottom_right[c] - bottom_left[c]) * fx;
+                pixel[c] = top + (bottom - top) * fy;
+            }
+
+            let i = (y * width + x) * 4;
+            let alpha = pixel[3];
+
+            if alpha > 0.0 {
+                for c in 0..3 {
+                    dst[i + c] = (pixel[c] / alpha).round().min(255.0) as u8;
+                }
+                dst[i + 3] = (alpha * 255.0).round().min(255.0) as u8;
+            }
+        }
+    }
+
+    dst
+}
+
+/// A [`Style`] for a [`Knob`] that is drawn from layers of SVG images,
+/// which stay sharp at any size.
+///
+/// The `svg` feature of `iced` must be enabled for the images to be drawn.
+///
+/// The renderer cannot rotate SVG images, so the indicator does not turn.
+/// Instead, it is moved around the center of the knob to the angle of the
+/// current value across the [`KnobAngleRange`]. This works best for round
+/// indicators such as dots.
+///
+/// [`Style`]: enum.Style.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+#[derive(Debug, Clone)]
+pub struct SvgStyle {
+    /// An optional layer drawn underneath the indicator, such as the body
+    /// of the knob
+    pub background: Option<SvgLayer>,
+    /// The indicator that moves with the value of the knob
+    pub indicator: SvgIndicator,
+    /// An optional layer drawn on top of the indicator, such as a lighting
+    /// highlight
+    pub foreground: Option<SvgLayer>,
+}
+
+/// A square SVG layer of a [`Knob`], centered on the knob
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub struct SvgLayer {
+    /// The [`Handle`] to the SVG image
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/svg/struct.Handle.html
+    pub handle: svg::Handle,
+    /// The width and height of the layer
+    pub size: StyleLength,
+}
+
+/// The SVG indicator of a [`Knob`]
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub struct SvgIndicator {
+    /// The [`Handle`] to the SVG image
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/svg/struct.Handle.html
+    pub handle: svg::Handle,
+    /// The width and height of the indicator
+    pub size: StyleLength,
+    /// The distance from the center of the knob to the center of the
+    /// indicator
+    pub radius: StyleLength,
+}
 
 /// A length in a [`Knob`] stylesheet
 ///
@@ -117,12 +445,28 @@ pub enum NotchShape {
 pub struct CircleStyle {
     /// The color of the knob
     pub color: Color,
+    /// A [`Gradient`] that is drawn in place of `color` when it is set
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    pub gradient: Option<Gradient>,
     /// The width of the border around the knob
     pub border_width: f32,
     /// The color of the border around the knob
     pub border_color: Color,
     /// The shape of the notch
     pub notch: NotchShape,
+    /// An optional [`Shadow`] underneath the knob
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub shadow: Option<Shadow>,
+    /// An optional [`InnerShadow`] along the inside of the border of the knob
+    ///
+    /// [`InnerShadow`]: ../effects/struct.InnerShadow.html
+    pub inner_shadow: Option<InnerShadow>,
+    /// An optional [`Glow`] around the knob
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    pub glow: Option<Glow>,
 }
 
 /// A modern arc [`Style`] of a [`Knob`]
@@ -137,10 +481,24 @@ pub struct ArcStyle {
     pub empty_color: Color,
     /// The color of the filled portion of the arc
     pub filled_color: Color,
+    /// A [`Gradient`] that is drawn in place of `filled_color` when it is
+    /// set. A [`Gradient::Range`] spans the whole arc.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    pub filled_gradient: Option<Gradient>,
     /// The shape of the notch
     pub notch: NotchShape,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// An optional [`Shadow`] underneath the whole arc
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub shadow: Option<Shadow>,
+    /// An optional [`Glow`] around the filled portion of the arc
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    pub glow: Option<Glow>,
 }
 
 /// A modern arc [`Style`] of a [`Knob`].
@@ -159,6 +517,13 @@ pub struct ArcBipolarStyle {
     pub left_filled_color: Color,
     /// The color of the filled portion to the right of the center
     pub right_filled_color: Color,
+    /// A [`Gradient`] that is drawn in place of `left_filled_color` and
+    /// `right_filled_color` when it is set. A [`Gradient::Range`] spans the
+    /// whole arc.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    pub filled_gradient: Option<Gradient>,
     /// The shape of the notch when in the center position
     pub notch_center: NotchShape,
     /// The shape of the notch when it is to the left and right of the
@@ -166,6 +531,16 @@ pub struct ArcBipolarStyle {
     pub notch_left_right: Option<(NotchShape, NotchShape)>,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// An optional [`Shadow`] underneath the whole arc
+    ///
+    /// [`Shadow`]: ../effects/struct.Shadow.html
+    pub shadow: Option<Shadow>,
+    /// An optional [`Glow`] around the filled portion of the arc. A
+    /// [`GlowIntensity::Bipolar`] grows from the center value.
+    ///
+    /// [`Glow`]: ../effects/struct.Glow.html
+    /// [`GlowIntensity::Bipolar`]: ../effects/enum.GlowIntensity.html#variant.Bipolar
+    pub glow: Option<Glow>,
 }
 
 /// A style for a value arc around a [`Knob`]
@@ -212,6 +587,32 @@ pub struct ModRangeArcStyle {
     pub cap: LineCap,
 }
 
+/// A style for the modulated value of a [`Knob`]
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub enum ModulationStyle {
+    /// A second notch at the modulated angle, drawn on top of the knob
+    Notch(NotchShape),
+    /// An arc around the knob from the value to the modulated value
+    Arc(ModulationArcStyle),
+}
+
+/// An arc around a [`Knob`] from its value to its modulated value
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct ModulationArcStyle {
+    /// The width (thickness) of the arc
+    pub width: f32,
+    /// The offset from the edge of the `Knob` in pixels
+    pub offset: f32,
+    /// The color of the arc
+    pub color: Color,
+    /// The cap at the ends of the arc
+    pub cap: LineCap,
+}
+
 /// Style of tick marks for a [`Knob`].
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -242,6 +643,12 @@ pub struct TextMarksStyle {
     ///
     /// The default is `-0.75`.
     pub v_offset: f32,
+    /// The orientation and alignment of the labels, and whether to hide
+    /// labels that overlap. This can keep dense labels readable on small
+    /// knobs.
+    ///
+    /// The default keeps every label upright, and draws all of them.
+    pub layout: text_marks::RadialLayout,
 }
 
 impl std::default::Default for TextMarksStyle {
@@ -251,6 +658,54 @@ impl std::default::Default for TextMarksStyle {
             offset: 15.0,
             h_char_offset: 3.0,
             v_offset: -0.75,
+            layout: text_marks::RadialLayout::default(),
+        }
+    }
+}
+
+/// What the text in the center of a [`Knob`] shows
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ValueTextContent {
+    /// The value of the [`Knob`], formatted by the closure set with
+    /// `value_text()`
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    Value,
+    /// The label set with `label()`, such as the name of the parameter. The
+    /// value is shown instead if the [`Knob`] has no label.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    Label,
+}
+
+/// Style of the text in the center of a [`Knob`], such as a readout of its
+/// value.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct ValueTextStyle {
+    /// What the text shows
+    pub content: ValueTextContent,
+    /// The color of the text
+    pub color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    pub font: Font,
+    /// The offset of the text from the center of the knob
+    pub offset: Offset,
+}
+
+impl std::default::Default for ValueTextStyle {
+    fn default() -> Self {
+        Self {
+            content: ValueTextContent::Value,
+            color: default_colors::TEXT_MARK,
+            text_size: 12,
+            font: Font::Default,
+            offset: Offset::ZERO,
         }
     }
 }
@@ -321,6 +776,22 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of the modulated value of a [`Knob`], which is set with
+    /// `modulation()`
+    ///
+    /// The default is an arc around the knob. For no modulated value, set
+    /// this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn modulation_style(&self) -> Option<ModulationStyle> {
+        Some(ModulationStyle::Arc(ModulationArcStyle {
+            width: 2.0,
+            offset: 1.5,
+            color: default_colors::MODULATION,
+            cap: LineCap::Round,
+        }))
+    }
+
     /// The style of text marks around a [`Knob`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -330,12 +801,42 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the text in the center of an active [`Knob`], which is
+    /// set with `value_text()` and `label()`
+    ///
+    /// For no text, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn value_text_style(&self) -> Option<ValueTextStyle> {
+        None
+    }
+
+    /// The style of the text in the center of a hovered [`Knob`]
+    ///
+    /// The default is the same as `value_text_style()`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn value_text_hovered_style(&self) -> Option<ValueTextStyle> {
+        self.value_text_style()
+    }
+
+    /// The style of the text in the center of a [`Knob`] that is being
+    /// dragged
+    ///
+    /// The default is the same as `value_text_hovered_style()`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn value_text_dragging_style(&self) -> Option<ValueTextStyle> {
+        self.value_text_hovered_style()
+    }
 }
 
 struct Default;
 impl Default {
     const ACTIVE_CIRCLE_STYLE: CircleStyle = CircleStyle {
         color: default_colors::LIGHT_BACK,
+        gradient: None,
         border_width: 1.0,
         border_color: default_colors::BORDER,
         notch: NotchShape::Circle(CircleNotch {
@@ -345,6 +846,9 @@ impl Default {
             diameter: StyleLength::Scaled(0.17),
             offset: StyleLength::Scaled(0.15),
         }),
+        shadow: None,
+        inner_shadow: None,
+        glow: None,
     };
 }
 impl StyleSheet for Default {
@@ -390,6 +894,7 @@ impl StyleSheet for Default {
             offset: 14.0,
             h_char_offset: 3.0,
             v_offset: -0.75,
+            layout: text_marks::RadialLayout::default(),
         })
     }
 }
@@ -0,0 +1,118 @@
+//! Various styles for the [`ModWheel`] widget
+//!
+//! [`ModWheel`]: ../native/mod_wheel/struct.ModWheel.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`ModWheel`].
+///
+/// The wheel is drawn as a cylinder seen from the front, sitting in a slot.
+/// Its ridges move around the cylinder as it turns.
+///
+/// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the slot the wheel sits in
+    pub back_color: Color,
+    /// The width of the border of the slot
+    pub back_border_width: f32,
+    /// The radius of the corners of the slot
+    pub back_border_radius: f32,
+    /// The color of the border of the slot
+    pub back_border_color: Color,
+    /// The space (in pixels) between the slot and the wheel
+    pub wheel_padding: f32,
+    /// The color of the wheel
+    pub wheel_color: Color,
+    /// The radius of the corners of the wheel
+    pub wheel_border_radius: f32,
+    /// The color of the ridges on the wheel
+    pub ridge_color: Color,
+    /// The thickness of a ridge (in pixels) where it faces the viewer
+    pub ridge_width: f32,
+    /// The angle (in degrees) between two ridges around the wheel
+    pub ridge_spacing: f32,
+    /// The color of the notch that marks the position of the wheel
+    pub notch_color: Color,
+    /// The thickness of the notch (in pixels) where it faces the viewer
+    pub notch_width: f32,
+    /// The color drawn over the top and bottom of the wheel to make it look
+    /// round. Set this to `Color::TRANSPARENT` for a flat wheel.
+    pub shade_color: Color,
+    /// How far (in degrees) the wheel turns in each direction from its
+    /// center position
+    pub max_angle: f32,
+}
+
+/// A set of rules that dictate the style of a [`ModWheel`].
+///
+/// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ModWheel`].
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ModWheel`].
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`ModWheel`] that is being dragged.
+    ///
+    /// [`ModWheel`]: ../../native/mod_wheel/struct.ModWheel.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::MOD_WHEEL_BACK,
+        back_border_width: 1.0,
+        back_border_radius: 3.0,
+        back_border_color: default_colors::BORDER,
+        wheel_padding: 3.0,
+        wheel_color: default_colors::MOD_WHEEL,
+        wheel_border_radius: 2.0,
+        ridge_color: default_colors::MOD_WHEEL_RIDGE,
+        ridge_width: 2.0,
+        ridge_spacing: 15.0,
+        notch_color: default_colors::MOD_WHEEL_NOTCH,
+        notch_width: 3.0,
+        shade_color: default_colors::MOD_WHEEL_SHADE,
+        max_angle: 60.0,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            wheel_color: default_colors::MOD_WHEEL_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,138 @@
+//! Various styles for the [`ParEqEditor`] widget
+//!
+//! [`ParEqEditor`]: ../native/par_eq_editor/struct.ParEqEditor.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the line at `0 dB`
+    pub zero_line_width: f32,
+    /// The color of the line at `0 dB`
+    pub zero_line_color: Color,
+    /// The color of the area under the spectrum underlay. Set this to
+    /// `Color::TRANSPARENT` to hide the spectrum.
+    pub spectrum_color: Color,
+    /// The width of the response line of each band
+    pub band_line_width: f32,
+    /// The color of the response line of each band
+    pub band_line_color: Color,
+    /// The color of the response line of the band that is hovered or being
+    /// dragged
+    pub active_band_line_color: Color,
+    /// The width of the combined response line of all bands
+    pub curve_line_width: f32,
+    /// The color of the combined response line of all bands
+    pub curve_line_color: Color,
+    /// The style of the nodes
+    pub node: NodeStyle,
+    /// The style of the node that is hovered or being dragged
+    pub active_node: NodeStyle,
+}
+
+/// The appearance of a node in the [`Style`] of a [`ParEqEditor`]
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+/// [`Style`]: struct.Style.html
+#[derive(Debug, Clone)]
+pub struct NodeStyle {
+    /// The color of the circle
+    pub color: Color,
+    /// The diameter of the circle
+    pub diameter: f32,
+    /// The width of the border of the circle
+    pub border_width: f32,
+    /// The color of the border of the circle
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`ParEqEditor`].
+///
+/// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`ParEqEditor`].
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`ParEqEditor`] that is being dragged.
+    ///
+    /// [`ParEqEditor`]: ../../native/par_eq_editor/struct.ParEqEditor.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        zero_line_width: 1.0,
+        zero_line_color: default_colors::ENVELOPE_GRID,
+        spectrum_color: default_colors::PAR_EQ_SPECTRUM,
+        band_line_width: 1.0,
+        band_line_color: default_colors::PAR_EQ_BAND_LINE,
+        active_band_line_color: default_colors::ENVELOPE_ACTIVE,
+        curve_line_width: 2.0,
+        curve_line_color: default_colors::BORDER,
+        node: NodeStyle {
+            color: default_colors::LIGHT_BACK,
+            diameter: 11.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+        active_node: NodeStyle {
+            color: default_colors::ENVELOPE_ACTIVE,
+            diameter: 13.0,
+            border_width: 2.0,
+            border_color: default_colors::BORDER,
+        },
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
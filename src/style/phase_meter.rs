@@ -0,0 +1,99 @@
+//! Various styles for the [`PhaseMeter`] widget
+//!
+//! [`PhaseMeter`]: ../native/phase_meter/struct.PhaseMeter.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+use crate::style::gradient::Gradient;
+
+/// The appearance of a [`PhaseMeter`].
+///
+/// [`PhaseMeter`]: ../../native/phase_meter/struct.PhaseMeter.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle of each meter
+    pub back_color: Color,
+    /// The width of the border of the background rectangle of each meter
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle of each meter
+    pub back_border_color: Color,
+    /// The color of the bar or needle when the correlation is `0.0` or
+    /// above
+    pub positive_color: Color,
+    /// The color of the bar or needle when the correlation is below `0.0`
+    pub negative_color: Color,
+    /// A [`Gradient`] that is drawn in place of `positive_color` and
+    /// `negative_color` when it is set. A [`Gradient::Range`] spans the
+    /// meter from `-1.0` on the left to `1.0` on the right.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    pub correlation_gradient: Option<Gradient>,
+    /// The color of the bar or needle of the balance meter
+    pub balance_color: Color,
+    /// A [`Gradient`] that is drawn in place of `balance_color` when it is
+    /// set. A [`Gradient::Range`] spans the meter from `-1.0` on the left to
+    /// `1.0` on the right.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    pub balance_gradient: Option<Gradient>,
+    /// The width of the needle in `Mode::Needle`
+    pub needle_width: f32,
+    /// The color of the line in the center of each meter
+    pub center_line_color: Color,
+    /// The width of the line in the center of each meter. Set this to `0.0`
+    /// to hide it.
+    pub center_line_width: f32,
+    /// The space between the correlation meter and the balance meter
+    pub spacing: f32,
+}
+
+/// A set of rules that dictate the style of a [`PhaseMeter`].
+///
+/// [`PhaseMeter`]: ../../native/phase_meter/struct.PhaseMeter.html
+pub trait StyleSheet {
+    /// Produces the style of a [`PhaseMeter`].
+    ///
+    /// [`PhaseMeter`]: ../../native/phase_meter/struct.PhaseMeter.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::PHASE_METER_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        positive_color: default_colors::PHASE_METER_POSITIVE,
+        negative_color: default_colors::PHASE_METER_NEGATIVE,
+        correlation_gradient: None,
+        balance_color: default_colors::PHASE_METER_BALANCE,
+        balance_gradient: None,
+        needle_width: 3.0,
+        center_line_color: default_colors::PHASE_METER_CENTER_LINE,
+        center_line_width: 1.0,
+        spacing: 2.0,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, filmstrip};
 
 /// The appearance of a [`Ramp`],
 ///
@@ -27,6 +27,24 @@ pub struct Style {
     pub line_down_color: Color,
 }
 
+/// A style for a [`Ramp`] that is drawn from the frames of a [`Filmstrip`]
+/// texture instead of a line. The frame is picked from the current value of
+/// the [`Ramp`], and it is scaled to fit the size of the [`Ramp`].
+///
+/// The renderer cannot mirror textures, so each [`RampDirection`] has its
+/// own filmstrip.
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+/// [`Filmstrip`]: ../filmstrip/struct.Filmstrip.html
+/// [`RampDirection`]: ../../native/ramp/enum.RampDirection.html
+#[derive(Debug, Clone)]
+pub struct FilmstripStyle {
+    /// The filmstrip texture of a ramp with the `Up` direction
+    pub up: filmstrip::Filmstrip,
+    /// The filmstrip texture of a ramp with the `Down` direction
+    pub down: filmstrip::Filmstrip,
+}
+
 /// A set of rules that dictate the style of a [`Ramp`].
 ///
 /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
@@ -45,6 +63,38 @@ pub trait StyleSheet {
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn dragging(&self) -> Style;
+
+    /// Produces the [`FilmstripStyle`] of an active [`Ramp`]. If this returns
+    /// `Some`, the filmstrip is drawn instead of the [`Style`].
+    ///
+    /// The default is `None`.
+    ///
+    /// [`FilmstripStyle`]: struct.FilmstripStyle.html
+    /// [`Style`]: struct.Style.html
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn active_filmstrip(&self) -> Option<FilmstripStyle> {
+        None
+    }
+
+    /// Produces the [`FilmstripStyle`] of a hovered [`Ramp`].
+    ///
+    /// The default is the [`FilmstripStyle`] of an active [`Ramp`].
+    ///
+    /// [`FilmstripStyle`]: struct.FilmstripStyle.html
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn hovered_filmstrip(&self) -> Option<FilmstripStyle> {
+        self.active_filmstrip()
+    }
+
+    /// Produces the [`FilmstripStyle`] of a [`Ramp`] that is being dragged.
+    ///
+    /// The default is the [`FilmstripStyle`] of a hovered [`Ramp`].
+    ///
+    /// [`FilmstripStyle`]: struct.FilmstripStyle.html
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn dragging_filmstrip(&self) -> Option<FilmstripStyle> {
+        self.hovered_filmstrip()
+    }
 }
 
 struct Default;
@@ -0,0 +1,109 @@
+//! Various styles for the [`Spectrogram`] widget
+//!
+//! [`Spectrogram`]: ../native/spectrogram/struct.Spectrogram.html
+
+use iced_native::Color;
+
+use crate::core::Normal;
+use crate::style::default_colors;
+use crate::style::gradient::interpolate_stops;
+
+/// A gradient that maps a magnitude to a color.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorMap {
+    /// From black to white.
+    Grayscale,
+    /// From black through purple and orange to pale yellow.
+    Magma,
+    /// A custom gradient made of `(position, color)` stops. The positions
+    /// must be sorted from `0.0` to `1.0`.
+    Gradient(Vec<(f32, Color)>),
+}
+
+impl std::default::Default for ColorMap {
+    fn default() -> Self {
+        ColorMap::Magma
+    }
+}
+
+const GRAYSCALE_STOPS: [(f32, Color); 2] = [
+    (0.0, Color::from_rgb(0.0, 0.0, 0.0)),
+    (1.0, Color::from_rgb(1.0, 1.0, 1.0)),
+];
+
+const MAGMA_STOPS: [(f32, Color); 6] = [
+    (0.0, Color::from_rgb(0.001, 0.0, 0.014)),
+    (0.2, Color::from_rgb(0.197, 0.063, 0.4)),
+    (0.4, Color::from_rgb(0.445, 0.123, 0.506)),
+    (0.6, Color::from_rgb(0.716, 0.215, 0.475)),
+    (0.8, Color::from_rgb(0.967, 0.441, 0.36)),
+    (1.0, Color::from_rgb(0.987, 0.991, 0.749)),
+];
+
+impl ColorMap {
+    /// Returns the color for the given magnitude.
+    pub fn color_at(&self, normal: Normal) -> Color {
+        let stops: &[(f32, Color)] = match self {
+            ColorMap::Grayscale => &GRAYSCALE_STOPS,
+            ColorMap::Magma => &MAGMA_STOPS,
+            ColorMap::Gradient(stops) => stops,
+        };
+
+        interpolate_stops(stops, normal.as_f32())
+    }
+}
+
+/// The appearance of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The gradient that maps each magnitude to a color
+    pub color_map: ColorMap,
+}
+
+/// A set of rules that dictate the style of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub trait StyleSheet {
+    /// Produces the style of a [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: Color::BLACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        color_map: ColorMap::Magma,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -112,3 +112,82 @@ impl std::default::Default for Style {
         }
     }
 }
+
+/// The direction that the text marks around an arc read in
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    /// Each label reads left to right, no matter where it is on the arc.
+    Upright,
+    /// Each label follows the arc. Labels on the bottom half of the arc are
+    /// flipped so that they still read left to right.
+    ///
+    /// iced cannot rotate text, so the label is drawn one character at a
+    /// time along the arc, and each character stays upright. This reads well
+    /// for short labels such as numbers, but at the sides of the arc the
+    /// characters of a longer label are stacked on top of each other.
+    Tangential,
+    /// Each label points away from the center of the arc. Labels on the left
+    /// half of the arc are flipped so that they still read left to right.
+    ///
+    /// Like `Tangential`, the label is drawn one character at a time, and
+    /// each character stays upright. The characters are stacked along a
+    /// line, so this is harder to read than `Upright`, and is best kept for
+    /// labels of one or two characters.
+    Radial,
+}
+
+/// The alignment of each text mark around an arc, relative to its position
+///
+/// The alignment of a single text mark can be overridden with
+/// `Group::with_radial_align`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RadialAlign {
+    /// Each label is centered on its position.
+    Center,
+    /// Each label starts at its position and extends towards the end of the
+    /// arc, or away from the center of the arc if it is
+    /// `Orientation::Radial`.
+    Start,
+    /// Each label ends at its position and extends towards the start of the
+    /// arc, or towards the center of the arc if it is
+    /// `Orientation::Radial`.
+    End,
+    /// Labels at the start of the arc are aligned to `Start`, labels at the
+    /// end of the arc are aligned to `End`, and the others are centered, so
+    /// that no label hangs past the ends of the arc.
+    Edges,
+}
+
+/// The layout of text marks around an arc, such as the text marks of a
+/// `Knob`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RadialLayout {
+    /// The direction that the labels read in
+    pub orientation: Orientation,
+    /// The alignment of each label relative to its position, unless it is
+    /// overridden for a label with `Group::with_radial_align`. This is
+    /// ignored by `Orientation::Upright`.
+    pub align: RadialAlign,
+    /// Whether to hide labels that would overlap a label that is already
+    /// drawn. Labels that come first in the `Group` are drawn first.
+    pub hide_overlapping: bool,
+    /// The estimated width of one character in pixels. This is used to lay
+    /// out the characters of a rotated label, and to find overlapping
+    /// labels.
+    pub char_width: f32,
+    /// The smallest gap in pixels between two labels when
+    /// `hide_overlapping` is set
+    pub spacing: f32,
+}
+
+impl std::default::Default for RadialLayout {
+    fn default() -> Self {
+        Self {
+            orientation: Orientation::Upright,
+            align: RadialAlign::Center,
+            hide_overlapping: false,
+            char_width: 6.5,
+            spacing: 2.0,
+        }
+    }
+}
@@ -0,0 +1,779 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use iced_native::Color;
+
+use super::{
+    KnobShape, KnobTheme, Metrics, NotchKind, Palette, SliderTheme, Theme,
+};
+use crate::core::Offset;
+use crate::style::{text_marks, tick_marks};
+
+/// An error in the text of a theme file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The line of the error, starting at `1`
+    pub line: usize,
+    /// The column of the error in characters, starting at `1`
+    pub column: usize,
+    /// A description of the error
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An error while loading a theme file
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The text of the file is not a valid theme
+    Parse(ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(error) => {
+                write!(f, "could not read theme file: {}", error)
+            }
+            LoadError::Parse(error) => {
+                write!(f, "invalid theme file: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(error) => Some(error),
+            LoadError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for LoadError {
+    fn from(error: std::io::Error) -> Self {
+        LoadError::Io(error)
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(error: ParseError) -> Self {
+        LoadError::Parse(error)
+    }
+}
+
+impl Theme {
+    /// Loads a theme from a theme file.
+    ///
+    /// See the [module documentation] for the format of the file.
+    ///
+    /// [module documentation]: index.html#theme-files
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, LoadError> {
+        let text = std::fs::read_to_string(path)?;
+
+        Ok(text.parse()?)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut theme = Theme::light();
+        let mut section: Option<Section> = None;
+        let mut h_slider = SliderKeys::default();
+        let mut v_slider = SliderKeys::default();
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = index + 1;
+            let trimmed = raw_line.trim();
+
+            if trimmed.is_empty()
+                || trimmed.starts_with('#')
+                || trimmed.starts_with(';')
+            {
+                continue;
+            }
+
+            let indent = raw_line.len() - raw_line.trim_start().len();
+            let column_of =
+                |offset: usize| raw_line[..indent + offset].chars().count() + 1;
+
+            if trimmed.starts_with('[') {
+                if !trimmed.ends_with(']') {
+                    return Err(ParseError {
+                        line,
+                        column: column_of(trimmed.len()),
+                        message: String::from(
+                            "expected `]` at the end of the section header",
+                        ),
+                    });
+                }
+
+                let name = trimmed[1..trimmed.len() - 1].trim();
+
+                section = Some(Section::from_name(name).ok_or_else(|| {
+                    ParseError {
+                        line,
+                        column: column_of(0),
+                        message: format!(
+                            "unknown section `[{}]`, expected one of {}",
+                            name,
+                            Section::NAMES
+                        ),
+                    }
+                })?);
+
+                continue;
+            }
+
+            let equals = trimmed.find('=').ok_or_else(|| ParseError {
+                line,
+                column: column_of(0),
+                message: String::from("expected `key = value`"),
+            })?;
+
+            let key = Key {
+                name: trimmed[..equals].trim(),
+                line,
+                column: column_of(0),
+            };
+
+            if key.name.is_empty() {
+                return Err(key.error("expected a key before `=`"));
+            }
+
+            let after_equals = &trimmed[equals + 1..];
+            let value_offset = equals
+                + 1
+                + (after_equals.len() - after_equals.trim_start().len());
+
+            let value = Value {
+                text: after_equals.trim(),
+                line,
+                column: column_of(value_offset),
+            };
+
+            if value.text.is_empty() {
+                return Err(value.error("expected a value after `=`"));
+            }
+
+            match section {
+                None => match key.name {
+                    "base" => {
+                        theme = value.parse_keyword(&[
+                            ("light", Theme::light()),
+                            ("dark", Theme::dark()),
+                        ])?;
+                    }
+                    _ => return Err(key.unknown(None)),
+                },
+                Some(Section::Palette) => {
+                    parse_palette(&mut theme.palette, &key, &value)?
+                }
+                Some(Section::Metrics) => {
+                    parse_metrics(&mut theme.metrics, &key, &value)?
+                }
+                Some(Section::Knob) => {
+                    parse_knob(&mut theme.knob, &key, &value)?
+                }
+                Some(Section::HSlider) => {
+                    h_slider.parse("h_slider", &key, &value)?
+                }
+                Some(Section::VSlider) => {
+                    v_slider.parse("v_slider", &key, &value)?
+                }
+            }
+        }
+
+        h_slider.apply(&mut theme.h_slider)?;
+        v_slider.apply(&mut theme.v_slider)?;
+
+        Ok(theme)
+    }
+}
+
+/// Watches a theme file for changes, so that a theme can be tweaked without
+/// recompiling.
+///
+/// Call [`poll`] periodically, for example on every tick of a timer
+/// subscription, and apply the theme it returns.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::style::theme::ThemeWatcher;
+///
+/// let path = std::env::temp_dir().join("iced_audio_theme_watcher.theme");
+/// std::fs::write(&path, "base = dark").unwrap();
+///
+/// let mut watcher = ThemeWatcher::new(&path);
+///
+/// // The file is always loaded on the first poll.
+/// assert!(watcher.poll().unwrap().is_ok());
+/// // Nothing has changed since then.
+/// assert!(watcher.poll().is_none());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// [`poll`]: #method.poll
+#[derive(Debug)]
+pub struct ThemeWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    failed: bool,
+}
+
+impl ThemeWatcher {
+    /// Creates a new `ThemeWatcher` for the theme file at `path`.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            modified: None,
+            failed: false,
+        }
+    }
+
+    /// Returns the path of the theme file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the theme file again if it was modified since the last call.
+    ///
+    /// This returns `None` if nothing has changed. The file is always loaded
+    /// on the first call. An error is only returned once, until the file
+    /// changes again.
+    pub fn poll(&mut self) -> Option<Result<Theme, LoadError>> {
+        let modified =
+            match std::fs::metadata(&self.path).and_then(|m| m.modified()) {
+                Ok(modified) => modified,
+                Err(error) => {
+                    self.modified = None;
+
+                    if self.failed {
+                        return None;
+                    }
+
+                    self.failed = true;
+                    return Some(Err(LoadError::Io(error)));
+                }
+            };
+
+        if !self.failed && self.modified == Some(modified) {
+            return None;
+        }
+
+        self.modified = Some(modified);
+        self.failed = false;
+
+        Some(Theme::load(&self.path))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Section {
+    Palette,
+    Metrics,
+    Knob,
+    HSlider,
+    VSlider,
+}
+
+impl Section {
+    const NAMES: &'static str =
+        "`[palette]`, `[metrics]`, `[knob]`, `[h_slider]`, `[v_slider]`";
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "palette" => Some(Section::Palette),
+            "metrics" => Some(Section::Metrics),
+            "knob" => Some(Section::Knob),
+            "h_slider" => Some(Section::HSlider),
+            "v_slider" => Some(Section::VSlider),
+            _ => None,
+        }
+    }
+}
+
+struct Key<'a> {
+    name: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Key<'a> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: String::from(message),
+        }
+    }
+
+    fn unknown(&self, section: Option<&str>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: match section {
+                Some(section) => {
+                    format!("unknown key `{}` in `[{}]`", self.name, section)
+                }
+                None => {
+                    format!("unknown key `{}` outside of a section", self.name)
+                }
+            },
+        }
+    }
+}
+
+struct Value<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Value<'a> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: String::from(message),
+        }
+    }
+
+    fn expected(&self, expected: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: format!("expected {}, found `{}`", expected, self.text),
+        }
+    }
+
+    fn parse_f32(&self) -> Result<f32, ParseError> {
+        self.text
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| self.expected("a number"))
+    }
+
+    fn parse_u16(&self) -> Result<u16, ParseError> {
+        self.text
+            .parse::<u16>()
+            .map_err(|_| self.expected("a whole number from 0 to 65535"))
+    }
+
+    fn parse_bool(&self) -> Result<bool, ParseError> {
+        self.parse_keyword(&[("true", true), ("false", false)])
+    }
+
+    fn parse_keyword<T: Clone>(
+        &self,
+        options: &[(&str, T)],
+    ) -> Result<T, ParseError> {
+        options
+            .iter()
+            .find(|(name, _)| *name == self.text)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                let names: Vec<String> = options
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect();
+
+                self.expected(&format!("one of {}", names.join(", ")))
+            })
+    }
+
+    fn parse_color(&self) -> Result<Color, ParseError> {
+        let expected = "a color such as `#rrggbb` or `#rrggbbaa`";
+
+        let hex = if self.text.starts_with('#') {
+            &self.text[1..]
+        } else {
+            return Err(self.expected(expected));
+        };
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.expected(expected));
+        }
+
+        let digits: Vec<u8> = hex
+            .chars()
+            .map(|c| c.to_digit(16).unwrap_or(0) as u8)
+            .collect();
+
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits.iter().map(|d| d * 17).collect(),
+            6 | 8 => digits.chunks(2).map(|d| (d[0] * 16) + d[1]).collect(),
+            _ => return Err(self.expected(expected)),
+        };
+
+        Ok(Color {
+            r: f32::from(channels[0]) / 255.0,
+            g: f32::from(channels[1]) / 255.0,
+            b: f32::from(channels[2]) / 255.0,
+            a: channels.get(3).map_or(1.0, |a| f32::from(*a) / 255.0),
+        })
+    }
+
+    /// Splits a value of the form `a, b` into its two parts.
+    fn split_pair(&self, expected: &str) -> Result<(Self, Self), ParseError> {
+        let comma =
+            self.text.find(',').ok_or_else(|| self.expected(expected))?;
+
+        let part = |start: usize, end: usize| {
+            let slice = &self.text[start..end];
+            let leading = slice.len() - slice.trim_start().len();
+
+            Value {
+                text: slice.trim(),
+                line: self.line,
+                column: self.column
+                    + self.text[..start + leading].chars().count(),
+            }
+        };
+
+        let first = part(0, comma);
+        let second = part(comma + 1, self.text.len());
+
+        if first.text.is_empty() || second.text.is_empty() {
+            return Err(self.expected(expected));
+        }
+
+        Ok((first, second))
+    }
+
+    fn parse_color_pair(&self) -> Result<(Color, Color), ParseError> {
+        let (first, second) =
+            self.split_pair("two colors such as `#000000, #ffffff`")?;
+
+        Ok((first.parse_color()?, second.parse_color()?))
+    }
+
+    fn parse_offset(&self) -> Result<Offset, ParseError> {
+        let (x, y) = self.split_pair("an offset such as `0.0, 7.0`")?;
+
+        Ok(Offset {
+            x: x.parse_f32()?,
+            y: y.parse_f32()?,
+        })
+    }
+}
+
+fn parse_palette(
+    palette: &mut Palette,
+    key: &Key<'_>,
+    value: &Value<'_>,
+) -> Result<(), ParseError> {
+    let color = match key.name {
+        "track" => {
+            palette.track = value.parse_color_pair()?;
+            return Ok(());
+        }
+        "background" => &mut palette.background,
+        "background_hover" => &mut palette.background_hover,
+        "background_drag" => &mut palette.background_drag,
+        "border" => &mut palette.border,
+        "handle" => &mut palette.handle,
+        "accent" => &mut palette.accent,
+        "accent_inverse" => &mut palette.accent_inverse,
+        "empty" => &mut palette.empty,
+        "center_line" => &mut palette.center_line,
+        "tick_tier_1" => &mut palette.tick_tier_1,
+        "tick_tier_2" => &mut palette.tick_tier_2,
+        "tick_tier_3" => &mut palette.tick_tier_3,
+        "text" => &mut palette.text,
+        "modulation" => &mut palette.modulation,
+        _ => return Err(key.unknown(Some("palette"))),
+    };
+
+    *color = value.parse_color()?;
+
+    Ok(())
+}
+
+fn parse_metrics(
+    metrics: &mut Metrics,
+    key: &Key<'_>,
+    value: &Value<'_>,
+) -> Result<(), ParseError> {
+    let length = match key.name {
+        "handle_length" => {
+            metrics.handle_length = value.parse_u16()?;
+            return Ok(());
+        }
+        "text_size" => {
+            metrics.text_size = value.parse_u16()?;
+            return Ok(());
+        }
+        "border_width" => &mut metrics.border_width,
+        "border_radius" => &mut metrics.border_radius,
+        "rail_width" => &mut metrics.rail_width,
+        "rail_padding" => &mut metrics.rail_padding,
+        "notch_width" => &mut metrics.notch_width,
+        "xy_handle_diameter" => &mut metrics.xy_handle_diameter,
+        "line_width" => &mut metrics.line_width,
+        "mod_range_width" => &mut metrics.mod_range_width,
+        "modulation_size" => &mut metrics.modulation_size,
+        _ => return Err(key.unknown(Some("metrics"))),
+    };
+
+    *length = value.parse_f32()?;
+
+    Ok(())
+}
+
+fn parse_knob(
+    knob: &mut KnobTheme,
+    key: &Key<'_>,
+    value: &Value<'_>,
+) -> Result<(), ParseError> {
+    match key.name {
+        "shape" => {
+            knob.shape = value.parse_keyword(&[
+                ("circle", KnobShape::Circle),
+                ("arc", KnobShape::Arc),
+            ])?
+        }
+        "notch" => {
+            knob.notch = value.parse_keyword(&[
+                ("none", NotchKind::None),
+                ("circle", NotchKind::Circle),
+                ("line", NotchKind::Line),
+            ])?
+        }
+        "arc_width" => knob.arc_width = value.parse_f32()?,
+        "notch_width" => knob.notch_width = value.parse_f32()?,
+        "notch_length" => knob.notch_length = value.parse_f32()?,
+        "tick_marks_offset" => knob.tick_marks_offset = value.parse_f32()?,
+        _ => return Err(key.unknown(Some("knob"))),
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone)]
+enum PlacementKind {
+    BothSides,
+    LeftOrTop,
+    RightOrBottom,
+    Center,
+    CenterSplit,
+}
+
+const TICK_MARKS_PLACEMENTS: [(&str, PlacementKind); 5] = [
+    ("both_sides", PlacementKind::BothSides),
+    ("left_or_top", PlacementKind::LeftOrTop),
+    ("right_or_bottom", PlacementKind::RightOrBottom),
+    ("center", PlacementKind::Center),
+    ("center_split", PlacementKind::CenterSplit),
+];
+
+const TEXT_MARKS_PLACEMENTS: [(&str, PlacementKind); 4] = [
+    ("both_sides", PlacementKind::BothSides),
+    ("left_or_top", PlacementKind::LeftOrTop),
+    ("right_or_bottom", PlacementKind::RightOrBottom),
+    ("center", PlacementKind::Center),
+];
+
+/// The keys of a `[h_slider]` or `[v_slider]` section
+///
+/// The placements are built once the whole file has been parsed, so that
+/// the keys can be given in any order. A key that does not apply to the
+/// final placement is an error instead of being dropped.
+#[derive(Debug, Default)]
+struct SliderKeys {
+    tick_kind: Option<PlacementKind>,
+    tick_offset: Option<Offset>,
+    tick_inside: Option<bool>,
+    tick_gap: Option<(f32, ParseError)>,
+    text_kind: Option<PlacementKind>,
+    text_offset: Option<Offset>,
+    text_inside: Option<(bool, ParseError)>,
+}
+
+impl SliderKeys {
+    fn parse(
+        &mut self,
+        section: &str,
+        key: &Key<'_>,
+        value: &Value<'_>,
+    ) -> Result<(), ParseError> {
+        match key.name {
+            "tick_marks_placement" => {
+                self.tick_kind =
+                    Some(value.parse_keyword(&TICK_MARKS_PLACEMENTS)?)
+            }
+            "tick_marks_offset" => {
+                self.tick_offset = Some(value.parse_offset()?)
+            }
+            "tick_marks_inside" => self.tick_inside = Some(value.parse_bool()?),
+            "tick_marks_gap" => {
+                self.tick_gap = Some((
+                    value.parse_f32()?,
+                    key.error(
+                        "`tick_marks_gap` only applies to `center_split` \
+                         tick marks",
+                    ),
+                ))
+            }
+            "text_marks_placement" => {
+                self.text_kind =
+                    Some(value.parse_keyword(&TEXT_MARKS_PLACEMENTS)?)
+            }
+            "text_marks_offset" => {
+                self.text_offset = Some(value.parse_offset()?)
+            }
+            "text_marks_inside" => {
+                self.text_inside = Some((
+                    value.parse_bool()?,
+                    key.error(
+                        "`text_marks_inside` does not apply to `center` \
+                         text marks",
+                    ),
+                ))
+            }
+            _ => return Err(key.unknown(Some(section))),
+        }
+
+        Ok(())
+    }
+
+    fn apply(&self, slider: &mut SliderTheme) -> Result<(), ParseError> {
+        slider.tick_marks_placement =
+            self.tick_marks_placement(slider.tick_marks_placement)?;
+        slider.text_marks_placement =
+            self.text_marks_placement(slider.text_marks_placement)?;
+
+        Ok(())
+    }
+
+    /// Returns `base` with the tick marks keys applied to it.
+    fn tick_marks_placement(
+        &self,
+        base: tick_marks::Placement,
+    ) -> Result<tick_marks::Placement, ParseError> {
+        let (base_kind, base_offset, base_inside, base_gap) = match base {
+            tick_marks::Placement::BothSides { offset, inside } => {
+                (PlacementKind::BothSides, offset, inside, 0.0)
+            }
+            tick_marks::Placement::LeftOrTop { offset, inside } => {
+                (PlacementKind::LeftOrTop, offset, inside, 0.0)
+            }
+            tick_marks::Placement::RightOrBottom { offset, inside } => {
+                (PlacementKind::RightOrBottom, offset, inside, 0.0)
+            }
+            tick_marks::Placement::Center {
+                offset,
+                fill_length,
+            } => (PlacementKind::Center, offset, fill_length, 0.0),
+            tick_marks::Placement::CenterSplit {
+                offset,
+                fill_length,
+                gap,
+            } => (PlacementKind::CenterSplit, offset, fill_length, gap),
+        };
+
+        let kind = self.tick_kind.unwrap_or(base_kind);
+        let offset = self.tick_offset.unwrap_or(base_offset);
+        let inside = self.tick_inside.unwrap_or(base_inside);
+        let gap = match (kind, &self.tick_gap) {
+            (PlacementKind::CenterSplit, Some((gap, _))) => *gap,
+            (_, Some((_, error))) => return Err(error.clone()),
+            (_, None) => base_gap,
+        };
+
+        Ok(match kind {
+            PlacementKind::BothSides => {
+                tick_marks::Placement::BothSides { offset, inside }
+            }
+            PlacementKind::LeftOrTop => {
+                tick_marks::Placement::LeftOrTop { offset, inside }
+            }
+            PlacementKind::RightOrBottom => {
+                tick_marks::Placement::RightOrBottom { offset, inside }
+            }
+            PlacementKind::Center => tick_marks::Placement::Center {
+                offset,
+                fill_length: inside,
+            },
+            PlacementKind::CenterSplit => tick_marks::Placement::CenterSplit {
+                offset,
+                fill_length: inside,
+                gap,
+            },
+        })
+    }
+
+    /// Returns `base` with the text marks keys applied to it.
+    fn text_marks_placement(
+        &self,
+        base: text_marks::Placement,
+    ) -> Result<text_marks::Placement, ParseError> {
+        let (base_kind, base_offset, base_inside, align) = match base {
+            text_marks::Placement::BothSides { inside, offset } => (
+                PlacementKind::BothSides,
+                offset,
+                inside,
+                text_marks::Align::Center,
+            ),
+            text_marks::Placement::LeftOrTop { inside, offset } => (
+                PlacementKind::LeftOrTop,
+                offset,
+                inside,
+                text_marks::Align::Center,
+            ),
+            text_marks::Placement::RightOrBottom { inside, offset } => (
+                PlacementKind::RightOrBottom,
+                offset,
+                inside,
+                text_marks::Align::Center,
+            ),
+            text_marks::Placement::Center { align, offset } => {
+                (PlacementKind::Center, offset, false, align)
+            }
+        };
+
+        let offset = self.text_offset.unwrap_or(base_offset);
+        let inside = match &self.text_inside {
+            Some((inside, _)) => *inside,
+            None => base_inside,
+        };
+
+        Ok(match self.text_kind.unwrap_or(base_kind) {
+            PlacementKind::BothSides => {
+                text_marks::Placement::BothSides { inside, offset }
+            }
+            PlacementKind::LeftOrTop => {
+                text_marks::Placement::LeftOrTop { inside, offset }
+            }
+            PlacementKind::RightOrBottom => {
+                text_marks::Placement::RightOrBottom { inside, offset }
+            }
+            PlacementKind::Center | PlacementKind::CenterSplit => {
+                if let Some((_, error)) = &self.text_inside {
+                    return Err(error.clone());
+                }
+
+                text_marks::Placement::Center { align, offset }
+            }
+        })
+    }
+}
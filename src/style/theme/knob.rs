@@ -0,0 +1,103 @@
+use iced_native::Color;
+
+use super::{KnobShape, NotchKind, Theme};
+use crate::style::knob::{
+    ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch, ModRangeArcStyle,
+    ModulationArcStyle, ModulationStyle, NotchShape, Style, StyleLength,
+    StyleSheet, TextMarksStyle, TickMarksStyle,
+};
+
+impl Theme {
+    fn knob_notch(&self) -> NotchShape {
+        match self.knob.notch {
+            NotchKind::None => NotchShape::None,
+            NotchKind::Circle => NotchShape::Circle(CircleNotch {
+                color: self.palette.handle,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                diameter: StyleLength::Scaled(self.knob.notch_width),
+                offset: StyleLength::Scaled(0.15),
+            }),
+            NotchKind::Line => NotchShape::Line(LineNotch {
+                color: self.palette.handle,
+                width: StyleLength::Scaled(self.knob.notch_width),
+                length: StyleLength::Scaled(self.knob.notch_length),
+                cap: LineCap::Round,
+                offset: StyleLength::Scaled(0.15),
+            }),
+        }
+    }
+
+    fn knob_style(&self, is_hovered: bool, is_dragging: bool) -> Style {
+        match self.knob.shape {
+            KnobShape::Circle => Style::Circle(CircleStyle {
+                color: self.background(is_hovered, is_dragging),
+                gradient: None,
+                border_width: self.metrics.border_width,
+                border_color: self.palette.border,
+                notch: self.knob_notch(),
+                shadow: None,
+                inner_shadow: None,
+                glow: None,
+            }),
+            KnobShape::Arc => Style::Arc(ArcStyle {
+                width: StyleLength::Scaled(self.knob.arc_width),
+                empty_color: self.palette.empty,
+                filled_color: self.palette.accent,
+                filled_gradient: None,
+                notch: self.knob_notch(),
+                cap: LineCap::Round,
+                shadow: None,
+                glow: None,
+            }),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        self.knob_style(false, false)
+    }
+
+    fn hovered(&self) -> Style {
+        self.knob_style(true, false)
+    }
+
+    fn dragging(&self) -> Style {
+        self.knob_style(true, true)
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: self.round_tick_marks(),
+            offset: self.knob.tick_marks_offset,
+        })
+    }
+
+    fn mod_range_arc_style(&self) -> Option<ModRangeArcStyle> {
+        Some(ModRangeArcStyle {
+            width: self.metrics.mod_range_width,
+            offset: 1.5,
+            empty_color: Some(self.palette.empty),
+            filled_color: self.palette.accent,
+            filled_inverse_color: self.palette.accent_inverse,
+            cap: LineCap::Butt,
+        })
+    }
+
+    fn modulation_style(&self) -> Option<ModulationStyle> {
+        Some(ModulationStyle::Arc(ModulationArcStyle {
+            width: 2.0,
+            offset: 1.5 + self.metrics.mod_range_width + 1.0,
+            color: self.palette.modulation,
+            cap: LineCap::Round,
+        }))
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: self.text_marks(),
+            ..TextMarksStyle::default()
+        })
+    }
+}
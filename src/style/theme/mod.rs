@@ -0,0 +1,453 @@
+//! A global theme that produces the style of every input widget
+//!
+//! A [`Theme`] holds a [`Palette`] of colors and a set of [`Metrics`]. It
+//! implements the `StyleSheet` of the [`Knob`], [`HSlider`], [`VSlider`],
+//! [`XYPad`], [`Ramp`], and [`ModRangeInput`] widgets, including their tick
+//! marks and text marks, so a whole interface can be re-skinned by changing a
+//! single value.
+//!
+//! # Example
+//!
+//! ```
+//! use iced_audio::style::theme::Theme;
+//! use iced_audio::{h_slider, knob};
+//!
+//! let theme = Theme::dark();
+//!
+//! // A `Theme` can be passed to the `style()` method of any of the widgets
+//! // above, since it converts into each of their style sheets.
+//! let knob_style: Box<dyn knob::StyleSheet> = theme.clone().into();
+//! let h_slider_style: Box<dyn h_slider::StyleSheet> = theme.into();
+//! ```
+//!
+//! # Theme files
+//!
+//! A [`Theme`] can also be loaded from a text file with [`Theme::load`], or
+//! parsed from a string with `str::parse`. A [`ThemeWatcher`] reloads the file
+//! whenever it changes, so a theme can be tweaked while the application is
+//! running.
+//!
+//! A theme file starts from the `light` or `dark` theme, and overrides any
+//! of its values in the `[palette]`, `[metrics]`, `[knob]`, `[h_slider]`,
+//! and `[v_slider]` sections. The keys are the names of the fields of
+//! [`Palette`], [`Metrics`], [`KnobTheme`], and [`SliderTheme`]. Lines that
+//! start with `#` or `;` are comments.
+//!
+//! ```
+//! use iced_audio::style::theme::{KnobShape, Theme};
+//!
+//! let theme: Theme = r#"
+//!     ; Start from the dark theme.
+//!     base = dark
+//!
+//!     [palette]
+//!     accent = #ff8800
+//!     track = #202020, #ff8800cc
+//!
+//!     [metrics]
+//!     border_radius = 4.0
+//!     text_size = 11
+//!
+//!     [knob]
+//!     shape = arc
+//!     notch = line
+//!
+//!     [h_slider]
+//!     text_marks_placement = left_or_top
+//!     text_marks_offset = 0.0, -7.0
+//! "#
+//! .parse()
+//! .unwrap();
+//!
+//! assert_eq!(theme.metrics.text_size, 11);
+//! assert_eq!(theme.knob.shape, KnobShape::Arc);
+//! assert_eq!(theme.palette.background, Theme::dark().palette.background);
+//!
+//! // Errors point to the offending value.
+//! let error = "[metrics]\nrail_width = wide".parse::<Theme>().unwrap_err();
+//! assert_eq!((error.line, error.column), (2, 14));
+//! ```
+//!
+//! Colors are written as `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa`, and
+//! offsets as `x, y`. The tick marks and text marks of the sliders are set
+//! with `tick_marks_placement` and `text_marks_placement`, which are one of
+//! `both_sides`, `left_or_top`, `right_or_bottom`, or `center`, along with
+//! `*_offset` and `*_inside`. Tick marks may also be `center_split`, with
+//! their gap set by `tick_marks_gap`. For centered tick marks,
+//! `tick_marks_inside` sets whether they fill the length of the slider.
+//! These keys can be given in any order. A key that does not apply to the
+//! placement, such as `tick_marks_gap` for tick marks that are not
+//! `center_split`, or `text_marks_inside` for `center` text marks, is an
+//! error.
+//!
+//! ```
+//! use iced_audio::style::theme::Theme;
+//! use iced_audio::style::tick_marks::Placement;
+//!
+//! let theme: Theme = "[h_slider]
+//!     tick_marks_gap = 4.0
+//!     tick_marks_placement = center_split"
+//!     .parse()
+//!     .unwrap();
+//!
+//! match theme.h_slider.tick_marks_placement {
+//!     Placement::CenterSplit { gap, .. } => assert_eq!(gap, 4.0),
+//!     _ => panic!(),
+//! }
+//!
+//! let error = "[v_slider]\ntick_marks_placement = left_or_top\n\
+//!              tick_marks_gap = 4.0"
+//!     .parse::<Theme>()
+//!     .unwrap_err();
+//! assert_eq!((error.line, error.column), (3, 1));
+//! ```
+//!
+//! [`Theme`]: struct.Theme.html
+//! [`Theme::load`]: struct.Theme.html#method.load
+//! [`ThemeWatcher`]: struct.ThemeWatcher.html
+//! [`KnobTheme`]: struct.KnobTheme.html
+//! [`SliderTheme`]: struct.SliderTheme.html
+//! [`Palette`]: struct.Palette.html
+//! [`Metrics`]: struct.Metrics.html
+//! [`Knob`]: ../../native/knob/struct.Knob.html
+//! [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+//! [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+//! [`Ramp`]: ../../native/ramp/struct.Ramp.html
+//! [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+
+use iced_native::Color;
+
+use crate::core::Offset;
+use crate::style::{default_colors, text_marks, tick_marks};
+
+pub use file::{LoadError, ParseError, ThemeWatcher};
+
+mod file;
+mod h_slider;
+mod knob;
+mod mod_range_input;
+mod ramp;
+mod v_slider;
+mod xy_pad;
+
+/// The colors of a [`Theme`]
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Palette {
+    /// The color of the body of widgets, such as a knob, the handle of a
+    /// slider, or the background of a pad
+    pub background: Color,
+    /// The color of the body of a hovered widget
+    pub background_hover: Color,
+    /// The color of the body of a widget that is being dragged
+    pub background_drag: Color,
+    /// The color of borders
+    pub border: Color,
+    /// The color of the notches and lines that show the value of a widget
+    pub handle: Color,
+    /// The color of filled portions, such as modulation ranges
+    pub accent: Color,
+    /// The color of filled portions that are inverted, such as a modulation
+    /// range where `end` is less than `start`
+    pub accent_inverse: Color,
+    /// The color of empty portions, such as the background of a modulation
+    /// range
+    pub empty: Color,
+    /// The colors of the two sides of the rails of sliders and pads
+    pub track: (Color, Color),
+    /// The color of center lines
+    pub center_line: Color,
+    /// The color of tier 1 tick marks
+    pub tick_tier_1: Color,
+    /// The color of tier 2 tick marks
+    pub tick_tier_2: Color,
+    /// The color of tier 3 tick marks
+    pub tick_tier_3: Color,
+    /// The color of text marks
+    pub text: Color,
+    /// The color of the modulated value of a widget
+    pub modulation: Color,
+}
+
+/// The sizes of a [`Theme`] in pixels
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Metrics {
+    /// The width of borders
+    pub border_width: f32,
+    /// The radius of the corners of the handles of sliders
+    pub border_radius: f32,
+    /// The width (thickness) of each side of the rails of sliders
+    pub rail_width: f32,
+    /// The padding from the rails of sliders to the edges of the widget
+    pub rail_padding: f32,
+    /// The length of the handles of sliders
+    pub handle_length: u16,
+    /// The width (thickness) of the notches of slider handles
+    pub notch_width: f32,
+    /// The diameter of the handle of an `XYPad`
+    pub xy_handle_diameter: f32,
+    /// The width of lines, such as the line of a `Ramp` or the rails of an
+    /// `XYPad`
+    pub line_width: f32,
+    /// The width (thickness) of modulation ranges
+    pub mod_range_width: f32,
+    /// The size of the indicator of the modulated value of a widget
+    pub modulation_size: f32,
+    /// The size of the text of text marks
+    pub text_size: u16,
+}
+
+/// The shape of a `Knob` in a [`Theme`]
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KnobShape {
+    /// A classic circular knob
+    Circle,
+    /// A modern arc that fills up with the value
+    Arc,
+}
+
+/// The shape of the notch of a `Knob` in a [`Theme`]
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NotchKind {
+    /// No notch
+    None,
+    /// A circular notch
+    Circle,
+    /// A line notch
+    Line,
+}
+
+/// The look of a `Knob` in a [`Theme`]
+///
+/// Lengths are scaled to the diameter of the knob.
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KnobTheme {
+    /// The shape of the knob
+    pub shape: KnobShape,
+    /// The width (thickness) of the arc of a [`KnobShape::Arc`] knob
+    ///
+    /// [`KnobShape::Arc`]: enum.KnobShape.html#variant.Arc
+    pub arc_width: f32,
+    /// The shape of the notch
+    pub notch: NotchKind,
+    /// The diameter of a circle notch, or the width of a line notch
+    pub notch_width: f32,
+    /// The length of a line notch
+    pub notch_length: f32,
+    /// The offset of the tick marks from the edge of the knob in pixels
+    pub tick_marks_offset: f32,
+}
+
+/// The look of an `HSlider` or a `VSlider` in a [`Theme`]
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SliderTheme {
+    /// The placement of the tick marks
+    pub tick_marks_placement: tick_marks::Placement,
+    /// The placement of the text marks
+    pub text_marks_placement: text_marks::Placement,
+}
+
+/// A theme that produces the style of every input widget
+///
+/// See the [module documentation] for more.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Theme {
+    /// The colors of the theme
+    pub palette: Palette,
+    /// The sizes of the theme
+    pub metrics: Metrics,
+    /// The look of knobs
+    pub knob: KnobTheme,
+    /// The look of horizontal sliders
+    pub h_slider: SliderTheme,
+    /// The look of vertical sliders
+    pub v_slider: SliderTheme,
+}
+
+impl Theme {
+    /// The default sizes used by both presets
+    pub const METRICS: Metrics = Metrics {
+        border_width: 1.0,
+        border_radius: 2.0,
+        rail_width: 1.0,
+        rail_padding: 12.0,
+        handle_length: 34,
+        notch_width: 4.0,
+        xy_handle_diameter: 11.0,
+        line_width: 2.0,
+        mod_range_width: 3.0,
+        modulation_size: 6.0,
+        text_size: 12,
+    };
+
+    /// The default look of knobs used by both presets
+    pub const KNOB: KnobTheme = KnobTheme {
+        shape: KnobShape::Circle,
+        arc_width: 0.12,
+        notch: NotchKind::Circle,
+        notch_width: 0.17,
+        notch_length: 0.2,
+        tick_marks_offset: 3.5,
+    };
+
+    /// The default look of horizontal sliders used by both presets
+    pub const H_SLIDER: SliderTheme = SliderTheme {
+        tick_marks_placement: tick_marks::Placement::Center {
+            offset: Offset::ZERO,
+            fill_length: false,
+        },
+        text_marks_placement: text_marks::Placement::RightOrBottom {
+            inside: false,
+            offset: Offset { x: 0.0, y: 7.0 },
+        },
+    };
+
+    /// The default look of vertical sliders used by both presets
+    pub const V_SLIDER: SliderTheme = SliderTheme {
+        tick_marks_placement: tick_marks::Placement::Center {
+            offset: Offset::ZERO,
+            fill_length: false,
+        },
+        text_marks_placement: text_marks::Placement::LeftOrTop {
+            inside: false,
+            offset: Offset { x: -7.0, y: 0.0 },
+        },
+    };
+
+    /// A light theme that matches the default styles of the widgets
+    pub fn light() -> Self {
+        Self {
+            palette: Palette {
+                background: default_colors::LIGHT_BACK,
+                background_hover: default_colors::LIGHT_BACK_HOVER,
+                background_drag: default_colors::LIGHT_BACK_DRAG,
+                border: default_colors::BORDER,
+                handle: default_colors::BORDER,
+                accent: default_colors::RANGE_SLIDER_FILLED,
+                accent_inverse: Color::from_rgb(0.9, 0.55, 0.45),
+                empty: Color::from_rgb(0.85, 0.85, 0.85),
+                track: default_colors::SLIDER_RAIL,
+                center_line: default_colors::XY_PAD_CENTER_LINE,
+                tick_tier_1: default_colors::TICK_TIER_1,
+                tick_tier_2: default_colors::TICK_TIER_2,
+                tick_tier_3: default_colors::TICK_TIER_3,
+                text: default_colors::TEXT_MARK,
+                modulation: default_colors::MODULATION,
+            },
+            metrics: Self::METRICS,
+            knob: Self::KNOB,
+            h_slider: Self::H_SLIDER,
+            v_slider: Self::V_SLIDER,
+        }
+    }
+
+    /// A dark theme
+    pub fn dark() -> Self {
+        Self {
+            palette: Palette {
+                background: Color::from_rgb(0.2, 0.2, 0.22),
+                background_hover: Color::from_rgb(0.24, 0.24, 0.26),
+                background_drag: Color::from_rgb(0.26, 0.26, 0.28),
+                border: Color::from_rgb(0.07, 0.07, 0.08),
+                handle: Color::from_rgb(0.88, 0.88, 0.9),
+                accent: Color::from_rgb(0.35, 0.65, 0.95),
+                accent_inverse: Color::from_rgb(0.95, 0.45, 0.35),
+                empty: Color::from_rgb(0.13, 0.13, 0.14),
+                track: (
+                    Color::from_rgba(0.05, 0.05, 0.05, 0.9),
+                    Color::from_rgba(0.4, 0.4, 0.42, 0.75),
+                ),
+                center_line: Color::from_rgba(1.0, 1.0, 1.0, 0.2),
+                tick_tier_1: Color::from_rgba(0.75, 0.75, 0.77, 0.93),
+                tick_tier_2: Color::from_rgba(0.65, 0.65, 0.67, 0.83),
+                tick_tier_3: Color::from_rgba(0.55, 0.55, 0.57, 0.65),
+                text: Color::from_rgba(0.85, 0.85, 0.87, 0.9),
+                modulation: default_colors::MODULATION,
+            },
+            metrics: Self::METRICS,
+            knob: Self::KNOB,
+            h_slider: Self::H_SLIDER,
+            v_slider: Self::V_SLIDER,
+        }
+    }
+
+    /// Returns the body color of a widget for the given interaction state.
+    fn background(&self, is_hovered: bool, is_dragging: bool) -> Color {
+        if is_dragging {
+            self.palette.background_drag
+        } else if is_hovered {
+            self.palette.background_hover
+        } else {
+            self.palette.background
+        }
+    }
+
+    /// Returns the style of tick marks drawn as lines of the given lengths
+    /// for each tier.
+    pub fn tick_marks(&self, lengths: (f32, f32, f32)) -> tick_marks::Style {
+        tick_marks::Style {
+            tier_1: tick_marks::Shape::Line {
+                length: lengths.0,
+                width: 2.0,
+                color: self.palette.tick_tier_1,
+            },
+            tier_2: tick_marks::Shape::Line {
+                length: lengths.1,
+                width: 1.0,
+                color: self.palette.tick_tier_2,
+            },
+            tier_3: tick_marks::Shape::Line {
+                length: lengths.2,
+                width: 1.0,
+                color: self.palette.tick_tier_3,
+            },
+        }
+    }
+
+    /// Returns the style of tick marks drawn as circles.
+    pub fn round_tick_marks(&self) -> tick_marks::Style {
+        tick_marks::Style {
+            tier_1: tick_marks::Shape::Circle {
+                diameter: 4.0,
+                color: self.palette.tick_tier_1,
+            },
+            tier_2: tick_marks::Shape::Circle {
+                diameter: 2.0,
+                color: self.palette.tick_tier_2,
+            },
+            tier_3: tick_marks::Shape::Circle {
+                diameter: 2.0,
+                color: self.palette.tick_tier_3,
+            },
+        }
+    }
+
+    /// Returns the style of text marks.
+    pub fn text_marks(&self) -> text_marks::Style {
+        text_marks::Style {
+            color: self.palette.text,
+            text_size: self.metrics.text_size,
+            ..text_marks::Style::default()
+        }
+    }
+}
+
+impl std::default::Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
@@ -0,0 +1,26 @@
+use super::Theme;
+use crate::style::mod_range_input::{CircleStyle, Style, StyleSheet};
+
+impl Theme {
+    fn mod_range_input_style(&self, is_hovered: bool) -> Style {
+        Style::Circle(CircleStyle {
+            color: self.background(is_hovered, false),
+            border_width: self.metrics.border_width,
+            border_color: self.palette.border,
+        })
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        self.mod_range_input_style(false)
+    }
+
+    fn hovered(&self) -> Style {
+        self.mod_range_input_style(true)
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
@@ -0,0 +1,30 @@
+use super::Theme;
+use crate::style::ramp::{Style, StyleSheet};
+
+impl Theme {
+    fn ramp_style(&self, is_hovered: bool) -> Style {
+        Style {
+            back_color: self.background(is_hovered, false),
+            back_border_width: self.metrics.border_width,
+            back_border_color: self.palette.border,
+            line_width: self.metrics.line_width,
+            line_center_color: self.palette.handle,
+            line_up_color: self.palette.handle,
+            line_down_color: self.palette.handle,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        self.ramp_style(false)
+    }
+
+    fn hovered(&self) -> Style {
+        self.ramp_style(true)
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
@@ -0,0 +1,83 @@
+use super::Theme;
+use crate::style::v_slider::{
+    ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
+    ModulationDot, ModulationStyle, Style, StyleSheet, TextMarksStyle,
+    TickMarksStyle,
+};
+
+impl Theme {
+    fn v_slider_style(&self, is_hovered: bool, is_dragging: bool) -> Style {
+        Style::Classic(ClassicStyle {
+            rail: ClassicRail {
+                rail_colors: self.palette.track,
+                rail_widths: (self.metrics.rail_width, self.metrics.rail_width),
+                rail_padding: self.metrics.rail_padding,
+            },
+            handle: ClassicHandle {
+                color: self.background(is_hovered, is_dragging),
+                height: self.metrics.handle_length,
+                notch_width: self.metrics.notch_width,
+                notch_color: self.palette.handle,
+                border_radius: self.metrics.border_radius,
+                border_color: self.palette.border,
+                border_width: self.metrics.border_width,
+                shadow: None,
+                inner_shadow: None,
+                glow: None,
+            },
+        })
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        self.v_slider_style(false, false)
+    }
+
+    fn hovered(&self) -> Style {
+        self.v_slider_style(true, false)
+    }
+
+    fn dragging(&self) -> Style {
+        self.v_slider_style(true, true)
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: self.tick_marks((24.0, 22.0, 18.0)),
+            placement: self.v_slider.tick_marks_placement,
+        })
+    }
+
+    fn mod_range_style(&self) -> Option<ModRangeStyle> {
+        Some(ModRangeStyle {
+            placement: ModRangePlacement::Right {
+                width: self.metrics.mod_range_width,
+                offset: 2.0,
+            },
+            back_border_width: 0.0,
+            back_border_radius: 2.0,
+            back_border_color: self.palette.border,
+            back_color: Some(self.palette.empty),
+            filled_color: self.palette.accent,
+            filled_inverse_color: self.palette.accent_inverse,
+        })
+    }
+
+    fn modulation_style(&self) -> Option<ModulationStyle> {
+        Some(ModulationStyle::Dot(ModulationDot {
+            color: self.palette.modulation,
+            diameter: self.metrics.modulation_size,
+            border_width: self.metrics.border_width,
+            border_color: self.palette.border,
+            offset: 0.0,
+        }))
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: self.text_marks(),
+            placement: self.v_slider.text_marks_placement,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use super::Theme;
+use crate::style::xy_pad::{HandleCircle, HandleShape, Style, StyleSheet};
+
+impl Theme {
+    fn xy_pad_style(&self, is_hovered: bool, is_dragging: bool) -> Style {
+        let diameter = if is_dragging {
+            self.metrics.xy_handle_diameter - 2.0
+        } else {
+            self.metrics.xy_handle_diameter
+        };
+
+        Style {
+            rail_width: self.metrics.line_width,
+            h_rail_color: self.palette.track.1,
+            v_rail_color: self.palette.track.1,
+            handle: HandleShape::Circle(HandleCircle {
+                color: self.background(is_hovered, is_dragging),
+                diameter,
+                border_width: self.metrics.line_width,
+                border_color: self.palette.border,
+            }),
+            back_color: self.palette.background,
+            back_gradient: None,
+            border_width: self.metrics.border_width,
+            border_color: self.palette.border,
+            center_line_width: 1.0,
+            center_line_color: self.palette.center_line,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        self.xy_pad_style(false, false)
+    }
+
+    fn hovered(&self) -> Style {
+        self.xy_pad_style(true, false)
+    }
+
+    fn dragging(&self) -> Style {
+        self.xy_pad_style(true, true)
+    }
+}
@@ -0,0 +1,143 @@
+//! Various styles for the [`WaveformView`] widget
+//!
+//! [`WaveformView`]: ../native/waveform_view/struct.WaveformView.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the line at zero amplitude
+    pub center_line_width: f32,
+    /// The color of the line at zero amplitude
+    pub center_line_color: Color,
+    /// The color of the peaks of the waveform
+    pub wave_color: Color,
+    /// The color of the RMS of the waveform, drawn over the peaks. Set this
+    /// to `Color::TRANSPARENT` to hide it.
+    pub rms_color: Color,
+    /// The color of the selection region
+    pub selection_color: Color,
+    /// The color of the loop region between the loop markers
+    pub loop_color: Color,
+    /// The color of the areas before the sample start marker and after the
+    /// sample end marker
+    pub outside_color: Color,
+    /// The style of the playhead
+    pub playhead: MarkerStyle,
+    /// The style of the loop start and loop end markers
+    pub loop_marker: MarkerStyle,
+    /// The style of the sample start and sample end markers
+    pub sample_marker: MarkerStyle,
+    /// The color of the marker that is hovered or being dragged
+    pub active_marker_color: Color,
+}
+
+/// The appearance of a marker in the [`Style`] of a [`WaveformView`]
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+/// [`Style`]: struct.Style.html
+#[derive(Debug, Clone)]
+pub struct MarkerStyle {
+    /// The color of the marker
+    pub color: Color,
+    /// The width of the line
+    pub width: f32,
+    /// The size of the square handle at the top of the line. Set this to
+    /// `0.0` to hide the handle.
+    pub handle_size: f32,
+}
+
+/// A set of rules that dictate the style of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`WaveformView`] while the selection or a
+    /// marker is being dragged.
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        center_line_width: 1.0,
+        center_line_color: default_colors::ENVELOPE_GRID,
+        wave_color: default_colors::WAVEFORM,
+        rms_color: default_colors::WAVEFORM_RMS,
+        selection_color: default_colors::WAVEFORM_SELECTION,
+        loop_color: default_colors::WAVEFORM_LOOP,
+        outside_color: default_colors::WAVEFORM_OUTSIDE,
+        playhead: MarkerStyle {
+            color: default_colors::WAVEFORM_PLAYHEAD,
+            width: 1.0,
+            handle_size: 0.0,
+        },
+        loop_marker: MarkerStyle {
+            color: default_colors::WAVEFORM_LOOP_MARKER,
+            width: 1.0,
+            handle_size: 6.0,
+        },
+        sample_marker: MarkerStyle {
+            color: default_colors::BORDER,
+            width: 1.0,
+            handle_size: 6.0,
+        },
+        active_marker_color: default_colors::ENVELOPE_ACTIVE,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,103 @@
+//! Various styles for the [`WavetableView`] widget
+//!
+//! [`WavetableView`]: ../native/wavetable_view/struct.WavetableView.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`WavetableView`].
+///
+/// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The width of the line at zero amplitude of the current frame
+    pub center_line_width: f32,
+    /// The color of the line at zero amplitude of the current frame
+    pub center_line_color: Color,
+    /// The color of the line of the current frame
+    pub wave_color: Color,
+    /// The width of the line of the current frame
+    pub wave_width: f32,
+    /// The color of the area between the current frame and its center line.
+    /// Set this to `Color::TRANSPARENT` to hide it.
+    pub fill_color: Color,
+    /// The color of the lines of the other frames in the stack
+    pub stack_color: Color,
+    /// The width of the lines of the other frames in the stack
+    pub stack_width: f32,
+}
+
+/// A set of rules that dictate the style of a [`WavetableView`].
+///
+/// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`WavetableView`].
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`WavetableView`].
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a [`WavetableView`] while the current frame is
+    /// being sketched.
+    ///
+    /// [`WavetableView`]: ../../native/wavetable_view/struct.WavetableView.html
+    fn drawing(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::LIGHT_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        center_line_width: 1.0,
+        center_line_color: default_colors::ENVELOPE_GRID,
+        wave_color: default_colors::WAVEFORM,
+        wave_width: 2.0,
+        fill_color: default_colors::WAVETABLE_FILL,
+        stack_color: default_colors::WAVETABLE_STACK,
+        stack_width: 1.0,
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            back_color: default_colors::LIGHT_BACK_HOVER,
+            ..Self::ACTIVE_STYLE
+        }
+    }
+
+    fn drawing(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -5,6 +5,7 @@
 use iced_native::Color;
 
 use crate::style::default_colors;
+use crate::style::gradient::Gradient;
 
 /// The appearance of an [`XYPad`].
 ///
@@ -24,6 +25,14 @@ pub struct Style {
     pub handle: HandleShape,
     /// the color of the background square
     pub back_color: Color,
+    /// a [`Gradient`] that is drawn in place of `back_color` when it is set.
+    /// A [`Gradient::Range`] or [`Gradient::Value`] follows the value of the
+    /// x axis.
+    ///
+    /// [`Gradient`]: ../gradient/enum.Gradient.html
+    /// [`Gradient::Range`]: ../gradient/enum.Gradient.html#variant.Range
+    /// [`Gradient::Value`]: ../gradient/enum.Gradient.html#variant.Value
+    pub back_gradient: Option<Gradient>,
     /// the width of the border of the background square
     pub border_width: f32,
     /// the color of the border of the background square
@@ -114,6 +123,7 @@ impl Default {
         v_rail_color: default_colors::XY_PAD_RAIL,
         handle: HandleShape::Circle(Self::ACTIVE_HANDLE),
         back_color: default_colors::LIGHT_BACK,
+        back_gradient: None,
         border_width: 1.0,
         border_color: default_colors::BORDER,
         center_line_width: 1.0,